@@ -0,0 +1,70 @@
+use dotenv_core::LineEntry;
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+pub(crate) struct ByteOrderMarkChecker<'a> {
+    template: &'a str,
+}
+
+impl ByteOrderMarkChecker<'_> {
+    fn message(&self) -> &str {
+        self.template
+    }
+}
+
+impl Default for ByteOrderMarkChecker<'_> {
+    fn default() -> Self {
+        Self {
+            template: "Byte order mark detected",
+        }
+    }
+}
+
+impl Check for ByteOrderMarkChecker<'_> {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        if line.bom {
+            return Some(Warning::new(line.number, self.name(), self.message()));
+        }
+
+        None
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::ByteOrderMark
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::line_entry;
+
+    #[test]
+    fn without_bom() {
+        let mut checker = ByteOrderMarkChecker::default();
+        assert_eq!(None, checker.run(&line_entry(1, 1, "A=1")));
+    }
+
+    #[test]
+    fn with_bom() {
+        let mut checker = ByteOrderMarkChecker::default();
+        let line = LineEntry {
+            bom: true,
+            ..line_entry(1, 1, "# comment")
+        };
+
+        assert_eq!(
+            Some(Warning::new(
+                1,
+                LintKind::ByteOrderMark,
+                "Byte order mark detected"
+            )),
+            checker.run(&line)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use dotenv_core::LineEntry;
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+/// Checks for keys which differ from other keys only in case (e.g. `api_key` and `API_KEY`).
+/// They are the same key in case-insensitive environments (e.g. on Windows).
+/// All the keys of such a group are reported, so the warnings don't depend on the order of keys.
+/// Keys which are equal are reported by `DuplicatedKeyChecker`.
+#[derive(Default)]
+pub(crate) struct CaseInsensitiveDuplicatedKeyChecker {
+    /// Keys with the numbers of their lines
+    keys: Vec<(usize, String)>,
+}
+
+impl Check for CaseInsensitiveDuplicatedKeyChecker {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        let key = line.get_key()?;
+        self.keys.push((line.number, key.to_string()));
+
+        None
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::CaseInsensitiveDuplicatedKey
+    }
+
+    fn end(&mut self) -> Vec<Warning> {
+        // Different spellings of each uppercased key in the order of lines
+        let mut spellings: HashMap<String, Vec<&str>> = HashMap::new();
+        for (_, key) in &self.keys {
+            let keys = spellings.entry(key.to_uppercase()).or_default();
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+        }
+
+        self.keys
+            .iter()
+            .filter_map(|(number, key)| {
+                let others: Vec<&str> = spellings[&key.to_uppercase()]
+                    .iter()
+                    .copied()
+                    .filter(|other| other != key)
+                    .collect();
+                if others.is_empty() {
+                    return None;
+                }
+
+                Some(Warning::new(
+                    *number,
+                    self.name(),
+                    format!(
+                        "The {key} key differs only in case from {}",
+                        others.join(", ")
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::line_entry;
+
+    fn run(lines: &[&str]) -> Vec<(usize, String)> {
+        let mut checker = CaseInsensitiveDuplicatedKeyChecker::default();
+        for (index, line) in lines.iter().enumerate() {
+            checker.run(&line_entry(index + 1, lines.len(), line));
+        }
+
+        checker
+            .end()
+            .into_iter()
+            .map(|w| (w.line_number(), w.message().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn unique_keys_test() {
+        assert!(run(&["FOO=BAR", "BAR=FOO", "FOO=BAZ"]).is_empty());
+    }
+
+    #[test]
+    fn keys_differing_in_case_test() {
+        assert_eq!(
+            run(&["API_KEY=1", "FOO=2", "api_key=3", "API_KEY=4", "Api_Key=5"]),
+            [
+                (
+                    1,
+                    "The API_KEY key differs only in case from api_key, Api_Key".into()
+                ),
+                (
+                    3,
+                    "The api_key key differs only in case from API_KEY, Api_Key".into()
+                ),
+                (
+                    4,
+                    "The API_KEY key differs only in case from api_key, Api_Key".into()
+                ),
+                (
+                    5,
+                    "The Api_Key key differs only in case from API_KEY, api_key".into()
+                ),
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use dotenv_core::LineEntry;
 
-use super::Check;
+use super::{Check, is_invisible};
 use crate::{LintKind, Warning, remove_invalid_leading_chars};
 
 pub(crate) struct IncorrectDelimiterChecker<'a> {
@@ -29,10 +29,11 @@ fn run(&mut self, line: &LineEntry) -> Option<Warning> {
         // remove all invalid leading characters before checking for incorrect delimiters
         let cleaned_key = remove_invalid_leading_chars(key);
 
+        // Invisible characters are reported by `SuspiciousCharacterChecker`
         if cleaned_key
             .trim()
             .chars()
-            .any(|c| !c.is_alphanumeric() && c != '_')
+            .any(|c| !c.is_alphanumeric() && c != '_' && !is_invisible(c))
         {
             return Some(Warning::new(line.number, self.name(), self.message(key)));
         }
@@ -139,6 +140,14 @@ fn trailing_space_run() {
         );
     }
 
+    #[test]
+    fn invisible_character_run() {
+        check_test(
+            &mut IncorrectDelimiterChecker::default(),
+            [("FOO\u{200B}_BAR=FOOBAR", None)],
+        );
+    }
+
     #[test]
     fn empty_run() {
         check_test(&mut IncorrectDelimiterChecker::default(), [("", None)]);
@@ -0,0 +1,83 @@
+use dotenv_core::{LineEnding, LineEntry};
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+/// Checks that the lines end with the same line ending as the first line
+#[derive(Default)]
+pub(crate) struct MixedLineEndingsChecker {
+    ending: Option<LineEnding>,
+}
+
+impl MixedLineEndingsChecker {
+    fn message(&self, ending: LineEnding, expected: LineEnding) -> String {
+        format!("The line ends with {ending} instead of {expected}")
+    }
+}
+
+impl Check for MixedLineEndingsChecker {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        // The last line has no line ending
+        if line.is_last_line {
+            return None;
+        }
+
+        let expected = *self.ending.get_or_insert(line.ending);
+        if line.ending != expected {
+            return Some(Warning::new(
+                line.number,
+                self.name(),
+                self.message(line.ending, expected),
+            ));
+        }
+
+        None
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::MixedLineEndings
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::line_entry;
+
+    fn line(number: usize, ending: LineEnding) -> LineEntry {
+        LineEntry {
+            ending,
+            ..line_entry(number, 5, "A=1")
+        }
+    }
+
+    #[test]
+    fn same_line_endings() {
+        let mut checker = MixedLineEndingsChecker::default();
+
+        for number in 1..=2 {
+            assert_eq!(None, checker.run(&line(number, LineEnding::CrLf)));
+        }
+    }
+
+    #[test]
+    fn mixed_line_endings() {
+        let mut checker = MixedLineEndingsChecker::default();
+
+        assert_eq!(None, checker.run(&line(1, LineEnding::CrLf)));
+        assert_eq!(
+            Some(Warning::new(
+                2,
+                LintKind::MixedLineEndings,
+                "The line ends with LF instead of CRLF"
+            )),
+            checker.run(&line(2, LineEnding::Lf))
+        );
+        // The ending of the last line is not used
+        assert_eq!(None, checker.run(&line(5, LineEnding::Lf)));
+    }
+}
@@ -1,8 +1,13 @@
 use dotenv_core::LineEntry;
 use dotenv_schema::DotEnvSchema;
 
+pub use naming_convention::{DEFAULT_RESERVED_NAMES, NamingConvention};
+pub(crate) use suspicious_character::{invisible_characters, is_invisible, is_removable};
+
 use crate::{Comment, LintKind, Warning};
 
+mod byte_order_mark;
+mod case_insensitive_duplicated_key;
 mod duplicated_key;
 mod ending_blank_line;
 mod extra_blank_line;
@@ -10,10 +15,14 @@
 mod key_without_value;
 mod leading_character;
 mod lowercase_key;
+mod mixed_line_endings;
+mod naming_convention;
 mod quote_character;
 mod schema_violation;
 mod space_character;
 mod substitution_key;
+mod suspicious_character;
+mod syntax_error;
 mod trailing_whitespace;
 mod unordered_key;
 mod value_without_quotes;
@@ -31,8 +40,13 @@ fn end(&mut self) -> Vec<Warning> {
 }
 
 // Checklist for checks which needs to know of only a single line
-fn checklist<'a>(schema: Option<&'a DotEnvSchema>) -> Vec<Box<dyn Check + 'a>> {
+fn checklist<'a>(
+    schema: Option<&'a DotEnvSchema>,
+    naming: Option<&'a NamingConvention>,
+) -> Vec<Box<dyn Check + 'a>> {
     vec![
+        Box::<byte_order_mark::ByteOrderMarkChecker>::default(),
+        Box::<case_insensitive_duplicated_key::CaseInsensitiveDuplicatedKeyChecker>::default(),
         Box::<duplicated_key::DuplicatedKeyChecker>::default(),
         Box::<ending_blank_line::EndingBlankLineChecker>::default(),
         Box::<extra_blank_line::ExtraBlankLineChecker>::default(),
@@ -40,9 +54,13 @@ fn checklist<'a>(schema: Option<&'a DotEnvSchema>) -> Vec<Box<dyn Check + 'a>> {
         Box::<key_without_value::KeyWithoutValueChecker>::default(),
         Box::<leading_character::LeadingCharacterChecker>::default(),
         Box::<lowercase_key::LowercaseKeyChecker>::default(),
+        Box::<mixed_line_endings::MixedLineEndingsChecker>::default(),
+        Box::new(naming_convention::NamingConventionChecker::new(naming)),
         Box::<quote_character::QuoteCharacterChecker>::default(),
         Box::<space_character::SpaceCharacterChecker>::default(),
         Box::<substitution_key::SubstitutionKeyChecker>::default(),
+        Box::<suspicious_character::SuspiciousCharacterChecker>::default(),
+        Box::<syntax_error::SyntaxErrorChecker>::default(),
         Box::<trailing_whitespace::TrailingWhitespaceChecker>::default(),
         Box::<unordered_key::UnorderedKeyChecker>::default(),
         Box::<value_without_quotes::ValueWithoutQuotesChecker>::default(),
@@ -54,8 +72,9 @@ pub fn check(
     lines: &[LineEntry],
     skip_checks: &[LintKind],
     schema: Option<&DotEnvSchema>,
+    naming: Option<&NamingConvention>,
 ) -> Vec<Warning> {
-    let mut checks = checklist(schema);
+    let mut checks = checklist(schema, naming);
 
     // Skip checks with the --skip argument (globally)
     checks.retain(|c| !skip_checks.contains(&c.name()));
@@ -109,7 +128,7 @@ fn run_with_empty_vec_test() {
         let empty: Vec<LineEntry> = Vec::new();
         let expected: Vec<Warning> = Vec::new();
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&empty, &skip_checks, None));
+        assert_eq!(expected, check(&empty, &skip_checks, None, None));
     }
 
     #[test]
@@ -117,7 +136,7 @@ fn run_with_empty_line_test() {
         let lines: Vec<LineEntry> = vec![blank_line_entry(1, 1)];
         let expected: Vec<Warning> = Vec::new();
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -128,7 +147,7 @@ fn run_with_comment_line_test() {
         ];
         let expected: Vec<Warning> = Vec::new();
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -136,7 +155,7 @@ fn run_with_valid_line_test() {
         let lines: Vec<LineEntry> = vec![line_entry(1, 2, "FOO=BAR"), blank_line_entry(2, 2)];
         let expected: Vec<Warning> = Vec::new();
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -150,7 +169,7 @@ fn run_with_invalid_line_test() {
         let lines: Vec<LineEntry> = vec![line, blank_line_entry(2, 2)];
         let expected: Vec<Warning> = vec![warning];
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -164,7 +183,7 @@ fn run_without_blank_line_test() {
         let lines: Vec<LineEntry> = vec![line];
         let expected: Vec<Warning> = vec![warning];
         let skip_checks: Vec<LintKind> = Vec::new();
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -180,7 +199,7 @@ fn skip_one_check() {
         let expected: Vec<Warning> = vec![warning];
         let skip_checks = vec![LintKind::KeyWithoutValue, LintKind::UnorderedKey];
 
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -190,7 +209,7 @@ fn skip_all_checks() {
         let expected: Vec<Warning> = Vec::new();
         let skip_checks = vec![LintKind::KeyWithoutValue, LintKind::EndingBlankLine];
 
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -207,7 +226,7 @@ fn skip_one_check_via_comment() {
         let expected: Vec<Warning> = vec![warning];
         let skip_checks = vec![LintKind::UnorderedKey];
 
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -223,7 +242,7 @@ fn skip_collision() {
         let lines: Vec<LineEntry> = vec![line1, line2, line3, blank_line_entry(4, 4)];
         let expected: Vec<Warning> = vec![warning];
         let skip_checks = vec![LintKind::KeyWithoutValue, LintKind::UnorderedKey];
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -245,7 +264,7 @@ fn on_and_off_same_checks() {
         let expected: Vec<Warning> = vec![warning];
         let skip_checks: Vec<LintKind> = Vec::new();
 
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
@@ -260,17 +279,25 @@ fn only_simple_comment() {
         let expected: Vec<Warning> = vec![warning];
         let skip_checks: Vec<LintKind> = Vec::new();
 
-        assert_eq!(expected, check(&lines, &skip_checks, None));
+        assert_eq!(expected, check(&lines, &skip_checks, None, None));
     }
 
     #[test]
     fn unordered_key_with_control_comment_test() {
         let line_entries = vec![
             line_entry(1, 7, "FOO=BAR"),
-            line_entry(2, 7, "# dotenv-linter:off LowercaseKey"),
+            line_entry(
+                2,
+                7,
+                "# dotenv-linter:off LowercaseKey, CaseInsensitiveDuplicatedKey",
+            ),
             line_entry(3, 7, "Bar=FOO"),
             line_entry(4, 7, "bar=FOO"),
-            line_entry(5, 7, "# dotenv-linter:on LowercaseKey"),
+            line_entry(
+                5,
+                7,
+                "# dotenv-linter:on LowercaseKey, CaseInsensitiveDuplicatedKey",
+            ),
             line_entry(6, 7, "X=X"),
             blank_line_entry(7, 7),
         ];
@@ -278,7 +305,7 @@ fn unordered_key_with_control_comment_test() {
         let expected: Vec<Warning> = Vec::new();
         let skip_checks: Vec<LintKind> = Vec::new();
 
-        assert_eq!(expected, check(&line_entries, &skip_checks, None));
+        assert_eq!(expected, check(&line_entries, &skip_checks, None, None));
     }
 
     mod schema {
@@ -324,7 +351,7 @@ fn string_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -340,7 +367,7 @@ fn string_unknown() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -353,7 +380,7 @@ fn string_unknown_allowed() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -366,7 +393,7 @@ fn integer_good() {
 
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -382,7 +409,7 @@ fn integer_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -398,7 +425,7 @@ fn integer_is_float() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -410,7 +437,7 @@ fn float_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -422,7 +449,7 @@ fn float_good2() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -438,7 +465,7 @@ fn float_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -450,7 +477,7 @@ fn url_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -466,7 +493,7 @@ fn url_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -478,7 +505,7 @@ fn email_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -494,7 +521,7 @@ fn email_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -507,7 +534,7 @@ fn required_present() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -524,7 +551,7 @@ fn required_missing() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -538,7 +565,7 @@ fn regex_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -556,7 +583,7 @@ fn regex_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -568,7 +595,7 @@ fn boolean_good() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
 
@@ -584,7 +611,87 @@ fn boolean_bad() {
             let skip_checks: Vec<LintKind> = Vec::new();
             assert_eq!(
                 expected,
-                crate::check::check(&lines, &skip_checks, Some(&schema))
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
+            );
+        }
+
+        fn load_pattern_schema() -> DotEnvSchema {
+            let json = r#"{
+            "version": "1.0.0",
+            "entries": {
+                "FEATURE_*": {
+                    "type": "Boolean"
+                },
+                "OLD_NAME": {
+                    "deprecated": true,
+                    "replaced_by": "NAME"
+                },
+                "LEGACY": {
+                    "deprecated": true
+                },
+                "NAME": {
+                    "type": "String",
+                    "required": true
+                }
+            }
+        }"#;
+            serde_json::from_str(json).expect("deserializing schema")
+        }
+
+        #[test]
+        fn pattern_good() {
+            let schema = load_pattern_schema();
+            let lines: Vec<LineEntry> = vec![
+                line_entry(1, 3, "FEATURE_LOGIN=true"),
+                line_entry(2, 3, "NAME=joe"),
+            ];
+            let expected: Vec<Warning> = vec![];
+            let skip_checks: Vec<LintKind> = Vec::new();
+            assert_eq!(
+                expected,
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
+            );
+        }
+
+        #[test]
+        fn pattern_bad() {
+            let schema = load_pattern_schema();
+            let lines: Vec<LineEntry> = vec![
+                line_entry(1, 3, "FEATURE_LOGIN=maybe"),
+                line_entry(2, 3, "NAME=joe"),
+            ];
+            let expected: Vec<Warning> = vec![Warning::new(
+                1,
+                LintKind::SchemaViolation,
+                "The FEATURE_LOGIN key is not a valid boolean",
+            )];
+            let skip_checks: Vec<LintKind> = Vec::new();
+            assert_eq!(
+                expected,
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
+            );
+        }
+
+        #[test]
+        fn deprecated() {
+            let schema = load_pattern_schema();
+            let lines: Vec<LineEntry> = vec![
+                line_entry(1, 4, "LEGACY=1"),
+                line_entry(2, 4, "NAME=joe"),
+                line_entry(3, 4, "OLD_NAME=joe"),
+            ];
+            let expected: Vec<Warning> = vec![
+                Warning::new(1, LintKind::SchemaViolation, "The LEGACY key is deprecated"),
+                Warning::new(
+                    3,
+                    LintKind::SchemaViolation,
+                    "The OLD_NAME key is deprecated, use NAME instead",
+                ),
+            ];
+            let skip_checks: Vec<LintKind> = Vec::new();
+            assert_eq!(
+                expected,
+                crate::check::check(&lines, &skip_checks, Some(&schema), None)
             );
         }
     }
@@ -0,0 +1,275 @@
+use std::borrow::Cow;
+
+use dotenv_core::LineEntry;
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+/// Names of environment variables which are set by the system and shouldn't be overridden
+pub const DEFAULT_RESERVED_NAMES: [&str; 5] =
+    ["HOME", "IFS", "LD_LIBRARY_PATH", "LD_PRELOAD", "PATH"];
+
+/// Rules for the names of the keys
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamingConvention {
+    /// Strict SCREAMING_SNAKE_CASE: no leading digit, no double underscores and no trailing
+    /// underscore. It is off by default, double underscores are used as separators of nested
+    /// settings (e.g. `Logging__LogLevel` in ASP.NET)
+    pub strict: bool,
+    pub max_key_length: Option<usize>,
+    /// The keys should start with one of the prefixes (any key if it is empty)
+    pub prefixes: Vec<String>,
+    /// Names which the keys can't have (in any case)
+    pub reserved_names: Vec<String>,
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_key_length: None,
+            prefixes: Vec::new(),
+            reserved_names: DEFAULT_RESERVED_NAMES.map(String::from).to_vec(),
+        }
+    }
+}
+
+impl NamingConvention {
+    /// Returns the message about the first rule which the key breaks
+    fn violation(&self, key: &str) -> Option<String> {
+        if self.is_reserved(key) {
+            return Some(format!("The {key} key is a reserved name"));
+        }
+
+        if let Some(max_key_length) = self.max_key_length
+            && key.chars().count() > max_key_length
+        {
+            return Some(format!(
+                "The {key} key is longer than {max_key_length} characters"
+            ));
+        }
+
+        if self.strict {
+            if key.starts_with(|c: char| c.is_ascii_digit()) {
+                return Some(format!("The {key} key should not start with a digit"));
+            }
+
+            if key.contains("__") {
+                return Some(format!(
+                    "The {key} key should not contain double underscores"
+                ));
+            }
+
+            if key.len() > 1 && key.ends_with('_') {
+                return Some(format!("The {key} key should not end with an underscore"));
+            }
+        }
+
+        if !self.has_prefix(key) {
+            return Some(format!(
+                "The {key} key should start with {}",
+                self.prefixes.join(" or ")
+            ));
+        }
+
+        None
+    }
+
+    /// Returns the name which follows the convention if there is only one such name: double and
+    /// trailing underscores are removed and the prefix is added if there is only one
+    pub(crate) fn rename(&self, key: &str) -> Option<String> {
+        if self.is_reserved(key) || key.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut renamed = key.to_string();
+        if self.strict {
+            while renamed.contains("__") {
+                renamed = renamed.replace("__", "_");
+            }
+            renamed = renamed.trim_end_matches('_').to_string();
+        }
+
+        if !self.has_prefix(&renamed) {
+            let [prefix] = self.prefixes.as_slice() else {
+                return None;
+            };
+            renamed.insert_str(0, prefix);
+        }
+
+        (renamed != key && !renamed.is_empty() && self.violation(&renamed).is_none())
+            .then_some(renamed)
+    }
+
+    fn is_reserved(&self, key: &str) -> bool {
+        self.reserved_names
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(key))
+    }
+
+    fn has_prefix(&self, key: &str) -> bool {
+        self.prefixes.is_empty()
+            || self
+                .prefixes
+                .iter()
+                .any(|prefix| key.starts_with(prefix.as_str()))
+    }
+}
+
+pub(crate) struct NamingConventionChecker<'a> {
+    convention: Cow<'a, NamingConvention>,
+}
+
+impl<'a> NamingConventionChecker<'a> {
+    pub fn new(convention: Option<&'a NamingConvention>) -> Self {
+        Self {
+            convention: convention.map_or_else(Cow::default, Cow::Borrowed),
+        }
+    }
+}
+
+impl Check for NamingConventionChecker<'_> {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        // Keys with an invalid leading character are reported by `LeadingCharacterChecker`
+        if !line
+            .raw_string
+            .starts_with(|c: char| c.is_alphabetic() || c == '_')
+        {
+            return None;
+        }
+
+        let message = self.convention.violation(line.get_key()?)?;
+        Some(Warning::new(line.number, self.name(), message))
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::NamingConvention
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_test;
+
+    #[test]
+    fn default_convention_test() {
+        check_test(
+            &mut NamingConventionChecker::new(None),
+            [
+                ("FOO_BAR=1", None),
+                ("_FOO=1", None),
+                ("export 9FOO=1", None),
+                ("Logging__LogLevel=Debug", None),
+                ("FOO_=1", None),
+                ("PATH=/bin", Some("The PATH key is a reserved name")),
+                ("export Home=/root", Some("The Home key is a reserved name")),
+            ],
+        );
+    }
+
+    #[test]
+    fn strict_convention_test() {
+        let convention = NamingConvention {
+            strict: true,
+            ..NamingConvention::default()
+        };
+
+        check_test(
+            &mut NamingConventionChecker::new(Some(&convention)),
+            [
+                ("FOO_BAR=1", None),
+                ("_FOO=1", None),
+                ("9FOO=1", None),
+                (
+                    "export 9FOO=1",
+                    Some("The 9FOO key should not start with a digit"),
+                ),
+                (
+                    "FOO__BAR=1",
+                    Some("The FOO__BAR key should not contain double underscores"),
+                ),
+                (
+                    "FOO_=1",
+                    Some("The FOO_ key should not end with an underscore"),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn without_reserved_names_test() {
+        let convention = NamingConvention {
+            reserved_names: Vec::new(),
+            ..NamingConvention::default()
+        };
+
+        check_test(
+            &mut NamingConventionChecker::new(Some(&convention)),
+            [("FOO__BAR_=1", None), ("PATH=/bin", None)],
+        );
+    }
+
+    #[test]
+    fn max_key_length_test() {
+        let convention = NamingConvention {
+            max_key_length: Some(5),
+            ..NamingConvention::default()
+        };
+
+        check_test(
+            &mut NamingConventionChecker::new(Some(&convention)),
+            [
+                ("FOO=1", None),
+                (
+                    "FOOBAR=1",
+                    Some("The FOOBAR key is longer than 5 characters"),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn prefixes_test() {
+        let convention = NamingConvention {
+            prefixes: vec!["NEXT_PUBLIC_".to_string(), "VITE_".to_string()],
+            ..NamingConvention::default()
+        };
+
+        check_test(
+            &mut NamingConventionChecker::new(Some(&convention)),
+            [
+                ("NEXT_PUBLIC_FOO=1", None),
+                ("VITE_FOO=1", None),
+                (
+                    "FOO=1",
+                    Some("The FOO key should start with NEXT_PUBLIC_ or VITE_"),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn rename_test() {
+        let convention = NamingConvention {
+            strict: true,
+            prefixes: vec!["VITE_".to_string()],
+            ..NamingConvention::default()
+        };
+
+        assert_eq!(
+            Some("VITE_FOO_BAR".to_string()),
+            convention.rename("VITE_FOO__BAR_")
+        );
+        assert_eq!(Some("VITE_FOO".to_string()), convention.rename("FOO"));
+        assert_eq!(None, convention.rename("VITE_FOO"));
+        assert_eq!(None, convention.rename("PATH"));
+        assert_eq!(None, convention.rename("9FOO"));
+
+        let convention = NamingConvention {
+            prefixes: vec!["NEXT_PUBLIC_".to_string(), "VITE_".to_string()],
+            ..NamingConvention::default()
+        };
+        assert_eq!(None, convention.rename("FOO"));
+    }
+}
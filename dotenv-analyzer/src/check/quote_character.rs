@@ -24,7 +24,8 @@ fn default() -> Self {
 impl Check for QuoteCharacterChecker<'_> {
     fn run(&mut self, line: &LineEntry) -> Option<Warning> {
         let val = line.get_value()?;
-        if val.contains("\\n")
+        // Escape sequences (e.g. `\n` or `\$`) are read only in quotes
+        if val.contains('\\')
             || val.contains(char::is_whitespace)
             || val.contains('$')
             || val.contains('#')
@@ -118,6 +119,18 @@ fn with_comments_test() {
         );
     }
 
+    #[test]
+    fn with_escape_sequences_test() {
+        check_test(
+            &mut QuoteCharacterChecker::default(),
+            [
+                ("FOO=\"a\\nb\"", None),
+                ("FOO=\"C:\\\\app\"", None),
+                ("FOO=\"\\${HOME}\"", None),
+            ],
+        );
+    }
+
     #[test]
     fn with_no_quotes_test() {
         check_test(&mut QuoteCharacterChecker::default(), [("FOO=BAR", None)]);
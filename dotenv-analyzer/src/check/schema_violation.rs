@@ -30,7 +30,7 @@ fn run(&mut self, line: &LineEntry) -> Option<Warning> {
         self.seen_keys.insert(key.to_string());
         let value = line.get_value()?;
 
-        let Some(entry) = schema.entries.get(key) else {
+        let Some(entry) = schema.entry_for(key) else {
             if schema.allow_other_keys {
                 return None;
             }
@@ -42,6 +42,17 @@ fn run(&mut self, line: &LineEntry) -> Option<Warning> {
             ));
         };
 
+        if entry.deprecated {
+            let message = match &entry.replaced_by {
+                Some(replacement) => {
+                    format!("The {key} key is deprecated, use {replacement} instead")
+                }
+                None => format!("The {key} key is deprecated"),
+            };
+
+            return Some(Warning::new(line.number, self.name(), message));
+        }
+
         let ValidateResult::Invalid(ty) = entry.is_valid(value) else {
             return None;
         };
@@ -78,7 +89,7 @@ fn end(&mut self) -> Vec<Warning> {
         let mut warnings = Vec::new();
         if let Some(schema) = self.schema {
             for (key, entry) in &schema.entries {
-                if entry.required && !self.seen_keys.contains(key) {
+                if entry.required && !entry.is_pattern(key) && !self.seen_keys.contains(key) {
                     warnings.push(Warning::new(
                         self.last_line_number,
                         self.name(),
@@ -0,0 +1,221 @@
+use dotenv_core::LineEntry;
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+/// Characters which can't be seen or look like a usual space, with their names
+const INVISIBLE_CHARACTERS: [(char, &str); 28] = [
+    ('\u{00A0}', "NO-BREAK SPACE"),
+    ('\u{00AD}', "SOFT HYPHEN"),
+    ('\u{034F}', "COMBINING GRAPHEME JOINER"),
+    ('\u{061C}', "ARABIC LETTER MARK"),
+    ('\u{180E}', "MONGOLIAN VOWEL SEPARATOR"),
+    ('\u{200B}', "ZERO WIDTH SPACE"),
+    ('\u{200C}', "ZERO WIDTH NON-JOINER"),
+    ('\u{200D}', "ZERO WIDTH JOINER"),
+    ('\u{200E}', "LEFT-TO-RIGHT MARK"),
+    ('\u{200F}', "RIGHT-TO-LEFT MARK"),
+    ('\u{202A}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202B}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202C}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202D}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202E}', "RIGHT-TO-LEFT OVERRIDE"),
+    ('\u{202F}', "NARROW NO-BREAK SPACE"),
+    ('\u{2060}', "WORD JOINER"),
+    ('\u{2061}', "FUNCTION APPLICATION"),
+    ('\u{2062}', "INVISIBLE TIMES"),
+    ('\u{2063}', "INVISIBLE SEPARATOR"),
+    ('\u{2064}', "INVISIBLE PLUS"),
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+    ('\u{2069}', "POP DIRECTIONAL ISOLATE"),
+    ('\u{3000}', "IDEOGRAPHIC SPACE"),
+    ('\u{3164}', "HANGUL FILLER"),
+    ('\u{FEFF}', "ZERO WIDTH NO-BREAK SPACE"),
+];
+
+/// Invisible characters which can't be just removed, they separate words
+const SPACES: [char; 3] = ['\u{00A0}', '\u{202F}', '\u{3000}'];
+
+/// Joiners which are a part of emoji sequences and of some scripts (e.g. Persian or Hindi)
+const JOINERS: [char; 2] = ['\u{200C}', '\u{200D}'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Self> {
+        match c {
+            'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' if c.is_alphabetic() => {
+                Some(Self::Latin)
+            }
+            '\u{0370}'..='\u{03FF}' if c.is_alphabetic() => Some(Self::Greek),
+            '\u{0400}'..='\u{052F}' if c.is_alphabetic() => Some(Self::Cyrillic),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the invisible characters of the string with their byte positions and names. Joiners
+/// between non-ASCII characters are not included, they are used in emoji and in some scripts.
+pub(crate) fn invisible_characters(s: &str) -> Vec<(usize, char, &'static str)> {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(index, (_, c))| {
+            let is_joined = |c: Option<&(usize, char)>| {
+                c.is_some_and(|(_, c)| !c.is_ascii() && !is_invisible(*c))
+            };
+
+            !(JOINERS.contains(c)
+                && index > 0
+                && is_joined(chars.get(index - 1))
+                && is_joined(chars.get(index + 1)))
+        })
+        .filter_map(|(_, &(position, c))| {
+            let (_, name) = INVISIBLE_CHARACTERS.iter().find(|(i, _)| *i == c)?;
+            Some((position, c, *name))
+        })
+        .collect()
+}
+
+/// Checks if the character is one of the invisible characters reported by the check
+pub(crate) fn is_invisible(c: char) -> bool {
+    INVISIBLE_CHARACTERS.iter().any(|(i, _)| *i == c)
+}
+
+/// Checks if the invisible character can be removed without joining words
+pub(crate) fn is_removable(c: char) -> bool {
+    !SPACES.contains(&c)
+}
+
+/// Returns the scripts of the first word mixing letters of different scripts
+fn mixed_scripts(value: &str) -> Option<(Script, Script)> {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .find_map(|word| {
+            let mut scripts = word.chars().filter_map(Script::of);
+            let first = scripts.next()?;
+            scripts
+                .find(|&script| script != first)
+                .map(|script| (first, script))
+        })
+}
+
+#[derive(Default)]
+pub(crate) struct SuspiciousCharacterChecker {}
+
+impl Check for SuspiciousCharacterChecker {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        if let Some((_, c, name)) = invisible_characters(&line.raw_string).first() {
+            let message = format!(
+                "Invisible character U+{:04X} ({name}) detected",
+                u32::from(*c)
+            );
+            return Some(Warning::new(line.number, self.name(), message));
+        }
+
+        let key = line.get_key()?;
+        let message = if let Some(c) = key.chars().find(|c| !c.is_ascii()) {
+            format!(
+                "The {key} key has a non-ASCII character U+{:04X}",
+                u32::from(c)
+            )
+        } else {
+            let (first, second) = mixed_scripts(line.get_value()?)?;
+            format!("The value of the {key} key mixes {first:?} and {second:?} letters in a word")
+        };
+
+        Some(Warning::new(line.number, self.name(), message))
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::SuspiciousCharacter
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_test;
+
+    #[test]
+    fn working_run() {
+        check_test(
+            &mut SuspiciousCharacterChecker::default(),
+            [
+                ("FOO=BAR", None),
+                ("FOO=Привет мир", None),
+                ("FOO=hello мир", None),
+                ("FOO=👨\u{200D}👩\u{200D}👧", None),
+                ("# Привет", None),
+            ],
+        );
+    }
+
+    #[test]
+    fn invisible_characters_test() {
+        check_test(
+            &mut SuspiciousCharacterChecker::default(),
+            [
+                (
+                    "FOO\u{200B}=BAR",
+                    Some("Invisible character U+200B (ZERO WIDTH SPACE) detected"),
+                ),
+                (
+                    "FOO=BAR\u{00A0}BAZ",
+                    Some("Invisible character U+00A0 (NO-BREAK SPACE) detected"),
+                ),
+                (
+                    "FOO=\u{202E}RAB",
+                    Some("Invisible character U+202E (RIGHT-TO-LEFT OVERRIDE) detected"),
+                ),
+                (
+                    "# Comment\u{200D}",
+                    Some("Invisible character U+200D (ZERO WIDTH JOINER) detected"),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn non_ascii_key_test() {
+        check_test(
+            &mut SuspiciousCharacterChecker::default(),
+            [(
+                "F\u{041E}O=BAR",
+                Some("The F\u{041E}O key has a non-ASCII character U+041E"),
+            )],
+        );
+    }
+
+    #[test]
+    fn mixed_scripts_test() {
+        check_test(
+            &mut SuspiciousCharacterChecker::default(),
+            [
+                (
+                    "PASSWORD=p\u{0430}ssword",
+                    Some(
+                        "The value of the PASSWORD key mixes Latin and Cyrillic letters in a word",
+                    ),
+                ),
+                (
+                    "HOST=\u{03BF}k.com",
+                    Some("The value of the HOST key mixes Greek and Latin letters in a word"),
+                ),
+            ],
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use dotenv_core::{LineEntry, is_escaped, unclosed_quote};
+
+use super::Check;
+use crate::{LintKind, Warning};
+
+#[derive(Default)]
+pub(crate) struct SyntaxErrorChecker {}
+
+impl Check for SyntaxErrorChecker {
+    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+        let key = line.get_key()?;
+        let value = line.get_value()?;
+
+        // Multi-line values are already joined, so the quote isn't closed at all
+        let message = match unclosed_quote(value) {
+            Some('"') => format!("The {key} key has an unterminated double quote"),
+            Some(_) => format!("The {key} key has an unterminated single quote"),
+            None if !value.trim_start().starts_with(['"', '\'']) && is_escaped(value) => {
+                format!("The {key} key has a trailing unescaped backslash")
+            }
+            None => return None,
+        };
+
+        Some(Warning::new(line.number, self.name(), message))
+    }
+
+    fn name(&self) -> LintKind {
+        LintKind::SyntaxError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_test;
+
+    #[test]
+    fn quoted_values_test() {
+        check_test(
+            &mut SyntaxErrorChecker::default(),
+            [
+                ("FOO=\"BAR\"", None),
+                ("FOO='BAR'", None),
+                ("FOO=\"multi\nline\"", None),
+                ("FOO=\"BAR\" # comment", None),
+                ("FOO='BAR \\' BAZ'", None),
+            ],
+        );
+    }
+
+    #[test]
+    fn unterminated_quotes_test() {
+        check_test(
+            &mut SyntaxErrorChecker::default(),
+            [
+                (
+                    "FOO=\"BAR",
+                    Some("The FOO key has an unterminated double quote"),
+                ),
+                (
+                    "FOO='BAR",
+                    Some("The FOO key has an unterminated single quote"),
+                ),
+                (
+                    "FOO=\"BAR\\\"",
+                    Some("The FOO key has an unterminated double quote"),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn trailing_backslash_test() {
+        check_test(
+            &mut SyntaxErrorChecker::default(),
+            [
+                ("FOO=BAR\\\\", None),
+                ("FOO=BAR\\ ", None),
+                (
+                    "FOO=BAR\\",
+                    Some("The FOO key has a trailing unescaped backslash"),
+                ),
+            ],
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::ops::Range;
+
+use dotenv_core::{LineEnding, LineEntry};
+
+/// A text edit which fixes a warning: replaces a range of line entries with new lines
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit {
+    range: Range<usize>,
+    lines: Vec<String>,
+    ending: Option<LineEnding>,
+    bom: Option<bool>,
+}
+
+impl Edit {
+    /// Creates an edit which replaces the line entries with the indexes in `range`.
+    /// An empty range inserts the lines, empty `lines` delete the range.
+    /// The new lines keep the line endings (and the byte order mark) of the replaced lines.
+    pub fn new(range: Range<usize>, lines: Vec<String>) -> Self {
+        Self {
+            range,
+            lines,
+            ending: None,
+            bom: None,
+        }
+    }
+
+    /// Sets the line ending of the new lines
+    pub fn with_ending(self, ending: LineEnding) -> Self {
+        Self {
+            ending: Some(ending),
+            ..self
+        }
+    }
+
+    /// Sets whether the first of the new lines has the byte order mark
+    pub fn with_bom(self, bom: bool) -> Self {
+        Self {
+            bom: Some(bom),
+            ..self
+        }
+    }
+
+    /// Creates an edit which replaces a single line entry
+    pub fn replace(index: usize, line: impl Into<String>) -> Self {
+        Self::new(index..index + 1, vec![line.into()])
+    }
+
+    /// Creates an edit which deletes a single line entry
+    pub fn delete(index: usize) -> Self {
+        Self::new(index..index + 1, Vec::new())
+    }
+
+    /// Creates the smallest edit which turns `old` lines into `new` lines, if they differ
+    pub fn between(old: &[LineEntry], new: &[LineEntry]) -> Option<Self> {
+        let prefix = old
+            .iter()
+            .zip(new)
+            .take_while(|(a, b)| a.raw_string == b.raw_string)
+            .count();
+        if prefix == old.len() && prefix == new.len() {
+            return None;
+        }
+
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a.raw_string == b.raw_string)
+            .count();
+
+        let lines = new[prefix..new.len() - suffix]
+            .iter()
+            .map(|line| line.raw_string.clone())
+            .collect();
+
+        Some(Self::new(prefix..old.len() - suffix, lines))
+    }
+
+    /// Indexes of the replaced line entries
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    /// Lines which replace the range
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Creates the new line with the offset: it takes the line ending of the replaced line with
+    /// the same offset (the last replaced line or the previous line if there is no such line)
+    fn line_entry(&self, lines: &[LineEntry], offset: usize) -> LineEntry {
+        let Range { start, end } = self.range;
+        let template = lines
+            .get(start + offset)
+            .filter(|_| start + offset < end)
+            .or_else(|| lines.get(start..end).and_then(<[LineEntry]>::last))
+            .or_else(|| start.checked_sub(1).and_then(|index| lines.get(index)));
+
+        let ending = self
+            .ending
+            .or(template.map(|line| line.ending))
+            .unwrap_or_default();
+        let bom = self
+            .bom
+            .unwrap_or(start < end && lines.get(start).is_some_and(|line| line.bom));
+
+        LineEntry {
+            ending,
+            bom: bom && offset == 0,
+            ..LineEntry::new(0, self.lines[offset].as_str(), false)
+        }
+    }
+
+    pub(crate) fn overlaps(&self, other: &Self) -> bool {
+        // Two insertions at the same place conflict, because their order is unknown
+        if self.range.is_empty() && other.range.is_empty() {
+            return self.range.start == other.range.start;
+        }
+
+        self.range.start < other.range.end && other.range.start < self.range.end
+    }
+}
+
+/// Applies the edits which don't overlap with each other (an edit which conflicts with one
+/// of the previous edits is skipped) and renumbers the lines.
+/// Returns the applied edits, equal edits are applied once.
+pub(crate) fn apply_edits(lines: &mut Vec<LineEntry>, edits: &[Edit]) -> Vec<Edit> {
+    let mut applied: Vec<Edit> = Vec::with_capacity(edits.len());
+    for edit in edits {
+        if applied.iter().any(|e| e == edit || e.overlaps(edit)) {
+            continue;
+        }
+
+        applied.push(edit.clone());
+    }
+
+    // Edits are applied from the end, so the indexes of the next edits stay valid
+    applied.sort_by_key(|edit| (edit.range.start, edit.range.end));
+    for edit in applied.iter().rev() {
+        let new_lines: Vec<LineEntry> = (0..edit.lines.len())
+            .map(|offset| edit.line_entry(lines, offset))
+            .collect();
+        lines.splice(edit.range.clone(), new_lines);
+    }
+
+    renumber(lines);
+    applied
+}
+
+/// Updates the numbers of the lines: an entry with a multi-line value takes several lines
+fn renumber(lines: &mut [LineEntry]) {
+    let total = lines.len();
+    let mut number = 1;
+
+    for (index, line) in lines.iter_mut().enumerate() {
+        line.number = number;
+        line.is_last_line = index + 1 == total;
+        number += line.raw_string.matches('\n').count() + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::line_entry;
+
+    fn lines(raw: &[&str]) -> Vec<LineEntry> {
+        (1..)
+            .zip(raw)
+            .map(|(number, line)| line_entry(number, raw.len(), line))
+            .collect()
+    }
+
+    fn raw(lines: &[LineEntry]) -> Vec<&str> {
+        lines.iter().map(|line| line.raw_string.as_str()).collect()
+    }
+
+    #[test]
+    fn between_test() {
+        let old = lines(&["A=1", "C=3", "B=2", "D=4"]);
+        let new = lines(&["A=1", "B=2", "C=3", "D=4"]);
+
+        assert_eq!(
+            Edit::between(&old, &new),
+            Some(Edit::new(1..3, vec!["B=2".to_string(), "C=3".to_string()]))
+        );
+        assert_eq!(Edit::between(&old, &old), None);
+    }
+
+    #[test]
+    fn apply_edits_test() {
+        let mut lines = lines(&["a=1", "", "", "B=2 ", "C=3"]);
+        let edits = [
+            Edit::replace(0, "A=1"),
+            Edit::delete(2),
+            Edit::replace(3, "B=2"),
+            Edit::replace(3, "B=2"),
+            Edit::new(5..5, vec!["\n".to_string()]),
+        ];
+
+        let applied = apply_edits(&mut lines, &edits);
+
+        assert_eq!(applied.len(), 4);
+        assert_eq!(raw(&lines), ["A=1", "", "B=2", "C=3", "\n"]);
+        assert_eq!(lines[4].number, 5);
+        assert!(lines[4].is_last_line);
+        assert!(!lines[3].is_last_line);
+    }
+
+    #[test]
+    fn conflicting_edits_test() {
+        let mut lines = lines(&["b=2", "A=1"]);
+        let edits = [
+            Edit::new(0..2, vec!["A=1".to_string(), "b=2".to_string()]),
+            Edit::replace(0, "B=2"),
+        ];
+
+        let applied = apply_edits(&mut lines, &edits);
+
+        assert_eq!(applied, &edits[..1]);
+        assert_eq!(raw(&lines), ["A=1", "b=2"]);
+    }
+
+    #[test]
+    fn line_endings_test() {
+        let mut lines = lines(&["a=1", "B=2 ", "C=3"]);
+        lines[0].bom = true;
+        lines[0].ending = LineEnding::CrLf;
+        lines[1].ending = LineEnding::CrLf;
+        let edits = [
+            Edit::new(
+                0..2,
+                vec!["A=1".to_string(), "B=2".to_string(), "X=0".to_string()],
+            ),
+            Edit::replace(2, "C=3").with_ending(LineEnding::CrLf),
+        ];
+
+        apply_edits(&mut lines, &edits);
+
+        let formats: Vec<(LineEnding, bool)> = lines.iter().map(|l| (l.ending, l.bom)).collect();
+        assert_eq!(
+            formats,
+            [
+                (LineEnding::CrLf, true),
+                (LineEnding::CrLf, false),
+                (LineEnding::CrLf, false),
+                (LineEnding::CrLf, false)
+            ]
+        );
+
+        apply_edits(&mut lines, &[Edit::replace(0, "A=1").with_bom(false)]);
+        assert!(!lines[0].bom);
+    }
+
+    #[test]
+    fn renumber_multiline_test() {
+        let mut lines = lines(&["A=\"1\n2\"", "B=3"]);
+        renumber(&mut lines);
+
+        assert_eq!(lines[1].number, 3);
+    }
+}
@@ -0,0 +1,48 @@
+use dotenv_core::LineEntry;
+
+use super::{Fix, line_index};
+use crate::{Edit, LintKind};
+
+#[derive(Default)]
+pub(crate) struct ByteOrderMarkFixer {}
+
+impl Fix for ByteOrderMarkFixer {
+    fn name(&self) -> LintKind {
+        LintKind::ByteOrderMark
+    }
+
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        warning_lines
+            .iter()
+            .filter_map(|&number| {
+                let index = line_index(lines, number)?;
+                let edit = Edit::replace(index, lines[index].raw_string.as_str()).with_bom(false);
+
+                Some((number, edit))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::tests::apply_fix_edits;
+    use crate::tests::*;
+
+    #[test]
+    fn fix_warnings_test() {
+        let fixer = ByteOrderMarkFixer::default();
+        let mut lines = vec![
+            LineEntry {
+                bom: true,
+                ..line_entry(1, 2, "A=1")
+            },
+            blank_line_entry(2, 2),
+        ];
+
+        assert_eq!(1, apply_fix_edits(&fixer, &[1], &mut lines));
+        assert_eq!("A=1", lines[0].raw_string);
+        assert!(!lines[0].bom);
+    }
+}
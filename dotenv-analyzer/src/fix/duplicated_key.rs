@@ -1,9 +1,7 @@
-use std::collections::HashSet;
-
 use dotenv_core::LineEntry;
 
 use super::Fix;
-use crate::{LintKind, comment::Comment};
+use crate::LintKind;
 
 #[derive(Default)]
 pub(crate) struct DuplicatedKeyFixer {}
@@ -13,31 +11,8 @@ fn name(&self) -> LintKind {
         LintKind::DuplicatedKey
     }
 
-    fn fix_warnings(&self, warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> Option<usize> {
-        let mut keys = HashSet::with_capacity(lines.len());
-        let mut is_disabled = false;
-
-        for line in lines {
-            if let Some(comment) = line.get_comment().and_then(Comment::parse)
-                && comment.checks.contains(&self.name())
-            {
-                is_disabled = comment.is_disabled();
-            }
-
-            if is_disabled {
-                continue;
-            }
-
-            if let Some(key) = line.get_key() {
-                if keys.contains(key) {
-                    self.fix_line(line)?;
-                } else {
-                    keys.insert(key.to_string());
-                }
-            }
-        }
-
-        Some(warning_lines.len())
+    fn is_safe(&self) -> bool {
+        false
     }
 
     fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
@@ -45,15 +20,12 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 
         Some(())
     }
-
-    fn is_mandatory(&self) -> bool {
-        true
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::line_entry;
 
     #[test]
@@ -67,7 +39,7 @@ fn fix_warnings() {
         ];
         let warning_lines = [lines[2].number, lines[3].number];
 
-        assert_eq!(Some(2), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(2, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         // what needed to be changed is changed
         assert_eq!(lines[2], line_entry(3, 4, "# FOO=BAZ"));
         assert_eq!(lines[3], line_entry(4, 4, "# Z=X"));
@@ -79,7 +51,7 @@ fn fix_warnings() {
     }
 
     #[test]
-    fn fix_lines_without_warnings() {
+    fn fix_interleaved_duplicates() {
         let fixer = DuplicatedKeyFixer::default();
         let mut lines = vec![
             line_entry(1, 4, "FOO=BAR"),
@@ -87,9 +59,9 @@ fn fix_lines_without_warnings() {
             line_entry(3, 4, "Z=Y"),
             line_entry(4, 4, "Z=X"),
         ];
-        let warning_lines = [];
+        let warning_lines = [2, 4];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(2, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=BAR", lines[0].raw_string);
         assert_eq!("# FOO=BAZ", lines[1].raw_string);
         assert_eq!("Z=Y", lines[2].raw_string);
@@ -108,7 +80,7 @@ fn control_comment_at_first_line() {
         ];
         let warning_lines = [];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(0, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("# dotenv-linter:off DuplicatedKey", lines[0].raw_string);
         assert_eq!("FOO=BAR", lines[1].raw_string);
         assert_eq!("FOO=BAZ", lines[2].raw_string);
@@ -129,7 +101,7 @@ fn control_comment_in_the_middle() {
         ];
         let warning_lines = [];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(0, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=BAR", lines[0].raw_string);
         assert_eq!("# dotenv-linter:off DuplicatedKey", lines[1].raw_string);
         assert_eq!("FOO=BAZ", lines[2].raw_string);
@@ -148,9 +120,9 @@ fn unrelated_control_comment() {
             line_entry(4, 5, "Z=Y"),
             line_entry(5, 5, "Z=X"),
         ];
-        let warning_lines = [];
+        let warning_lines = [3, 5];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(2, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("# dotenv-linter:off LowercaseKey", lines[0].raw_string);
         assert_eq!("FOO=BAR", lines[1].raw_string);
         assert_eq!("# FOO=BAZ", lines[2].raw_string);
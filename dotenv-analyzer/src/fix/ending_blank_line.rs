@@ -1,7 +1,7 @@
 use dotenv_core::LineEntry;
 
 use super::Fix;
-use crate::{LF, LintKind};
+use crate::{Edit, LF, LintKind};
 
 #[derive(Default)]
 pub(crate) struct EndingBlankLineFixer {}
@@ -11,22 +11,26 @@ fn name(&self) -> LintKind {
         LintKind::EndingBlankLine
     }
 
-    fn fix_warnings(&self, _: &[usize], lines: &mut Vec<LineEntry>) -> Option<usize> {
-        let last_line = lines.last()?;
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        let (Some(&number), Some(last_line)) = (warning_lines.first(), lines.last()) else {
+            return Vec::new();
+        };
 
         if last_line.raw_string.ends_with(LF) {
-            return Some(0);
+            return Vec::new();
         }
 
-        lines.push(LineEntry::new(lines.len() + 1, LF, true));
-
-        Some(1)
+        vec![(
+            number,
+            Edit::new(lines.len()..lines.len(), vec![LF.to_string()]),
+        )]
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::line_entry;
 
     #[test]
@@ -35,7 +39,7 @@ fn fix_warnings_test() {
         let mut lines = vec![line_entry(1, 2, "FOO=BAR"), line_entry(2, 2, "Z=Y")];
         let warning_lines = [lines[1].number];
 
-        assert_eq!(Some(1), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(1, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("\n", lines[2].raw_string);
     }
 
@@ -44,7 +48,7 @@ fn ending_blank_line_exist_test() {
         let fixer = EndingBlankLineFixer::default();
         let mut lines = vec![line_entry(1, 2, "FOO=BAR"), line_entry(2, 2, LF)];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&[], &mut lines));
+        assert_eq!(0, apply_fix_edits(&fixer, &[], &mut lines));
         assert_eq!(lines.len(), 2);
     }
 }
@@ -1,7 +1,7 @@
 use dotenv_core::LineEntry;
 
-use super::Fix;
-use crate::LintKind;
+use super::{Fix, line_index};
+use crate::{Edit, LintKind};
 
 #[derive(Default)]
 pub(crate) struct ExtraBlankLineFixer {}
@@ -11,15 +11,23 @@ fn name(&self) -> LintKind {
         LintKind::ExtraBlankLine
     }
 
-    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
-        line.mark_as_deleted();
-        Some(())
+    // The blank line before the extra one is deleted, so the last entry of a file which holds
+    // its final LF is kept
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        warning_lines
+            .iter()
+            .filter_map(|&number| {
+                let index = line_index(lines, number)?.checked_sub(1)?;
+                Some((number, Edit::delete(index)))
+            })
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::line_entry;
 
     #[test]
@@ -32,7 +40,7 @@ fn no_blank_lines_test() {
         ];
         let warning_lines = [];
 
-        assert_eq!(Some(0), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(0, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!(lines, lines);
     }
 
@@ -47,7 +55,7 @@ fn fix_one_extra_blank_line_test() {
         ];
         let warning_lines = [lines[2].number];
 
-        assert_eq!(Some(1), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(1, apply_fix_edits(&fixer, &warning_lines, &mut lines));
     }
 
     #[test]
@@ -62,6 +70,6 @@ fn fix_multiple_blank_lines_test() {
         ];
         let warning_lines = [lines[2].number, lines[3].number];
 
-        assert_eq!(Some(2), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(2, apply_fix_edits(&fixer, &warning_lines, &mut lines));
     }
 }
@@ -1,7 +1,7 @@
 use dotenv_core::LineEntry;
 
 use super::Fix;
-use crate::{LintKind, remove_invalid_leading_chars};
+use crate::{LintKind, check, remove_invalid_leading_chars};
 
 #[derive(Default)]
 pub(crate) struct IncorrectDelimiterFixer {}
@@ -17,7 +17,10 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
         let cleaned_key = remove_invalid_leading_chars(key);
         let start_idx = key.len() - cleaned_key.len();
 
-        let cleaned_key = key[start_idx..].replace(|c: char| !c.is_alphanumeric(), "_");
+        let cleaned_key = key[start_idx..].replace(
+            |c: char| !c.is_alphanumeric() && !check::is_invisible(c),
+            "_",
+        );
 
         line.raw_string = format!("{}{}={}", &key[..start_idx], cleaned_key, line.get_value()?);
 
@@ -28,6 +31,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::{blank_line_entry, line_entry};
 
     #[test]
@@ -67,7 +71,7 @@ fn fix_warnings_test() {
         ];
         let warning_lines = [lines[0].number];
 
-        assert_eq!(Some(1), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(1, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("RAILS_ENV=development", lines[0].raw_string);
     }
 }
@@ -25,6 +25,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::*;
 
     #[test]
@@ -109,7 +110,7 @@ fn fix_warnings_test() {
             lines[3].number,
         ];
 
-        assert_eq!(Some(4), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(4, apply_fix_edits(&fixer, &warning_lines, &mut lines));
 
         assert_eq!("FOO=BAR", lines[0].raw_string);
         assert_eq!("Z=Y", lines[1].raw_string);
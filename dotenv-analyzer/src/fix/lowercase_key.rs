@@ -1,7 +1,7 @@
 use dotenv_core::LineEntry;
 
-use super::Fix;
-use crate::LintKind;
+use super::{Fix, line_index};
+use crate::{Edit, LintKind};
 
 #[derive(Default)]
 pub(crate) struct LowercaseKeyFixer {}
@@ -11,6 +11,33 @@ fn name(&self) -> LintKind {
         LintKind::LowercaseKey
     }
 
+    fn is_safe(&self) -> bool {
+        false
+    }
+
+    // A key which differs from another key only in case would become a duplicate of it,
+    // so it is not fixed (see `CaseInsensitiveDuplicatedKeyChecker`)
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        warning_lines
+            .iter()
+            .filter_map(|&number| {
+                let index = line_index(lines, number)?;
+                let key = lines[index].get_key()?;
+                let collides = lines
+                    .iter()
+                    .filter_map(LineEntry::get_key)
+                    .any(|other| other != key && other.to_uppercase() == key.to_uppercase());
+                if collides {
+                    return None;
+                }
+
+                let mut line = lines[index].clone();
+                self.fix_line(&mut line)?;
+                Some((number, Edit::replace(index, line.raw_string)))
+            })
+            .collect()
+    }
+
     fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
         let key = line.get_key()?;
         let key = key.to_uppercase();
@@ -23,6 +50,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::*;
 
     #[test]
@@ -44,7 +72,24 @@ fn fix_warnings_test() {
         ];
         let warning_lines = [lines[0].number];
 
-        assert_eq!(Some(1), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(1, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=BAR", lines[0].raw_string);
     }
+
+    #[test]
+    fn keys_differing_in_case_are_not_fixed_test() {
+        let fixer = LowercaseKeyFixer::default();
+        let mut lines = vec![
+            line_entry(1, 5, "API_KEY=1"),
+            line_entry(2, 5, "api_key=2"),
+            line_entry(3, 5, "foo=3"),
+            line_entry(4, 5, "foo=4"),
+            blank_line_entry(5, 5),
+        ];
+
+        assert_eq!(2, apply_fix_edits(&fixer, &[2, 3, 4], &mut lines));
+        assert_eq!("api_key=2", lines[1].raw_string);
+        assert_eq!("FOO=3", lines[2].raw_string);
+        assert_eq!("FOO=4", lines[3].raw_string);
+    }
 }
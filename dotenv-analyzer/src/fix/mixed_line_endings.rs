@@ -0,0 +1,90 @@
+use dotenv_core::{LineEnding, LineEntry};
+
+use super::Fix;
+use crate::{Edit, LintKind};
+
+/// Changes the line endings to the configured one or, by default, to the line ending of the
+/// first line
+#[derive(Default)]
+pub(crate) struct MixedLineEndingsFixer {
+    ending: Option<LineEnding>,
+}
+
+impl MixedLineEndingsFixer {
+    pub(crate) fn new(ending: Option<LineEnding>) -> Self {
+        Self { ending }
+    }
+}
+
+impl Fix for MixedLineEndingsFixer {
+    fn name(&self) -> LintKind {
+        LintKind::MixedLineEndings
+    }
+
+    // The lines to change are not only the ones with warnings (if the configured line ending
+    // differs from the one of the first line), so a single edit changes all of them
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        let (Some(&number), Some(first)) = (warning_lines.first(), lines.first()) else {
+            return Vec::new();
+        };
+        let ending = self.ending.unwrap_or(first.ending);
+
+        let mut changed = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.ending != ending)
+            .map(|(index, _)| index);
+        let Some(start) = changed.next() else {
+            return Vec::new();
+        };
+        let end = changed.next_back().unwrap_or(start) + 1;
+
+        let new_lines = lines[start..end]
+            .iter()
+            .map(|line| line.raw_string.clone())
+            .collect();
+        vec![(number, Edit::new(start..end, new_lines).with_ending(ending))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::tests::apply_fix_edits;
+    use crate::tests::*;
+
+    fn lines(endings: &[LineEnding]) -> Vec<LineEntry> {
+        let total = endings.len() + 1;
+        endings
+            .iter()
+            .enumerate()
+            .map(|(index, &ending)| LineEntry {
+                ending,
+                ..line_entry(index + 1, total, "A=1")
+            })
+            .chain([blank_line_entry(total, total)])
+            .collect()
+    }
+
+    fn endings(lines: &[LineEntry]) -> Vec<LineEnding> {
+        lines[..lines.len() - 1].iter().map(|l| l.ending).collect()
+    }
+
+    #[test]
+    fn fix_to_first_line_ending() {
+        let fixer = MixedLineEndingsFixer::default();
+        let mut lines = lines(&[LineEnding::CrLf, LineEnding::Lf, LineEnding::CrLf]);
+
+        assert_eq!(1, apply_fix_edits(&fixer, &[2], &mut lines));
+        assert_eq!(endings(&lines), [LineEnding::CrLf; 3]);
+    }
+
+    #[test]
+    fn fix_to_configured_line_ending() {
+        let fixer = MixedLineEndingsFixer::new(Some(LineEnding::Lf));
+        let mut lines = lines(&[LineEnding::CrLf, LineEnding::Lf, LineEnding::CrLf]);
+
+        assert_eq!(1, apply_fix_edits(&fixer, &[2], &mut lines));
+        assert_eq!(endings(&lines), [LineEnding::Lf; 3]);
+    }
+}
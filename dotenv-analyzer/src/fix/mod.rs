@@ -1,7 +1,12 @@
-use dotenv_core::LineEntry;
+use dotenv_core::{LineEnding, LineEntry};
+use dotenv_schema::DotEnvSchema;
 
-use crate::{LintKind, Warning};
+use crate::{
+    LintKind, NamingConvention, Warning, check,
+    edit::{self, Edit},
+};
 
+mod byte_order_mark;
 mod duplicated_key;
 mod ending_blank_line;
 mod extra_blank_line;
@@ -9,47 +14,71 @@
 mod key_without_value;
 mod leading_character;
 mod lowercase_key;
+mod mixed_line_endings;
+mod naming_convention;
 mod quote_character;
+mod schema_violation;
 mod space_character;
 mod substitution_key;
+mod suspicious_character;
 mod trailing_whitespace;
 mod unordered_key;
 mod value_without_quotes;
 
+/// Maximum number of passes of checking and fixing. A fix can cause new warnings (e.g. a renamed
+/// key can be duplicated), so the file is checked again until no edits can be applied.
+const MAX_ITERATIONS: usize = 10;
+
 trait Fix {
     fn name(&self) -> LintKind;
 
-    fn fix_warnings(&self, warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> Option<usize> {
-        let mut count: usize = 0;
-        for line_number in warning_lines {
-            let line = lines
-                .iter_mut()
-                .find(|entry| entry.number == *line_number)?;
-            if self.fix_line(line).is_some() {
-                count += 1;
-            }
-        }
+    /// Returns `false` if the fix can change how the file is read (e.g. which value of a key wins),
+    /// so it is applied only with `unsafe_fixes`
+    fn is_safe(&self) -> bool {
+        true
+    }
 
-        Some(count)
+    /// Returns the edits fixing the warnings on the lines with the numbers, together with
+    /// these numbers. By default, each line is fixed separately by `fix_line`.
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        warning_lines
+            .iter()
+            .filter_map(|&number| {
+                let index = line_index(lines, number)?;
+                let mut line = lines[index].clone();
+                self.fix_line(&mut line)?;
+
+                Some((number, Edit::replace(index, line.raw_string)))
+            })
+            .collect()
     }
 
     fn fix_line(&self, _line: &mut LineEntry) -> Option<()> {
         None
     }
+}
 
-    fn is_mandatory(&self) -> bool {
-        false
-    }
+/// Returns the index of the line with the number
+fn line_index(lines: &[LineEntry], number: usize) -> Option<usize> {
+    lines.iter().position(|line| line.number == number)
 }
 
-// Fix order matters
-fn fixlist() -> Vec<Box<dyn Fix>> {
+fn fixlist<'a>(
+    schema: Option<&'a DotEnvSchema>,
+    naming: Option<&'a NamingConvention>,
+    line_ending: Option<LineEnding>,
+) -> Vec<Box<dyn Fix + 'a>> {
     vec![
-        // At first we run the fixers that handle a single line entry (they use default
-        // implementation of the fix_warnings() function)
+        Box::<byte_order_mark::ByteOrderMarkFixer>::default(),
+        Box::new(mixed_line_endings::MixedLineEndingsFixer::new(line_ending)),
+        Box::new(schema_violation::SchemaViolationFixer::new(schema)),
         Box::<key_without_value::KeyWithoutValueFixer>::default(),
         Box::<lowercase_key::LowercaseKeyFixer>::default(),
+        Box::new(naming_convention::NamingConventionFixer::new(naming)),
         Box::<space_character::SpaceCharacterFixer>::default(),
+        // The fix of whole lines replaces the fix of the key when unsafe fixes are applied
+        Box::new(suspicious_character::SuspiciousCharacterFixer::new(true)),
+        Box::new(suspicious_character::SuspiciousCharacterFixer::new(false)),
         Box::<trailing_whitespace::TrailingWhitespaceFixer>::default(),
         Box::<leading_character::LeadingCharacterFixer>::default(),
         Box::<value_without_quotes::ValueWithoutQuotesFixer>::default(),
@@ -57,46 +86,249 @@ fn fixlist() -> Vec<Box<dyn Fix>> {
         Box::<incorrect_delimiter::IncorrectDelimiterFixer>::default(),
         Box::<extra_blank_line::ExtraBlankLineFixer>::default(),
         Box::<substitution_key::SubstitutionKeyFixer>::default(),
-        // Then we should run the fixers that handle the line entry collection at whole
         Box::<unordered_key::UnorderedKeyFixer>::default(),
         Box::<duplicated_key::DuplicatedKeyFixer>::default(),
         Box::<ending_blank_line::EndingBlankLineFixer>::default(),
     ]
 }
 
-pub fn fix(warnings: &[Warning], lines: &mut Vec<LineEntry>, skip_checks: &[LintKind]) -> usize {
-    if warnings.is_empty() {
-        return 0;
+/// Warnings of the lines before and after fixing
+#[derive(Debug, Default)]
+pub struct FixResult {
+    /// Warnings of the original lines which are fixed
+    pub fixed: Vec<Warning>,
+    /// Warnings of the fixed lines which have unsafe fixes, skipped without `unsafe_fixes`
+    pub unsafe_skipped: Vec<Warning>,
+    /// Warnings of the fixed lines which fixes are not approved (see `fix_with`)
+    pub declined: Vec<Warning>,
+    /// Warnings of the fixed lines: the ones which can't be fixed and new ones caused by fixes
+    pub remaining: Vec<Warning>,
+}
+
+/// What `fix` and `fix_with` check and fix. By default all the checks are run and only
+/// the safe fixes are applied.
+#[derive(Clone, Copy)]
+pub struct FixConfig<'a> {
+    /// Checks which are not run at all
+    pub skip_checks: &'a [LintKind],
+    /// Checks which warnings are fixed
+    pub fix_checks: &'a [LintKind],
+    pub schema: Option<&'a DotEnvSchema>,
+    pub naming: Option<&'a NamingConvention>,
+    /// Apply unsafe fixes too (see `Fix::is_safe`)
+    pub unsafe_fixes: bool,
+    /// Line ending to change mixed line endings to, the line ending of the first line if it
+    /// isn't set
+    pub line_ending: Option<LineEnding>,
+}
+
+impl Default for FixConfig<'_> {
+    fn default() -> Self {
+        Self {
+            skip_checks: &[],
+            fix_checks: &LintKind::ALL,
+            schema: None,
+            naming: None,
+            unsafe_fixes: false,
+            line_ending: None,
+        }
     }
-    let mut fixes = fixlist();
+}
 
-    // Skip fixes for checks in --skip argument (globally)
-    fixes.retain(|f| !skip_checks.contains(&f.name()));
+/// Fixes the warnings of the lines. The lines are checked, the edits of the warnings are applied
+/// (an edit which overlaps with another one waits for the next pass) and the lines are checked
+/// again until there is nothing to fix or `MAX_ITERATIONS` is reached.
+pub fn fix(lines: &mut Vec<LineEntry>, config: &FixConfig) -> FixResult {
+    fix_with(lines, config, |_, _| true)
+}
+
+/// Fixes the warnings like `fix`, but the edit of a warning is applied only if `approve` returns
+/// `true` for the warning (with the edit) and the lines it changes. A fix which is declined
+/// is not proposed again, unless it changes (e.g. because of the other fixes).
+pub fn fix_with(
+    lines: &mut Vec<LineEntry>,
+    config: &FixConfig,
+    mut approve: impl FnMut(&Warning, &[LineEntry]) -> bool,
+) -> FixResult {
+    let FixConfig {
+        skip_checks,
+        fix_checks,
+        schema,
+        naming,
+        unsafe_fixes,
+        line_ending,
+    } = *config;
+
+    let mut fixes = fixlist(schema, naming, line_ending);
+    fixes.retain(|f| fix_checks.contains(&f.name()));
+    let (fixes, unsafe_fixes): (Vec<_>, Vec<_>) =
+        fixes.into_iter().partition(|f| unsafe_fixes || f.is_safe());
+
+    let mut warnings = check(lines, skip_checks, schema, naming);
+    // Warnings of the original lines with the edits proposed for them
+    let mut original: Option<Vec<Warning>> = None;
+    let mut declined: Vec<DeclinedFix> = Vec::new();
+
+    for _ in 0..MAX_ITERATIONS {
+        for fixer in &fixes {
+            let warning_lines: Vec<usize> = warnings
+                .iter()
+                .filter(|w| *w.check_name() == fixer.name())
+                .map(|w| w.line_number())
+                .collect();
+            if warning_lines.is_empty() {
+                continue;
+            }
+
+            for (number, edit) in fixer.edits(&warning_lines, lines) {
+                if let Some(warning) = warnings
+                    .iter_mut()
+                    .find(|w| *w.check_name() == fixer.name() && w.line_number() == number)
+                {
+                    warning.set_edit(edit);
+                }
+            }
+        }
+
+        original.get_or_insert_with(|| warnings.clone());
+
+        // An edit which overlaps with an approved one waits for the next pass, so it is
+        // proposed only when it can be applied
+        let mut edits: Vec<Edit> = Vec::new();
+        for warning in &warnings {
+            let Some(edit) = warning.edit() else {
+                continue;
+            };
+            if edits.contains(edit) || edits.iter().any(|e| e.overlaps(edit)) {
+                continue;
+            }
 
-    let mut count = 0;
-    for fixer in fixes {
-        // We can optimize it: create check_name:warnings map in advance
+            let fix = DeclinedFix::new(warning, edit, lines);
+            if declined.contains(&fix) {
+                continue;
+            }
+
+            if approve(warning, lines) {
+                edits.push(edit.clone());
+            } else {
+                declined.push(fix);
+            }
+        }
+
+        if edit::apply_edits(lines, &edits).is_empty() {
+            break;
+        }
+
+        warnings = check(lines, skip_checks, schema, naming);
+    }
+
+    // A warning is fixed if there is no such warning (with the same message) after fixing.
+    // Lines can move, so warnings are matched with the closest ones first.
+    let original = original.unwrap_or_default();
+    let mut pairs: Vec<(usize, usize, usize)> = original
+        .iter()
+        .enumerate()
+        .flat_map(|(i, warning)| {
+            warnings
+                .iter()
+                .enumerate()
+                .filter(move |(_, w)| {
+                    w.check_name() == warning.check_name() && w.message() == warning.message()
+                })
+                .map(move |(j, w)| (w.line_number().abs_diff(warning.line_number()), i, j))
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    let mut matched_original = vec![false; original.len()];
+    let mut matched = vec![false; warnings.len()];
+    for (_, i, j) in pairs {
+        if !matched_original[i] && !matched[j] {
+            matched_original[i] = true;
+            matched[j] = true;
+        }
+    }
+
+    let fixed = original
+        .into_iter()
+        .zip(matched_original)
+        .filter(|(_, matched)| !matched)
+        .map(|(warning, _)| warning)
+        .collect();
+
+    // Remaining warnings which would be fixed by the skipped unsafe fixes
+    let mut unsafe_skipped = Vec::new();
+    for fixer in &unsafe_fixes {
         let warning_lines: Vec<usize> = warnings
             .iter()
             .filter(|w| *w.check_name() == fixer.name())
             .map(|w| w.line_number())
             .collect();
+        if warning_lines.is_empty() {
+            continue;
+        }
 
-        // Some fixers are mandatory because previous fixers can spawn warnings for them
-        if fixer.is_mandatory() || !warning_lines.is_empty() {
-            match fixer.fix_warnings(&warning_lines, lines) {
-                Some(fixer_count) => count += fixer_count,
-                None => {
-                    return 0;
+        let fixable: Vec<usize> = fixer
+            .edits(&warning_lines, lines)
+            .into_iter()
+            .map(|(number, _)| number)
+            .collect();
+        let (skipped, rest) = warnings
+            .into_iter()
+            .partition(|w| *w.check_name() == fixer.name() && fixable.contains(&w.line_number()));
+        unsafe_skipped.extend(skipped);
+        warnings = rest;
+    }
+    unsafe_skipped.sort_by_key(|w| w.line_number());
+
+    // Remaining warnings which fixes are declined
+    let (mut declined, mut remaining): (Vec<_>, Vec<_>) =
+        warnings.into_iter().partition(|warning| {
+            match declined.iter().position(|fix| {
+                fix.check_name == *warning.check_name() && fix.message == warning.message()
+            }) {
+                Some(position) => {
+                    declined.remove(position);
+                    true
                 }
+                None => false,
             }
-        }
+        });
+    // The sort is stable, so the warnings of a line keep the order of the checks
+    declined.sort_by_key(|w| w.line_number());
+    remaining.sort_by_key(|w| w.line_number());
+
+    FixResult {
+        fixed,
+        unsafe_skipped,
+        declined,
+        remaining,
     }
+}
 
-    // Removes extra blank lines
-    lines.retain(|l| !l.is_deleted);
+/// Fix which is not approved: the warning with the lines before and after the edit
+#[derive(PartialEq, Eq)]
+struct DeclinedFix {
+    check_name: LintKind,
+    message: String,
+    old_lines: Vec<String>,
+    new_lines: Vec<String>,
+}
 
-    count
+impl DeclinedFix {
+    fn new(warning: &Warning, edit: &Edit, lines: &[LineEntry]) -> Self {
+        Self {
+            check_name: *warning.check_name(),
+            message: warning.message().to_string(),
+            old_lines: lines
+                .get(edit.range().clone())
+                .unwrap_or_default()
+                .iter()
+                .map(|line| line.raw_string.clone())
+                .collect(),
+            new_lines: edit.lines().to_vec(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -104,11 +336,48 @@ mod tests {
     use super::*;
     use crate::tests::*;
 
+    fn raw(lines: &[LineEntry]) -> Vec<&str> {
+        lines.iter().map(|line| line.raw_string.as_str()).collect()
+    }
+
+    fn kinds(warnings: &[Warning]) -> Vec<(usize, LintKind)> {
+        warnings
+            .iter()
+            .map(|w| (w.line_number(), *w.check_name()))
+            .collect()
+    }
+
+    /// Applies the edits of the fixer for the warnings on the lines with the numbers and returns
+    /// the number of the edits
+    pub(super) fn apply_fix_edits(
+        fixer: &impl Fix,
+        warning_lines: &[usize],
+        lines: &mut Vec<LineEntry>,
+    ) -> usize {
+        let edits: Vec<Edit> = fixer
+            .edits(warning_lines, lines)
+            .into_iter()
+            .map(|(_, edit)| edit)
+            .collect();
+        edit::apply_edits(lines, &edits);
+
+        edits.len()
+    }
+
     #[test]
     fn run_with_empty_warnings_test() {
         let mut lines = vec![line_entry(1, 2, "A=B"), blank_line_entry(2, 2)];
 
-        assert_eq!(0, fix(&[], &mut lines, &[]));
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert!(result.fixed.is_empty());
+        assert!(result.remaining.is_empty());
     }
 
     #[test]
@@ -118,13 +387,18 @@ fn run_with_fixable_warning_test() {
             line_entry(2, 3, "c=d"),
             blank_line_entry(3, 3),
         ];
-        let warnings = [Warning::new(
-            lines[1].number,
-            LintKind::LowercaseKey,
-            "The c key should be in uppercase",
-        )];
 
-        assert_eq!(1, fix(&warnings, &mut lines, &[]));
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(kinds(&result.fixed), [(2, LintKind::LowercaseKey)]);
+        assert_eq!(result.fixed[0].edit(), Some(&Edit::replace(1, "C=d")));
+        assert!(result.remaining.is_empty());
         assert_eq!("C=d", lines[1].raw_string);
     }
 
@@ -135,20 +409,17 @@ fn run_when_lines_do_not_fit_numbers_test() {
             line_entry(4, 3, "c=D"),
             blank_line_entry(3, 3),
         ];
-        let warnings = [
-            Warning::new(
-                lines[0].number,
-                LintKind::LowercaseKey,
-                "The a key should be in uppercase",
-            ),
-            Warning::new(
-                lines[1].number,
-                LintKind::LowercaseKey,
-                "The c key should be in uppercase",
-            ),
-        ];
 
-        assert_eq!(2, fix(&warnings, &mut lines, &[]));
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(result.fixed.len(), 2);
+        assert_eq!(raw(&lines), ["A=B", "C=D", "\n"]);
     }
 
     #[test]
@@ -160,25 +431,26 @@ fn new_warnings_after_fix_test() {
             line_entry(4, 5, "a2=2"),
             blank_line_entry(5, 5),
         ];
-        let warnings = [
-            Warning::new(
-                lines[2].number,
-                LintKind::LowercaseKey,
-                "The a0 key should be in uppercase",
-            ),
-            Warning::new(
-                lines[3].number,
-                LintKind::LowercaseKey,
-                "The a2 key should be in uppercase",
-            ),
-        ];
 
-        assert_eq!(2, fix(&warnings, &mut lines, &[]));
-        assert_eq!("A0=0", lines[0].raw_string);
-        assert_eq!("A1=1", lines[1].raw_string);
-        assert_eq!("A2=2", lines[2].raw_string);
-        assert_eq!("# A2=2", lines[3].raw_string);
-        assert_eq!("\n", lines[4].raw_string);
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        // The a2 key isn't renamed, it would be a duplicate of A2
+        assert_eq!(kinds(&result.fixed), [(3, LintKind::LowercaseKey)]);
+        assert_eq!(
+            kinds(&result.remaining),
+            [
+                (3, LintKind::CaseInsensitiveDuplicatedKey),
+                (4, LintKind::LowercaseKey),
+                (4, LintKind::CaseInsensitiveDuplicatedKey)
+            ]
+        );
+        assert_eq!(raw(&lines), ["A0=0", "A1=1", "A2=2", "a2=2", "\n"]);
     }
 
     #[test]
@@ -190,25 +462,19 @@ fn skip_duplicated_key() {
             line_entry(4, 5, "a2=2"),
             blank_line_entry(5, 5),
         ];
-        let warnings = [
-            Warning::new(
-                lines[2].number,
-                LintKind::LowercaseKey,
-                "The a0 key should be in uppercase",
-            ),
-            Warning::new(
-                lines[3].number,
-                LintKind::LowercaseKey,
-                "The a2 key should be in uppercase",
-            ),
-        ];
 
-        assert_eq!(2, fix(&warnings, &mut lines, &[LintKind::DuplicatedKey]));
-        assert_eq!("A0=0", lines[0].raw_string);
-        assert_eq!("A1=1", lines[1].raw_string);
-        assert_eq!("A2=2", lines[2].raw_string);
-        assert_eq!("A2=2", lines[3].raw_string);
-        assert_eq!("\n", lines[4].raw_string);
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                skip_checks: &[LintKind::DuplicatedKey],
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(kinds(&result.fixed), [(3, LintKind::LowercaseKey)]);
+        assert!(kinds(&result.remaining).contains(&(4, LintKind::LowercaseKey)));
+        assert_eq!(raw(&lines), ["A0=0", "A1=1", "A2=2", "a2=2", "\n"]);
     }
 
     #[test]
@@ -220,24 +486,176 @@ fn skip_unordered_key() {
             line_entry(4, 5, "a2=2"),
             blank_line_entry(5, 5),
         ];
-        let warnings = [
-            Warning::new(
-                lines[2].number,
-                LintKind::LowercaseKey,
-                "The a0 key should be in uppercase",
-            ),
-            Warning::new(
-                lines[3].number,
-                LintKind::LowercaseKey,
-                "The a2 key should be in uppercase",
-            ),
+
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                skip_checks: &[LintKind::UnorderedKey],
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(kinds(&result.fixed), [(3, LintKind::LowercaseKey)]);
+        assert!(kinds(&result.remaining).contains(&(4, LintKind::LowercaseKey)));
+        assert_eq!(raw(&lines), ["A1=1", "A2=2", "A0=0", "a2=2", "\n"]);
+    }
+
+    #[test]
+    fn overlapping_edits_test() {
+        // Sorting and the lowercase fix change the same lines, so they are applied in turns
+        let mut lines = vec![
+            line_entry(1, 4, "B=1"),
+            line_entry(2, 4, "a=2 "),
+            line_entry(3, 4, "C=3"),
+            blank_line_entry(4, 4),
+        ];
+
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(
+            kinds(&result.fixed),
+            [
+                (2, LintKind::LowercaseKey),
+                (2, LintKind::TrailingWhitespace),
+                (3, LintKind::UnorderedKey)
+            ]
+        );
+        assert!(result.remaining.is_empty());
+        assert_eq!(raw(&lines), ["A=2", "B=1", "C=3", "\n"]);
+    }
+
+    #[test]
+    fn remaining_warnings_test() {
+        let schema = serde_json::from_str(
+            r#"{
+                "version": "1.0.0",
+                "allow_other_keys": true,
+                "entries": { "PORT": { "type": "Integer" } }
+            }"#,
+        )
+        .expect("deserializing schema");
+        let mut lines = vec![
+            line_entry(1, 3, "PORT=http"),
+            line_entry(2, 3, "x=1"),
+            blank_line_entry(3, 3),
+        ];
+
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                schema: Some(&schema),
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(kinds(&result.fixed), [(2, LintKind::LowercaseKey)]);
+        assert_eq!(kinds(&result.remaining), [(1, LintKind::SchemaViolation)]);
+        assert_eq!(raw(&lines), ["PORT=http", "X=1", "\n"]);
+    }
+
+    #[test]
+    fn skip_unsafe_fixes_test() {
+        let mut lines = vec![
+            line_entry(1, 4, "B=1 "),
+            line_entry(2, 4, "a=2"),
+            line_entry(3, 4, "B=3"),
+            blank_line_entry(4, 4),
+        ];
+
+        let result = fix(&mut lines, &FixConfig::default());
+
+        assert_eq!(kinds(&result.fixed), [(1, LintKind::TrailingWhitespace)]);
+        assert_eq!(
+            kinds(&result.unsafe_skipped),
+            [
+                (2, LintKind::LowercaseKey),
+                (3, LintKind::UnorderedKey),
+                (3, LintKind::DuplicatedKey)
+            ]
+        );
+        assert!(result.remaining.is_empty());
+        assert_eq!(raw(&lines), ["B=1", "a=2", "B=3", "\n"]);
+    }
+
+    #[test]
+    fn fix_checks_test() {
+        let mut lines = vec![
+            line_entry(1, 3, "B=1 "),
+            line_entry(2, 3, "A=2 "),
+            blank_line_entry(3, 3),
+        ];
+
+        let result = fix(
+            &mut lines,
+            &FixConfig {
+                fix_checks: &[LintKind::UnorderedKey],
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+        );
+
+        assert_eq!(kinds(&result.fixed), [(2, LintKind::UnorderedKey)]);
+        assert_eq!(
+            kinds(&result.remaining),
+            [
+                (1, LintKind::TrailingWhitespace),
+                (2, LintKind::TrailingWhitespace)
+            ]
+        );
+        assert_eq!(raw(&lines), ["A=2 ", "B=1 ", "\n"]);
+    }
+
+    #[test]
+    fn declined_fixes_test() {
+        let mut lines = vec![
+            line_entry(1, 4, "a=1 "),
+            line_entry(2, 4, "B=2 "),
+            line_entry(3, 4, "c=3"),
+            blank_line_entry(4, 4),
         ];
 
-        assert_eq!(2, fix(&warnings, &mut lines, &[LintKind::UnorderedKey]));
-        assert_eq!("A1=1", lines[0].raw_string);
-        assert_eq!("A2=2", lines[1].raw_string);
-        assert_eq!("A0=0", lines[2].raw_string);
-        assert_eq!("# A2=2", lines[3].raw_string);
-        assert_eq!("\n", lines[4].raw_string);
+        let mut proposed = Vec::new();
+        let result = fix_with(
+            &mut lines,
+            &FixConfig {
+                unsafe_fixes: true,
+                ..FixConfig::default()
+            },
+            |w, _| {
+                proposed.push((w.line_number(), *w.check_name()));
+                *w.check_name() != LintKind::TrailingWhitespace || w.line_number() == 1
+            },
+        );
+
+        // A declined fix is proposed once, overlapping fixes are proposed in the next pass
+        assert_eq!(
+            proposed,
+            [
+                (1, LintKind::LowercaseKey),
+                (2, LintKind::TrailingWhitespace),
+                (3, LintKind::LowercaseKey),
+                (1, LintKind::TrailingWhitespace),
+            ]
+        );
+        assert_eq!(
+            kinds(&result.fixed),
+            [
+                (1, LintKind::LowercaseKey),
+                (1, LintKind::TrailingWhitespace),
+                (2, LintKind::UnorderedKey),
+                (3, LintKind::LowercaseKey)
+            ]
+        );
+        assert_eq!(kinds(&result.declined), [(2, LintKind::TrailingWhitespace)]);
+        assert!(result.remaining.is_empty());
+        assert_eq!(raw(&lines), ["A=1", "B=2 ", "C=3", "\n"]);
     }
 }
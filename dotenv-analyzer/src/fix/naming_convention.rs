@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use dotenv_core::LineEntry;
+
+use super::{Fix, line_index};
+use crate::{Edit, LintKind, NamingConvention};
+
+pub(crate) struct NamingConventionFixer<'a> {
+    convention: Cow<'a, NamingConvention>,
+}
+
+impl<'a> NamingConventionFixer<'a> {
+    pub fn new(convention: Option<&'a NamingConvention>) -> Self {
+        Self {
+            convention: convention.map_or_else(Cow::default, Cow::Borrowed),
+        }
+    }
+}
+
+impl Fix for NamingConventionFixer<'_> {
+    fn name(&self) -> LintKind {
+        LintKind::NamingConvention
+    }
+
+    fn is_safe(&self) -> bool {
+        false
+    }
+
+    // A key isn't renamed to a key which the file already has (in any case)
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        warning_lines
+            .iter()
+            .filter_map(|&number| {
+                let index = line_index(lines, number)?;
+                let renamed = self.convention.rename(lines[index].get_key()?)?;
+                let collides = lines
+                    .iter()
+                    .filter_map(LineEntry::get_key)
+                    .any(|other| other.eq_ignore_ascii_case(&renamed));
+                if collides {
+                    return None;
+                }
+
+                let mut line = lines[index].clone();
+                self.fix_line(&mut line)?;
+                Some((number, Edit::replace(index, line.raw_string)))
+            })
+            .collect()
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let key = line.get_key()?;
+        let renamed = self.convention.rename(key)?;
+
+        // The key is the last word before the equal sign (after `export`)
+        let delimiter = line.raw_string.find('=')?;
+        let start = line.raw_string[..delimiter].rfind(key)?;
+        line.raw_string
+            .replace_range(start..start + key.len(), &renamed);
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::tests::apply_fix_edits;
+    use crate::tests::*;
+
+    #[test]
+    fn fix_line_test() {
+        let convention = NamingConvention {
+            strict: true,
+            ..NamingConvention::default()
+        };
+        let fixer = NamingConventionFixer::new(Some(&convention));
+        let mut line = line_entry(1, 1, "export FOO__BAR_=BAZ");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export FOO_BAR=BAZ", line.raw_string);
+    }
+
+    #[test]
+    fn fix_prefix_test() {
+        let convention = NamingConvention {
+            prefixes: vec!["VITE_".to_string()],
+            ..NamingConvention::default()
+        };
+        let fixer = NamingConventionFixer::new(Some(&convention));
+        let mut line = line_entry(1, 1, "API_URL=http://localhost");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("VITE_API_URL=http://localhost", line.raw_string);
+    }
+
+    #[test]
+    fn ambiguous_rename_test() {
+        let fixer = NamingConventionFixer::new(None);
+        let mut line = line_entry(1, 1, "PATH=/bin");
+
+        assert_eq!(None, fixer.fix_line(&mut line));
+        assert_eq!("PATH=/bin", line.raw_string);
+    }
+
+    #[test]
+    fn colliding_keys_are_not_fixed_test() {
+        let convention = NamingConvention {
+            strict: true,
+            ..NamingConvention::default()
+        };
+        let fixer = NamingConventionFixer::new(Some(&convention));
+        let mut lines = vec![
+            line_entry(1, 3, "FOO_=1"),
+            line_entry(2, 3, "FOO=2"),
+            blank_line_entry(3, 3),
+        ];
+
+        assert_eq!(0, apply_fix_edits(&fixer, &[1], &mut lines));
+        assert_eq!("FOO_=1", lines[0].raw_string);
+    }
+}
@@ -24,6 +24,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::*;
 
     #[test]
@@ -45,7 +46,7 @@ fn fix_warnings_test() {
         ];
         let warning_lines = [lines[0].number];
 
-        assert_eq!(Some(1), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(1, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=bar", lines[0].raw_string);
     }
 }
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use dotenv_core::LineEntry;
+use dotenv_schema::DotEnvSchema;
+
+use super::{Fix, line_index};
+use crate::{Edit, LintKind};
+
+/// Renames deprecated keys to the keys they are replaced by in the schema
+pub(crate) struct SchemaViolationFixer<'a> {
+    schema: Option<&'a DotEnvSchema>,
+}
+
+impl<'a> SchemaViolationFixer<'a> {
+    pub fn new(schema: Option<&'a DotEnvSchema>) -> Self {
+        Self { schema }
+    }
+
+    fn replacement(&self, line: &LineEntry) -> Option<&'a str> {
+        let entry = self.schema?.entry_for(line.get_key()?)?;
+        if !entry.deprecated {
+            return None;
+        }
+
+        entry.replaced_by.as_deref()
+    }
+}
+
+impl Fix for SchemaViolationFixer<'_> {
+    fn name(&self) -> LintKind {
+        LintKind::SchemaViolation
+    }
+
+    fn is_safe(&self) -> bool {
+        false
+    }
+
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        let mut keys: HashSet<String> = lines
+            .iter()
+            .filter_map(LineEntry::get_key)
+            .map(str::to_string)
+            .collect();
+
+        let mut edits = Vec::new();
+        for &number in warning_lines {
+            let Some(index) = line_index(lines, number) else {
+                continue;
+            };
+            let line = &lines[index];
+
+            // Other violations (e.g. invalid values) can't be fixed automatically
+            let (Some(replacement), Some(key)) = (self.replacement(line), line.get_key()) else {
+                continue;
+            };
+
+            // Renaming to a key which is already set would create a duplicate
+            if !keys.insert(replacement.to_string()) {
+                continue;
+            }
+
+            // Only the key is replaced to keep the `export` prefix and the spaces
+            let Some(start) = line
+                .raw_string
+                .find('=')
+                .and_then(|delimiter| line.raw_string[..delimiter].rfind(key))
+            else {
+                continue;
+            };
+            let mut raw_string = line.raw_string.clone();
+            raw_string.replace_range(start..start + key.len(), replacement);
+
+            edits.push((number, Edit::replace(index, raw_string)));
+        }
+
+        edits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::tests::apply_fix_edits;
+    use crate::tests::*;
+
+    fn load_schema() -> DotEnvSchema {
+        let json = r#"{
+            "version": "1.0.0",
+            "entries": {
+                "DB_URL": { "type": "Url", "deprecated": true, "replaced_by": "DATABASE_URL" },
+                "DEBUG": { "deprecated": true },
+                "PORT": { "type": "Integer" }
+            }
+        }"#;
+        serde_json::from_str(json).expect("deserializing schema")
+    }
+
+    #[test]
+    fn fix_warnings_test() {
+        let schema = load_schema();
+        let fixer = SchemaViolationFixer::new(Some(&schema));
+        let mut lines = vec![
+            line_entry(1, 5, "DB_URL=postgres://localhost"),
+            line_entry(2, 5, "DEBUG=true"),
+            line_entry(3, 5, "PORT=port"),
+            line_entry(4, 5, "X=Y"),
+            blank_line_entry(5, 5),
+        ];
+
+        assert_eq!(1, apply_fix_edits(&fixer, &[1, 2, 3], &mut lines));
+        assert_eq!("DATABASE_URL=postgres://localhost", lines[0].raw_string);
+        assert_eq!("DEBUG=true", lines[1].raw_string);
+        assert_eq!("PORT=port", lines[2].raw_string);
+    }
+
+    #[test]
+    fn export_test() {
+        let schema = load_schema();
+        let fixer = SchemaViolationFixer::new(Some(&schema));
+        let mut lines = vec![
+            line_entry(1, 2, "export  DB_URL=postgres://localhost"),
+            blank_line_entry(2, 2),
+        ];
+
+        assert_eq!(1, apply_fix_edits(&fixer, &[1], &mut lines));
+        assert_eq!(
+            "export  DATABASE_URL=postgres://localhost",
+            lines[0].raw_string
+        );
+    }
+
+    #[test]
+    fn replacement_already_set_test() {
+        let schema = load_schema();
+        let fixer = SchemaViolationFixer::new(Some(&schema));
+        let mut lines = vec![
+            line_entry(1, 3, "DATABASE_URL=postgres://db"),
+            line_entry(2, 3, "DB_URL=postgres://localhost"),
+            blank_line_entry(3, 3),
+        ];
+
+        assert_eq!(0, apply_fix_edits(&fixer, &[2], &mut lines));
+        assert_eq!("DB_URL=postgres://localhost", lines[1].raw_string);
+    }
+
+    #[test]
+    fn without_schema_test() {
+        let fixer = SchemaViolationFixer::new(None);
+        let mut lines = vec![line_entry(1, 2, "DB_URL=1"), blank_line_entry(2, 2)];
+
+        assert_eq!(0, apply_fix_edits(&fixer, &[1], &mut lines));
+    }
+}
@@ -23,6 +23,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::*;
 
     #[test]
@@ -53,7 +54,7 @@ fn fix_warnings_test() {
         ];
         let warning_lines = [lines[0].number, lines[1].number];
 
-        assert_eq!(Some(2), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(2, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=BAR", lines[0].raw_string);
         assert_eq!("Z=Y", lines[1].raw_string);
     }
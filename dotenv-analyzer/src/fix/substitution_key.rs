@@ -63,6 +63,7 @@ fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::*;
 
     #[test]
@@ -88,7 +89,7 @@ fn fix_warnings_test() {
         ];
         let warning_lines = [1, 3, 4, 6];
 
-        assert_eq!(Some(4), fixer.fix_warnings(&warning_lines, &mut lines));
+        assert_eq!(4, apply_fix_edits(&fixer, &warning_lines, &mut lines));
         assert_eq!("FOO=${BAR}-${ABC_ROOT}", lines[0].raw_string);
         assert_eq!("BAR=${Y}-${OPTS}", lines[2].raw_string);
         assert_eq!("ABC=${BAR}${XYZ}", lines[3].raw_string);
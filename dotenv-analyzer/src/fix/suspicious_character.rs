@@ -0,0 +1,124 @@
+use dotenv_core::LineEntry;
+
+use super::Fix;
+use crate::{LintKind, check};
+
+/// Zero-width and bidirectional formatting characters, a key is never meant to have them
+const ZERO_WIDTH_AND_BIDI: [char; 17] = [
+    '\u{061C}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}',
+    '\u{202C}', '\u{202D}', '\u{202E}', '\u{2060}', '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+    '\u{FEFF}',
+];
+
+/// Removes the invisible characters. Removing zero-width and bidirectional formatting characters
+/// from keys is safe, removing the characters from whole lines (values and comments) is not.
+pub(crate) struct SuspiciousCharacterFixer {
+    keys_only: bool,
+}
+
+impl SuspiciousCharacterFixer {
+    pub(crate) fn new(keys_only: bool) -> Self {
+        Self { keys_only }
+    }
+}
+
+impl Fix for SuspiciousCharacterFixer {
+    fn name(&self) -> LintKind {
+        LintKind::SuspiciousCharacter
+    }
+
+    fn is_safe(&self) -> bool {
+        self.keys_only
+    }
+
+    // Only the reported invisible characters are removed, joiners inside emoji are kept.
+    // Non-breaking spaces separate words, and non-ASCII keys and mixed scripts need a human
+    // to choose the right characters.
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let mut invisible = check::invisible_characters(&line.raw_string);
+
+        if self.keys_only {
+            let key_end = line.raw_string.find('=')?;
+            invisible
+                .retain(|(position, c, _)| *position < key_end && ZERO_WIDTH_AND_BIDI.contains(c));
+        } else if invisible.iter().any(|(_, c, _)| !check::is_removable(*c)) {
+            return None;
+        }
+
+        if invisible.is_empty() {
+            return None;
+        }
+
+        line.raw_string = line
+            .raw_string
+            .char_indices()
+            .filter(|(index, _)| !invisible.iter().any(|(position, ..)| position == index))
+            .map(|(_, c)| c)
+            .collect();
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::tests::apply_fix_edits;
+    use crate::tests::*;
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = SuspiciousCharacterFixer::new(false);
+        let mut line = line_entry(1, 1, "FOO\u{200B}=\u{202E}BAR\u{FEFF}");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("FOO=BAR", line.raw_string);
+    }
+
+    #[test]
+    fn fix_warnings_test() {
+        let fixer = SuspiciousCharacterFixer::new(false);
+        let mut lines = vec![
+            line_entry(1, 5, "FOO=BAR\u{200B}"),
+            line_entry(2, 5, "BAR=A\u{00A0}B"),
+            line_entry(3, 5, "F\u{041E}O=BAR"),
+            line_entry(4, 5, "EMOJI=👨\u{200D}👩\u{200B}"),
+            blank_line_entry(5, 5),
+        ];
+
+        assert_eq!(2, apply_fix_edits(&fixer, &[1, 2, 3, 4], &mut lines));
+        assert_eq!("FOO=BAR", lines[0].raw_string);
+        assert_eq!("BAR=A\u{00A0}B", lines[1].raw_string);
+        assert_eq!("F\u{041E}O=BAR", lines[2].raw_string);
+        assert_eq!("EMOJI=👨\u{200D}👩", lines[3].raw_string);
+    }
+
+    #[test]
+    fn keeps_joiners_of_emoji_test() {
+        let fixer = SuspiciousCharacterFixer::new(false);
+        // The joiner at the end is reported, the one inside the emoji is not
+        let mut line = line_entry(1, 1, "EMOJI=👨\u{200D}👩 \u{200D}");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("EMOJI=👨\u{200D}👩 ", line.raw_string);
+    }
+
+    #[test]
+    fn keys_only_test() {
+        let fixer = SuspiciousCharacterFixer::new(true);
+        let mut lines = vec![
+            line_entry(1, 5, "F\u{200B}OO\u{202E}=BAR\u{200B}"),
+            line_entry(2, 5, "BAR=BAZ\u{200B}"),
+            line_entry(3, 5, "B\u{00A0}AZ=1"),
+            line_entry(4, 5, "# \u{200B}"),
+            blank_line_entry(5, 5),
+        ];
+
+        assert!(fixer.is_safe());
+        assert_eq!(1, apply_fix_edits(&fixer, &[1, 2, 3, 4], &mut lines));
+        assert_eq!("FOO=BAR\u{200B}", lines[0].raw_string);
+        assert_eq!("BAR=BAZ\u{200B}", lines[1].raw_string);
+        assert_eq!("B\u{00A0}AZ=1", lines[2].raw_string);
+        assert_eq!("# \u{200B}", lines[3].raw_string);
+    }
+}
@@ -1,7 +1,7 @@
 use dotenv_core::LineEntry;
 
 use super::Fix;
-use crate::{LintKind, comment::Comment};
+use crate::{Edit, LintKind, comment::Comment};
 
 #[derive(Default)]
 pub(crate) struct UnorderedKeyFixer {}
@@ -26,7 +26,29 @@ fn name(&self) -> LintKind {
         LintKind::UnorderedKey
     }
 
-    fn fix_warnings(&self, warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> Option<usize> {
+    fn is_safe(&self) -> bool {
+        false
+    }
+
+    // All the warnings are fixed by the same edit which sorts the groups
+    fn edits(&self, warning_lines: &[usize], lines: &[LineEntry]) -> Vec<(usize, Edit)> {
+        let mut sorted = lines.to_vec();
+        let edit = self
+            .sort_groups(&mut sorted)
+            .and_then(|_| Edit::between(lines, &sorted));
+
+        match edit {
+            Some(edit) => warning_lines
+                .iter()
+                .map(|&number| (number, edit.clone()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl UnorderedKeyFixer {
+    fn sort_groups(&self, lines: &mut [LineEntry]) -> Option<()> {
         // We find all sorting groups and sort them
         let mut start_index = 0;
         let mut end = None;
@@ -91,15 +113,9 @@ fn fix_warnings(&self, warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> O
             }
         }
 
-        Some(warning_lines.len())
-    }
-
-    fn is_mandatory(&self) -> bool {
-        true
+        Some(())
     }
-}
 
-impl UnorderedKeyFixer {
     fn sort_part(part: &mut [LineEntry]) {
         // Each slice includes a significant line (with key) and previous comments (if present)
         let mut slices = Vec::with_capacity(part.len());
@@ -123,6 +139,7 @@ fn sort_part(part: &mut [LineEntry]) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::tests::apply_fix_edits;
     use crate::tests::line_entry;
 
     fn get_lines(lines: Vec<&str>) -> Vec<LineEntry> {
@@ -135,10 +152,10 @@ fn get_lines(lines: Vec<&str>) -> Vec<LineEntry> {
             .collect()
     }
 
-    fn run_fixer(warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> Option<usize> {
+    fn run_fixer(warning_lines: &[usize], lines: &mut Vec<LineEntry>) -> usize {
         let fixer = UnorderedKeyFixer::default();
 
-        fixer.fix_warnings(warning_lines, lines)
+        apply_fix_edits(&fixer, warning_lines, lines)
     }
 
     fn assert_lines(result: &[LineEntry], lines: Vec<&str>) {
@@ -153,7 +170,7 @@ fn fix_warnings_test() {
         let mut lines = get_lines(vec!["B=C", "A=B", "D=E", "\n"]);
         let warning_lines = [1];
 
-        assert_eq!(Some(1), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(1, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(&lines, vec!["A=B", "B=C", "D=E", "\n"]);
     }
@@ -163,9 +180,9 @@ fn fix_when_no_warnings_test() {
         let mut lines = get_lines(vec!["B=C", "A=B", "D=E", "\n"]);
         let warning_lines = [];
 
-        assert_eq!(Some(0), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(0, run_fixer(&warning_lines, &mut lines));
 
-        assert_lines(&lines, vec!["A=B", "B=C", "D=E", "\n"]);
+        assert_lines(&lines, vec!["B=C", "A=B", "D=E", "\n"]);
     }
 
     #[test]
@@ -173,7 +190,7 @@ fn many_moves_test() {
         let mut lines = get_lines(vec!["X=X", "A=A", "D=D", "Z=Z", "Y=Y", "KLM=123", "\n"]);
         let warning_lines = [1, 4, 5];
 
-        assert_eq!(Some(3), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(3, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -195,7 +212,7 @@ fn previous_comments_test() {
         ]);
         let warning_lines = [4];
 
-        assert_eq!(Some(1), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(1, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -236,7 +253,7 @@ fn several_groups_test() {
         ]);
         let warning_lines = [6, 12];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -268,7 +285,7 @@ fn no_ending_blank_line_test() {
         let mut lines = get_lines(vec!["B=C", "A=B", "D=E"]);
         let warning_lines = [2];
 
-        assert_eq!(Some(1), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(1, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(&lines, vec!["A=B", "B=C", "D=E"]);
     }
@@ -285,7 +302,7 @@ fn key_order_substitution_variable_test() {
         ]);
         let warning_lines = [1, 5];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -317,7 +334,7 @@ fn key_order_substitution_variable_multiple_groups_test() {
         ]);
         let warning_lines = [1, 5];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -342,7 +359,7 @@ fn key_order_multiple_substitution_variable_together_test() {
         let mut lines = get_lines(vec!["FOO=1", "BAR=2", "A=$FOO$BAR", "B=3", "AA=4"]);
         let warning_lines = [1, 4];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -367,7 +384,7 @@ fn key_order_four_substitution_variable_together_test() {
         ]);
         let warning_lines = [];
 
-        assert_eq!(Some(0), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(0, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -376,7 +393,7 @@ fn key_order_four_substitution_variable_together_test() {
                 "CCC=1",
                 "DDD=1",
                 "EEE=1",
-                "AAA=$EEE$CCC$BBB$DDD$FFF # Unordered because AAA uses EEE, CCC, BBB, DDD",
+                "AAA=$EEE$CCC$BBB$DDD$FFF",
             ],
         );
     }
@@ -386,7 +403,7 @@ fn key_order_substitution_variable_in_different_group_test() {
         let mut lines = get_lines(vec!["FOO=1", "BAR=2", "", "B=3", "A=$FOO"]);
         let warning_lines = [1, 4];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(&lines, vec!["BAR=2", "FOO=1", "", "A=$FOO", "B=3"]);
     }
@@ -396,7 +413,7 @@ fn key_order_many_substitution_variable_test() {
         let mut lines = get_lines(vec!["Z=1", "Y=2", "X=$Y", "W=$Y", "V=4", "U=5", "T=$V"]);
         let warning_lines = [1, 4, 5];
 
-        assert_eq!(Some(3), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(3, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -433,7 +450,7 @@ fn key_order_substitution_variable_big_test() {
         ]);
         let warning_lines = [1, 5, 12];
 
-        assert_eq!(Some(3), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(3, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -470,7 +487,7 @@ fn all_file_control_comments_test() {
         ]);
         let warning_lines = [];
 
-        assert_eq!(Some(0), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(0, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -501,7 +518,7 @@ fn some_lines_control_comments_test() {
         ]);
         let warning_lines = [2, 8];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -534,7 +551,7 @@ fn other_checker_control_comments_test() {
         ]);
         let warning_lines = [2, 5, 8];
 
-        assert_eq!(Some(3), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(3, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -570,7 +587,7 @@ fn different_control_comments_test() {
         ]);
         let warning_lines = [2, 9];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -607,7 +624,7 @@ fn comment_between_control_comments_test() {
         ]);
         let warning_lines = [8];
 
-        assert_eq!(Some(1), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(1, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -644,7 +661,7 @@ fn crossed_control_comments_test() {
         ]);
         let warning_lines = [3, 11];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -685,7 +702,7 @@ fn nested_control_comments_test() {
         ]);
         let warning_lines = [4, 10, 13];
 
-        assert_eq!(Some(3), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(3, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -723,7 +740,7 @@ fn empty_control_comments_test() {
         ]);
         let warning_lines = [6, 8];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
@@ -777,7 +794,7 @@ fn control_comments_and_blank_lines_test() {
         ]);
         let warning_lines = [8, 19];
 
-        assert_eq!(Some(2), run_fixer(&warning_lines, &mut lines));
+        assert_eq!(2, run_fixer(&warning_lines, &mut lines));
 
         assert_lines(
             &lines,
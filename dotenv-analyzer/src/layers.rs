@@ -0,0 +1,303 @@
+//! Checks of the files of one directory which are loaded together, each file overriding the keys
+//! of the files before it, e.g. `.env`, `.env.local`, `.env.production` and
+//! `.env.production.local`
+
+use std::collections::HashMap;
+
+use dotenv_core::LineEntry;
+
+use crate::{Comment, LintKind, Warning};
+
+/// Placeholder of the environment name in the layer order
+pub const ENVIRONMENT: &str = "{env}";
+
+/// Order in which the files are loaded, the later files override the earlier ones
+pub const DEFAULT_ORDER: [&str; 4] = [".env", ".env.local", ".env.{env}", ".env.{env}.local"];
+
+/// Files with this suffix are not committed, so their keys are missing in CI
+const LOCAL_SUFFIX: &str = ".local";
+
+/// Names of the committed templates (e.g. `.env.example`), they are not environments
+const TEMPLATES: [&str; 4] = ["example", "sample", "template", "dist"];
+
+/// Key of a file with its line number and the checks disabled at the line
+struct Definition<'a> {
+    number: usize,
+    value: &'a str,
+    disabled_checks: Vec<LintKind>,
+}
+
+/// Checks the files (names and lines) of one directory together and returns the warnings of each
+/// file. The `order` lists the file names from the lowest precedence to the highest one, names
+/// with the `{env}` placeholder match the files of each environment.
+pub fn check_layers(
+    files: &[(&str, &[LineEntry])],
+    order: &[&str],
+    skip_checks: &[LintKind],
+) -> Vec<Vec<Warning>> {
+    let mut warnings = vec![Vec::new(); files.len()];
+
+    let layers: Vec<_> = files.iter().map(|(name, _)| layer(name, order)).collect();
+    let definitions: Vec<_> = files.iter().map(|(_, lines)| definitions(lines)).collect();
+
+    // The files are loaded without an environment file too, e.g. in development
+    let mut environments: Vec<Option<&str>> = layers
+        .iter()
+        .filter_map(|layer| layer.and_then(|(_, environment)| environment))
+        .map(Some)
+        .collect();
+    environments.push(None);
+    environments.sort_unstable();
+    environments.dedup();
+
+    for environment in environments {
+        let mut stack: Vec<usize> = (0..files.len())
+            .filter(|&i| {
+                matches!(layers[i], Some((_, layer_environment))
+                    if layer_environment.is_none() || layer_environment == environment)
+            })
+            .collect();
+        stack.sort_by_key(|&i| layers[i].map(|(position, _)| position));
+
+        let mut add_warning = |file: usize, definition: &Definition, kind: LintKind, message| {
+            if skip_checks.contains(&kind) || definition.disabled_checks.contains(&kind) {
+                return;
+            }
+
+            let warning = Warning::new(definition.number, kind, message);
+            if !warnings[file].contains(&warning) {
+                warnings[file].push(warning);
+            }
+        };
+
+        for (position, &file) in stack.iter().enumerate() {
+            for (key, definition) in &definitions[file] {
+                // The value which the key overrides is the one of the closest file before
+                let overridden = stack[..position].iter().rev().find_map(|&lower| {
+                    definitions[lower]
+                        .get(key)
+                        .map(|lower_definition| (lower, lower_definition))
+                });
+
+                if let Some((lower, lower_definition)) = overridden
+                    && unquote(lower_definition.value) == unquote(definition.value)
+                {
+                    add_warning(
+                        file,
+                        definition,
+                        LintKind::RedundantOverride,
+                        format!(
+                            "The {key} key has the same value in {}, which it overrides",
+                            files[lower].0
+                        ),
+                    );
+                }
+
+                let local_only = stack
+                    .iter()
+                    .filter(|&&other| definitions[other].contains_key(key))
+                    .all(|&other| files[other].0.ends_with(LOCAL_SUFFIX));
+
+                if local_only {
+                    add_warning(
+                        file,
+                        definition,
+                        LintKind::LocalOnlyKey,
+                        format!("The {key} key is defined only in local files"),
+                    );
+                }
+            }
+        }
+    }
+
+    for file_warnings in &mut warnings {
+        file_warnings.sort_by_key(Warning::line_number);
+    }
+
+    warnings
+}
+
+/// Returns the position of the file in the order and its environment, or `None` if the file
+/// isn't in the order
+fn layer<'a>(name: &'a str, order: &[&str]) -> Option<(usize, Option<&'a str>)> {
+    if let Some(position) = order.iter().position(|pattern| *pattern == name) {
+        return Some((position, None));
+    }
+
+    order.iter().enumerate().find_map(|(position, pattern)| {
+        let (prefix, suffix) = pattern.split_once(ENVIRONMENT)?;
+        let environment = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+
+        (!environment.is_empty() && !environment.contains('.') && !TEMPLATES.contains(&environment))
+            .then_some((position, Some(environment)))
+    })
+}
+
+/// Returns the keys of the file, the first definition of a duplicated key is used
+fn definitions(lines: &[LineEntry]) -> HashMap<&str, Definition<'_>> {
+    let mut definitions = HashMap::new();
+    let mut disabled_checks: Vec<LintKind> = Vec::new();
+
+    for line in lines {
+        if let Some(comment) = line.get_comment().and_then(Comment::parse) {
+            if comment.is_disabled() {
+                disabled_checks.extend(comment.checks);
+            } else {
+                disabled_checks.retain(|&s| !comment.checks.contains(&s));
+            }
+        }
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            definitions.entry(key).or_insert_with(|| Definition {
+                number: line.number,
+                value,
+                disabled_checks: disabled_checks.clone(),
+            });
+        }
+    }
+
+    definitions
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::line_entry;
+
+    fn lines(content: &[&str]) -> Vec<LineEntry> {
+        (1..)
+            .zip(content)
+            .map(|(number, line)| line_entry(number, content.len(), line))
+            .collect()
+    }
+
+    fn messages(warnings: &[Warning]) -> Vec<String> {
+        warnings
+            .iter()
+            .map(|w| format!("{} {}: {}", w.line_number(), w.check_name(), w.message()))
+            .collect()
+    }
+
+    #[test]
+    fn layer_test() {
+        assert_eq!(Some((0, None)), layer(".env", &DEFAULT_ORDER));
+        assert_eq!(Some((1, None)), layer(".env.local", &DEFAULT_ORDER));
+        assert_eq!(
+            Some((2, Some("production"))),
+            layer(".env.production", &DEFAULT_ORDER)
+        );
+        assert_eq!(
+            Some((3, Some("production"))),
+            layer(".env.production.local", &DEFAULT_ORDER)
+        );
+        assert_eq!(None, layer(".env.example.backup", &DEFAULT_ORDER));
+        assert_eq!(None, layer(".env.example", &DEFAULT_ORDER));
+        assert_eq!(None, layer(".env.sample", &DEFAULT_ORDER));
+        assert_eq!(None, layer(".env.template.local", &DEFAULT_ORDER));
+        assert_eq!(None, layer("production.env", &DEFAULT_ORDER));
+    }
+
+    #[test]
+    fn redundant_override_test() {
+        let env = lines(&["A=1", "B=2", "C=\"3\""]);
+        let production = lines(&["A=1", "B=3", "C=3"]);
+        let production_local = lines(&["B=3"]);
+
+        let warnings = check_layers(
+            &[
+                (".env", &env),
+                (".env.production", &production),
+                (".env.production.local", &production_local),
+            ],
+            &DEFAULT_ORDER,
+            &[],
+        );
+
+        assert!(warnings[0].is_empty());
+        assert_eq!(
+            vec![
+                "1 RedundantOverride: The A key has the same value in .env, which it overrides",
+                "3 RedundantOverride: The C key has the same value in .env, which it overrides",
+            ],
+            messages(&warnings[1])
+        );
+        assert_eq!(
+            vec![
+                "1 RedundantOverride: The B key has the same value in .env.production, which it \
+                 overrides"
+            ],
+            messages(&warnings[2])
+        );
+    }
+
+    #[test]
+    fn local_only_key_test() {
+        let env = lines(&["A=1"]);
+        let local = lines(&["A=2", "B=2", "C=3"]);
+        let production = lines(&["C=4"]);
+
+        let warnings = check_layers(
+            &[
+                (".env", &env),
+                (".env.local", &local),
+                (".env.production", &production),
+            ],
+            &DEFAULT_ORDER,
+            &[],
+        );
+
+        // C is missing without an environment file
+        assert_eq!(
+            vec![
+                "2 LocalOnlyKey: The B key is defined only in local files",
+                "3 LocalOnlyKey: The C key is defined only in local files",
+            ],
+            messages(&warnings[1])
+        );
+        assert!(warnings[2].is_empty());
+    }
+
+    #[test]
+    fn custom_order_test() {
+        let local = lines(&["A=1"]);
+        let production = lines(&["A=1"]);
+        let files = [
+            (".env.local", local.as_slice()),
+            (".env.production", &production),
+        ];
+
+        // .env.local overrides .env.production, like in Next.js
+        let warnings = check_layers(&files, &[".env", ".env.{env}", ".env.local"], &[]);
+
+        // Without the environment file, the key is only in .env.local
+        assert!(warnings[1].is_empty());
+        assert_eq!(
+            vec![
+                "1 LocalOnlyKey: The A key is defined only in local files",
+                "1 RedundantOverride: The A key has the same value in .env.production, which it \
+                 overrides",
+            ],
+            messages(&warnings[0])
+        );
+    }
+
+    #[test]
+    fn skip_checks_test() {
+        let env = lines(&["A=1"]);
+        let local = lines(&["# dotenv-linter:off LocalOnlyKey", "A=1", "B=2"]);
+        let files = [(".env", env.as_slice()), (".env.local", &local)];
+
+        let warnings = check_layers(&files, &DEFAULT_ORDER, &[LintKind::RedundantOverride]);
+
+        assert!(warnings.iter().all(Vec::is_empty));
+    }
+}
@@ -1,17 +1,27 @@
 mod check;
 mod comment;
+mod edit;
 mod fix;
+mod layers;
 mod lint_kind;
 mod warning;
 
-pub use check::check;
+pub use check::{DEFAULT_RESERVED_NAMES, NamingConvention, check};
 pub(crate) use comment::Comment;
-pub use fix::fix;
+pub use edit::Edit;
+pub use fix::{FixConfig, FixResult, fix, fix_with};
+pub use layers::{DEFAULT_ORDER as DEFAULT_LAYER_ORDER, check_layers};
 pub use lint_kind::LintKind;
 pub use warning::Warning;
 
 const LF: &str = "\n";
 
+/// Checks if the line separates groups of keys which are sorted independently by `UnorderedKey`:
+/// a blank line or a control comment (`# dotenv-linter:off ...`)
+pub fn is_group_separator(line: &dotenv_core::LineEntry) -> bool {
+    line.is_empty() || line.get_comment().and_then(Comment::parse).is_some()
+}
+
 fn remove_invalid_leading_chars(string: &str) -> &str {
     string.trim_start_matches(|c: char| !(c.is_alphabetic() || c == '_'))
 }
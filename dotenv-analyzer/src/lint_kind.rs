@@ -2,37 +2,81 @@
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LintKind {
+    ByteOrderMark,
+    CaseInsensitiveDuplicatedKey,
     DuplicatedKey,
     EndingBlankLine,
     ExtraBlankLine,
     IncorrectDelimiter,
     KeyWithoutValue,
     LeadingCharacter,
+    LocalOnlyKey,
     LowercaseKey,
+    MixedLineEndings,
+    NamingConvention,
     QuoteCharacter,
+    RedundantOverride,
     SpaceCharacter,
     SubstitutionKey,
+    SuspiciousCharacter,
+    SyntaxError,
     TrailingWhitespace,
     UnorderedKey,
     ValueWithoutQuotes,
     SchemaViolation,
 }
 
+impl LintKind {
+    /// All the checks
+    pub const ALL: [LintKind; 22] = [
+        LintKind::ByteOrderMark,
+        LintKind::CaseInsensitiveDuplicatedKey,
+        LintKind::DuplicatedKey,
+        LintKind::EndingBlankLine,
+        LintKind::ExtraBlankLine,
+        LintKind::IncorrectDelimiter,
+        LintKind::KeyWithoutValue,
+        LintKind::LeadingCharacter,
+        LintKind::LocalOnlyKey,
+        LintKind::LowercaseKey,
+        LintKind::MixedLineEndings,
+        LintKind::NamingConvention,
+        LintKind::QuoteCharacter,
+        LintKind::RedundantOverride,
+        LintKind::SpaceCharacter,
+        LintKind::SubstitutionKey,
+        LintKind::SuspiciousCharacter,
+        LintKind::SyntaxError,
+        LintKind::TrailingWhitespace,
+        LintKind::UnorderedKey,
+        LintKind::ValueWithoutQuotes,
+        LintKind::SchemaViolation,
+    ];
+}
+
 impl FromStr for LintKind {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "ByteOrderMark" => Ok(LintKind::ByteOrderMark),
+            "CaseInsensitiveDuplicatedKey" => Ok(LintKind::CaseInsensitiveDuplicatedKey),
             "DuplicatedKey" => Ok(LintKind::DuplicatedKey),
             "EndingBlankLine" => Ok(LintKind::EndingBlankLine),
             "ExtraBlankLine" => Ok(LintKind::ExtraBlankLine),
             "IncorrectDelimiter" => Ok(LintKind::IncorrectDelimiter),
             "KeyWithoutValue" => Ok(LintKind::KeyWithoutValue),
             "LeadingCharacter" => Ok(LintKind::LeadingCharacter),
+            "LocalOnlyKey" => Ok(LintKind::LocalOnlyKey),
             "LowercaseKey" => Ok(LintKind::LowercaseKey),
+            "MixedLineEndings" => Ok(LintKind::MixedLineEndings),
+            "NamingConvention" => Ok(LintKind::NamingConvention),
             "QuoteCharacter" => Ok(LintKind::QuoteCharacter),
+            "RedundantOverride" => Ok(LintKind::RedundantOverride),
             "SpaceCharacter" => Ok(LintKind::SpaceCharacter),
             "SubstitutionKey" => Ok(LintKind::SubstitutionKey),
+            "SuspiciousCharacter" => Ok(LintKind::SuspiciousCharacter),
+            "SyntaxError" => Ok(LintKind::SyntaxError),
             "TrailingWhitespace" => Ok(LintKind::TrailingWhitespace),
             "UnorderedKey" => Ok(LintKind::UnorderedKey),
             "ValueWithoutQuotes" => Ok(LintKind::ValueWithoutQuotes),
@@ -50,36 +94,33 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[cfg(feature = "clap")]
 impl clap::ValueEnum for LintKind {
     fn value_variants<'a>() -> &'a [Self] {
-        &[
-            LintKind::DuplicatedKey,
-            LintKind::EndingBlankLine,
-            LintKind::ExtraBlankLine,
-            LintKind::IncorrectDelimiter,
-            LintKind::KeyWithoutValue,
-            LintKind::LeadingCharacter,
-            LintKind::LowercaseKey,
-            LintKind::QuoteCharacter,
-            LintKind::SpaceCharacter,
-            LintKind::SubstitutionKey,
-            LintKind::TrailingWhitespace,
-            LintKind::UnorderedKey,
-            LintKind::ValueWithoutQuotes,
-            LintKind::SchemaViolation,
-        ]
+        &Self::ALL
     }
 
     fn to_possible_value<'a>(&self) -> Option<clap::builder::PossibleValue> {
         Some(match self {
+            LintKind::ByteOrderMark => clap::builder::PossibleValue::new("ByteOrderMark"),
+            LintKind::CaseInsensitiveDuplicatedKey => {
+                clap::builder::PossibleValue::new("CaseInsensitiveDuplicatedKey")
+            }
             LintKind::DuplicatedKey => clap::builder::PossibleValue::new("DuplicatedKey"),
             LintKind::EndingBlankLine => clap::builder::PossibleValue::new("EndingBlankLine"),
             LintKind::ExtraBlankLine => clap::builder::PossibleValue::new("ExtraBlankLine"),
             LintKind::IncorrectDelimiter => clap::builder::PossibleValue::new("IncorrectDelimiter"),
             LintKind::KeyWithoutValue => clap::builder::PossibleValue::new("KeyWithoutValue"),
             LintKind::LeadingCharacter => clap::builder::PossibleValue::new("LeadingCharacter"),
+            LintKind::LocalOnlyKey => clap::builder::PossibleValue::new("LocalOnlyKey"),
             LintKind::LowercaseKey => clap::builder::PossibleValue::new("LowercaseKey"),
+            LintKind::MixedLineEndings => clap::builder::PossibleValue::new("MixedLineEndings"),
+            LintKind::NamingConvention => clap::builder::PossibleValue::new("NamingConvention"),
             LintKind::QuoteCharacter => clap::builder::PossibleValue::new("QuoteCharacter"),
+            LintKind::RedundantOverride => clap::builder::PossibleValue::new("RedundantOverride"),
             LintKind::SpaceCharacter => clap::builder::PossibleValue::new("SpaceCharacter"),
             LintKind::SubstitutionKey => clap::builder::PossibleValue::new("SubstitutionKey"),
+            LintKind::SuspiciousCharacter => {
+                clap::builder::PossibleValue::new("SuspiciousCharacter")
+            }
+            LintKind::SyntaxError => clap::builder::PossibleValue::new("SyntaxError"),
             LintKind::TrailingWhitespace => clap::builder::PossibleValue::new("TrailingWhitespace"),
             LintKind::UnorderedKey => clap::builder::PossibleValue::new("UnorderedKey"),
             LintKind::ValueWithoutQuotes => clap::builder::PossibleValue::new("ValueWithoutQuotes"),
@@ -1,10 +1,12 @@
-use crate::lint_kind::LintKind;
+use crate::{edit::Edit, lint_kind::LintKind};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Warning {
     check_name: LintKind,
     line_number: usize,
     message: String,
+    /// Edit which fixes the warning, set by `fix`
+    edit: Option<Edit>,
 }
 
 impl Warning {
@@ -14,6 +16,7 @@ pub fn new(line_number: usize, check_name: LintKind, message: impl Into<String>)
             check_name,
             line_number,
             message,
+            edit: None,
         }
     }
 
@@ -28,4 +31,12 @@ pub fn line_number(&self) -> usize {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    pub fn edit(&self) -> Option<&Edit> {
+        self.edit.as_ref()
+    }
+
+    pub(crate) fn set_edit(&mut self, edit: Edit) {
+        self.edit = Some(edit);
+    }
 }
@@ -16,7 +16,16 @@ pub fn check_benchmark(c: &mut Criterion) {
         exclude: vec![],
         quiet: false,
         recursive: false,
+        encoding: None,
         schema: None,
+        naming: Default::default(),
+        fix: false,
+        fix_checks: vec![],
+        unsafe_fixes: false,
+        no_backup: false,
+        backups: Default::default(),
+        symlinks: Default::default(),
+        layers: None,
     };
 
     fs::copy("benches/fixtures/simple.env", path.join(".env")).expect("copy .env file");
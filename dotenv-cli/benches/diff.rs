@@ -14,6 +14,16 @@ pub fn diff_benchmark(c: &mut Criterion) {
     let env_compare = path.join(".env.compare");
     let opts = dotenv_linter::DiffOptions {
         files: vec![&env, &env_compare],
+        exclude: vec![],
+        recursive: false,
+        encoding: None,
+        ignore_keys: vec![],
+        reference: None,
+        allow_extra_keys: vec![],
+        values: false,
+        expand: false,
+        format: dotenv_linter::cli::DiffFormat::Text,
+        show_secrets: false,
         quiet: false,
     };
 
@@ -27,11 +27,21 @@ pub fn fix_benchmark(c: &mut Criterion) {
                 let opts = dotenv_linter::FixOptions {
                     files: vec![&simple_fix_path],
                     ignore_checks: vec![],
+                    fix_checks: dotenv_analyzer::LintKind::ALL.to_vec(),
                     exclude: vec![],
                     quiet: false,
                     recursive: false,
+                    encoding: None,
+                    schema: None,
+                    naming: Default::default(),
+                    unsafe_fixes: true,
                     no_backup: true,
+                    backups: Default::default(),
                     dry_run: false,
+                    symlinks: dotenv_linter::cli::SymlinkMode::Follow,
+                    line_ending: None,
+                    diff: false,
+                    interactive: false,
                 };
                 dotenv_linter::fix(black_box(&opts), black_box(&current_dir))
             },
@@ -62,11 +72,24 @@ pub fn fix_benchmark_with_backup(c: &mut Criterion) {
                 let opts = dotenv_linter::FixOptions {
                     files: vec![&simple_fix_path],
                     ignore_checks: vec![],
+                    fix_checks: dotenv_analyzer::LintKind::ALL.to_vec(),
                     exclude: vec![],
                     quiet: false,
                     recursive: false,
+                    encoding: None,
+                    schema: None,
+                    naming: Default::default(),
+                    unsafe_fixes: true,
                     no_backup: false,
+                    backups: dotenv_linter::backup::BackupOptions {
+                        dir: path.join("backups"),
+                        ..Default::default()
+                    },
                     dry_run: false,
+                    symlinks: dotenv_linter::cli::SymlinkMode::Follow,
+                    line_ending: None,
+                    diff: false,
+                    interactive: false,
                 };
                 dotenv_linter::fix(black_box(&opts), black_box(&current_dir))
             },
@@ -0,0 +1,319 @@
+//! Backups of the files changed by `fix`, `check --fix` and `sync`, which `restore` brings back.
+//! The backups are kept in one directory which mirrors the paths of the files, e.g. the backups
+//! of `config/.env` are `.dotenv-linter/backups/config/.env_<timestamp>.bak`. The paths are
+//! canonical, so a file has the same backups whichever path it is given with. The files outside
+//! the current directory are kept under `@root` with their absolute paths, e.g. the backups of
+//! `/srv/app/.env` are `.dotenv-linter/backups/@root/srv/app/.env_<timestamp>.bak`.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Component, Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::Result;
+
+pub const DEFAULT_DIR: &str = ".dotenv-linter/backups";
+pub const DEFAULT_KEEP: usize = 5;
+/// Environment variable with the backup directory
+pub const DIR_ENV: &str = "DOTENV_LINTER_BACKUP_DIR";
+const EXTENSION: &str = "bak";
+/// Directory of the backups of the files outside the current directory
+const OUTSIDE_DIR: &str = "@root";
+
+#[derive(Clone, Debug)]
+pub struct BackupOptions {
+    /// Directory to keep the backups in (relative to the current directory)
+    pub dir: PathBuf,
+    /// Number of the backups kept for each file, older backups are removed
+    pub keep: usize,
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from(DEFAULT_DIR),
+            keep: DEFAULT_KEEP,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backup {
+    pub path: PathBuf,
+    /// Time of the backup in milliseconds since the Unix epoch
+    pub timestamp: u128,
+}
+
+impl BackupOptions {
+    /// Copies the file (a path relative to the current directory) to a new backup and removes
+    /// the backups of the file over the `keep` count. Returns the path of the new backup.
+    pub fn create(&self, file: &Path) -> Result<PathBuf> {
+        let (dir, name) = self.location(file).ok_or_else(|| invalid_path(file))?;
+        fs::create_dir_all(&dir)?;
+
+        let mut timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)?
+            .as_millis();
+        // Files can be backed up more than once in the same millisecond (e.g. by `check --fix`
+        // and then `fix`), the later backup gets the next free timestamp
+        let mut path = dir.join(backup_name(&name, timestamp));
+        while path.exists() {
+            timestamp += 1;
+            path = dir.join(backup_name(&name, timestamp));
+        }
+
+        fs::copy(file, &path)?;
+
+        for backup in self.list(file)?.iter().skip(self.keep.max(1)) {
+            fs::remove_file(&backup.path)?;
+        }
+
+        Ok(path)
+    }
+
+    /// Returns the backups of the file, the latest one first
+    pub fn list(&self, file: &Path) -> io::Result<Vec<Backup>> {
+        let Some((dir, name)) = self.location(file) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(list_all_in(&dir)?
+            .into_iter()
+            .filter(|(file_name, _)| *file_name == name)
+            .map(|(_, backup)| backup)
+            .collect())
+    }
+
+    /// Returns the backups of all the files (with the paths of the files relative to the
+    /// current directory), the latest backup of each file first
+    pub fn list_all(&self) -> io::Result<BTreeMap<PathBuf, Vec<Backup>>> {
+        let mut files: BTreeMap<PathBuf, Vec<Backup>> = BTreeMap::new();
+        let mut dirs = vec![self.dir.clone()];
+
+        while let Some(dir) = dirs.pop() {
+            if !dir.is_dir() {
+                continue;
+            }
+
+            let relative_dir = dir.strip_prefix(&self.dir).unwrap_or(&dir);
+            let relative_dir = match relative_dir.strip_prefix(OUTSIDE_DIR) {
+                Ok(absolute) => Path::new(std::path::MAIN_SEPARATOR_STR).join(absolute),
+                Err(_) => relative_dir.to_path_buf(),
+            };
+            for (name, backup) in list_all_in(&dir)? {
+                files
+                    .entry(relative_dir.join(name))
+                    .or_default()
+                    .push(backup);
+            }
+
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Returns `true` if the backup is one of the backups of the file
+    pub fn is_backup_of(&self, backup: &Path, file: &Path) -> io::Result<bool> {
+        let backup = fs::canonicalize(backup)?;
+        Ok(self
+            .list(file)?
+            .iter()
+            .any(|b| fs::canonicalize(&b.path).is_ok_and(|path| path == backup)))
+    }
+
+    /// Returns the directory with the backups of the file and the name of the file. The
+    /// directory mirrors the canonical path of the file, so `./.env` and `../dir/.env` (from
+    /// `dir`) have the same backups, and the files outside the current directory are kept
+    /// under `OUTSIDE_DIR`.
+    fn location(&self, file: &Path) -> Option<(PathBuf, String)> {
+        let name = file.file_name()?.to_str()?.to_string();
+        let parent = match file.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let parent = fs::canonicalize(parent).ok()?;
+        let current_dir = fs::canonicalize(".").ok()?;
+
+        let dir = match parent.strip_prefix(&current_dir) {
+            Ok(relative) => self.dir.join(relative),
+            Err(_) => parent
+                .components()
+                .filter_map(|component| match component {
+                    Component::Normal(component) => Some(component.to_os_string()),
+                    // e.g. `C:` on Windows
+                    Component::Prefix(prefix) => Some(
+                        prefix
+                            .as_os_str()
+                            .to_string_lossy()
+                            .replace([':', '\\', '?'], "")
+                            .into(),
+                    ),
+                    _ => None,
+                })
+                .fold(self.dir.join(OUTSIDE_DIR), |dir, component| {
+                    dir.join(component)
+                }),
+        };
+
+        Some((dir, name))
+    }
+}
+
+fn backup_name(name: &str, timestamp: u128) -> String {
+    format!("{name}_{timestamp}.{EXTENSION}")
+}
+
+/// Returns the backups in the directory (not in its subdirectories) with the names of the
+/// backed up files, the latest backup first
+fn list_all_in(dir: &Path) -> io::Result<Vec<(String, Backup)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let parsed = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(EXTENSION)?.strip_suffix('.'))
+            .and_then(|name| name.rsplit_once('_'))
+            .and_then(|(name, timestamp)| Some((name.to_string(), timestamp.parse().ok()?)));
+
+        if let Some((name, timestamp)) = parsed {
+            backups.push((name, Backup { path, timestamp }));
+        }
+    }
+
+    backups.sort_by_key(|(_, backup)| std::cmp::Reverse(backup.timestamp));
+    Ok(backups)
+}
+
+fn invalid_path(file: &Path) -> Box<dyn std::error::Error> {
+    Box::new(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("cannot back up {}", file.display()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(dir: &Path, keep: usize) -> BackupOptions {
+        BackupOptions {
+            dir: dir.join("backups"),
+            keep,
+        }
+    }
+
+    #[test]
+    fn create_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let file = dir.path().join("config").join(".env");
+        fs::create_dir(dir.path().join("config")).expect("create dir");
+        fs::write(&file, "A=B\n").expect("write file");
+
+        let options = options(dir.path(), 2);
+        let backup = options.create(&file).expect("create backup");
+
+        assert_eq!("A=B\n", fs::read_to_string(&backup).expect("read backup"));
+        assert!(backup.starts_with(&options.dir));
+        assert!(backup.parent().expect("backup dir").ends_with("config"));
+        assert_eq!(vec![backup], paths(&options.list(&file).expect("list")));
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn location_test() {
+        let options = BackupOptions::default();
+        let current_dir = fs::canonicalize(".").expect("current dir");
+        let parent = current_dir.parent().expect("parent dir");
+
+        let (dir, name) = options.location(Path::new(".env")).expect("location");
+        assert_eq!(
+            (PathBuf::from(DEFAULT_DIR), ".env".to_string()),
+            (dir, name)
+        );
+        assert_eq!(
+            options.location(Path::new(".env")),
+            options.location(&current_dir.join("src/../.env"))
+        );
+
+        let (dir, _) = options.location(Path::new("../.env")).expect("location");
+        assert!(dir.starts_with(Path::new(DEFAULT_DIR).join(OUTSIDE_DIR)));
+        assert!(dir.ends_with(parent.strip_prefix("/").unwrap_or(parent)));
+    }
+
+    #[test]
+    fn retention_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let file = dir.path().join(".env");
+        let options = options(dir.path(), 2);
+
+        let backups: Vec<PathBuf> = (1..=3)
+            .map(|i| {
+                fs::write(&file, format!("A={i}\n")).expect("write file");
+                options.create(&file).expect("create backup")
+            })
+            .collect();
+
+        let listed = options.list(&file).expect("list");
+        assert_eq!(vec![backups[2].clone(), backups[1].clone()], paths(&listed));
+        assert!(!backups[0].exists());
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn list_all_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let options = options(dir.path(), 5);
+        let nested = options.dir.join("config");
+        fs::create_dir_all(&nested).expect("create dir");
+
+        for name in [
+            ".env_10.bak",
+            ".env_20.bak",
+            ".env.local_1.bak",
+            "other.txt",
+        ] {
+            fs::write(options.dir.join(name), "").expect("write file");
+        }
+        fs::write(nested.join(".env_5.bak"), "").expect("write file");
+
+        let files = options.list_all().expect("list all");
+        let timestamps: Vec<(PathBuf, Vec<u128>)> = files
+            .into_iter()
+            .map(|(file, backups)| (file, backups.iter().map(|b| b.timestamp).collect()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (PathBuf::from(".env"), vec![20, 10]),
+                (PathBuf::from(".env.local"), vec![1]),
+                (PathBuf::from("config/.env"), vec![5]),
+            ],
+            timestamps
+        );
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    fn paths(backups: &[Backup]) -> Vec<PathBuf> {
+        backups.iter().map(|backup| backup.path.clone()).collect()
+    }
+}
@@ -1,10 +1,17 @@
 use std::path::PathBuf;
 
-use clap::{Args, Parser, Subcommand, command};
-use dotenv_analyzer::LintKind;
+use clap::{Args, Parser, Subcommand, ValueEnum, command};
+use dotenv_analyzer::{DEFAULT_LAYER_ORDER, DEFAULT_RESERVED_NAMES, LintKind, NamingConvention};
+use dotenv_core::LineEnding;
+use dotenv_finder::Encoding;
 use dotenv_schema::DotEnvSchema;
 
-use crate::{CheckOptions, DiffOptions, FixOptions, Result};
+use crate::{
+    CheckOptions, DiffOptions, FixOptions, RestoreOptions, Result, SyncOptions, UnreadableFiles,
+    backup::{self, BackupOptions},
+    naming::NamingOptions,
+    schema::RenderOptions,
+};
 
 const HELP_TEMPLATE: &str = "
 {before-help}{name} {version}
@@ -54,10 +61,74 @@ enum Command {
         #[command(flatten)]
         common: CommonArgs,
 
+        /// Run only these lint checks
+        #[arg(long, value_name = "CHECK_NAME", value_delimiter = ',')]
+        only: Vec<LintKind>,
+
+        #[command(flatten)]
+        encoding: EncodingArgs,
+
         /// Schema file to validate .env file contents
         #[arg(short('s'), long, value_name = "PATH")]
         schema: Option<PathBuf>,
 
+        #[command(flatten)]
+        naming: NamingArgs,
+
+        /// Fix the files and report only the warnings which remain
+        #[arg(long)]
+        fix: bool,
+
+        /// Also apply fixes which can change how the file is read (with --fix)
+        #[arg(long, requires = "fix")]
+        unsafe_fixes: bool,
+
+        /// Fix only the warnings of these checks (with --fix)
+        #[arg(
+            long,
+            value_name = "CHECK_NAME",
+            value_delimiter = ',',
+            requires = "fix"
+        )]
+        fix_only: Vec<LintKind>,
+
+        /// Don't fix the warnings of these checks (with --fix)
+        #[arg(
+            long,
+            value_name = "CHECK_NAME",
+            value_delimiter = ',',
+            requires = "fix"
+        )]
+        no_fix: Vec<LintKind>,
+
+        /// Prevent creating backups before applying fixes (with --fix)
+        #[arg(long, requires = "fix")]
+        no_backup: bool,
+
+        #[command(flatten)]
+        backup: CheckBackupArgs,
+
+        /// How to write .env files which are symbolic links (with --fix)
+        #[arg(long, value_name = "MODE", default_value = "follow", requires = "fix")]
+        symlinks: SymlinkMode,
+
+        /// Also check the files of each directory together as layers overriding each other:
+        /// keys overriding the same value (RedundantOverride) and keys defined only in .local
+        /// files (LocalOnlyKey)
+        #[arg(long)]
+        layers: bool,
+
+        /// File names in the order they override each other, {env} stands for the environment
+        /// (with --layers)
+        #[arg(
+            long,
+            value_name = "FILE_NAME",
+            value_delimiter = ',',
+            default_values = DEFAULT_LAYER_ORDER,
+            requires = "layers"
+        )]
+        layer_order: Vec<String>,
+
         /// Disable checking for application updates
         #[cfg(feature = "update-informer")]
         #[arg(long, env = "DOTENV_LINTER_SKIP_UPDATES")]
@@ -75,13 +146,61 @@ enum Command {
         #[command(flatten)]
         common: CommonArgs,
 
+        /// Run only these lint checks
+        #[arg(long, value_name = "CHECK_NAME", value_delimiter = ',')]
+        only: Vec<LintKind>,
+
+        /// Fix only the warnings of these checks, the other warnings are just reported
+        #[arg(long, value_name = "CHECK_NAME", value_delimiter = ',')]
+        fix_only: Vec<LintKind>,
+
+        /// Don't fix the warnings of these checks
+        #[arg(long, value_name = "CHECK_NAME", value_delimiter = ',')]
+        no_fix: Vec<LintKind>,
+
+        #[command(flatten)]
+        encoding: EncodingArgs,
+
+        /// Schema file to rename deprecated keys with
+        #[arg(short('s'), long, value_name = "PATH")]
+        schema: Option<PathBuf>,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+
+        /// Also apply fixes which can change how the file is read (DuplicatedKey, LowercaseKey,
+        /// NamingConvention, SchemaViolation, SuspiciousCharacter, UnorderedKey)
+        #[arg(long)]
+        unsafe_fixes: bool,
+
         /// Prevent creating backups before applying fixes
         #[arg(long)]
         no_backup: bool,
 
+        #[command(flatten)]
+        backup: BackupArgs,
+
         /// Print fixed .env content to stdout without saving changes
         #[arg(long)]
         dry_run: bool,
+
+        /// How to write .env files which are symbolic links
+        #[arg(long, value_name = "MODE", default_value = "follow")]
+        symlinks: SymlinkMode,
+
+        /// Line ending to fix mixed line endings with (the line ending of the first line by
+        /// default)
+        #[arg(long, value_name = "STYLE")]
+        line_ending: Option<LineEndingStyle>,
+
+        /// Print a unified diff of the fixes without saving changes.
+        /// Exits with a non-zero code if any file would be changed
+        #[arg(long, conflicts_with = "dry_run")]
+        diff: bool,
+
+        /// Show the fix of each warning and ask whether to apply it
+        #[arg(long, conflicts_with = "diff")]
+        interactive: bool,
     },
     /// Compare .env files to ensure matching key sets
     Diff {
@@ -91,12 +210,260 @@ enum Command {
             required = true,
         )]
         files: Vec<PathBuf>,
+
+        #[command(flatten)]
+        common: CommonArgs,
+
+        #[command(flatten)]
+        encoding: EncodingArgs,
+
+        /// Keys (glob patterns) to leave out of the comparison
+        #[arg(long, value_name = "KEY", value_delimiter = ',')]
+        ignore_keys: Vec<String>,
+
+        /// Compare files with this file (e.g. .env.example) instead of with each other
+        #[arg(long, value_name = "PATH")]
+        reference: Option<PathBuf>,
+
+        /// Keys (glob patterns) allowed in files but not in the reference file
+        #[arg(
+            long,
+            value_name = "KEY",
+            value_delimiter = ',',
+            requires = "reference"
+        )]
+        allow_extra_keys: Vec<String>,
+
+        /// Compare values of the keys and print a table of them
+        #[arg(long, conflicts_with = "reference")]
+        values: bool,
+
+        /// Compare values with substitutions ($KEY, ${KEY}) expanded
+        #[arg(long, requires = "values")]
+        expand: bool,
+
+        /// Output format of the key comparison
+        #[arg(
+            short,
+            long,
+            value_enum,
+            default_value_t = DiffFormat::Text,
+            conflicts_with = "values"
+        )]
+        format: DiffFormat,
+
+        /// Show values of secret-looking keys (e.g. *_PASSWORD, *_TOKEN) instead of masking them
+        #[arg(long)]
+        show_secrets: bool,
+    },
+    /// Add keys missing in .env files from a reference file (e.g. .env.example)
+    Sync {
+        /// .env files or directories to sync (one or more required)
+        #[arg(
+            num_args(1..),
+            required = true,
+        )]
+        files: Vec<PathBuf>,
+
+        /// Reference file to take the keys and their comments from
+        #[arg(long, value_name = "PATH")]
+        from: PathBuf,
+
+        /// Values of the added keys
+        #[arg(long, value_enum, default_value_t = SyncValues::Empty)]
+        values: SyncValues,
+
+        /// Remove keys which are not in the reference file
+        #[arg(long)]
+        prune: bool,
+
+        #[command(flatten)]
+        encoding: EncodingArgs,
+
+        /// Prevent creating backups before changing files
+        #[arg(long)]
+        no_backup: bool,
+
+        #[command(flatten)]
+        backup: BackupArgs,
+
+        /// Print synced .env content to stdout without saving changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// How to write .env files which are symbolic links
+        #[arg(long, value_name = "MODE", default_value = "follow")]
+        symlinks: SymlinkMode,
+    },
+    /// List the backups made by fix and sync or restore a file from its backup
+    Restore {
+        /// .env file to restore (or to list the backups of)
+        #[arg(value_name = "PATH", required_unless_present = "list")]
+        file: Option<PathBuf>,
+
+        /// List the backups of the file or of all the files
+        #[arg(long)]
+        list: bool,
+
+        /// Backup to restore (as listed with --list), the latest one by default
+        #[arg(long, value_name = "PATH", conflicts_with = "list")]
+        backup: Option<PathBuf>,
+
+        /// Directory with the backups
+        #[arg(
+            long,
+            value_name = "PATH",
+            default_value = backup::DEFAULT_DIR,
+            env = backup::DIR_ENV
+        )]
+        backup_dir: PathBuf,
+
+        /// How to write .env files which are symbolic links
+        #[arg(
+            long,
+            value_name = "MODE",
+            default_value = "follow",
+            conflicts_with = "list"
+        )]
+        symlinks: SymlinkMode,
+    },
+    /// Work with schema files
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommand,
     },
 }
 
+#[derive(Subcommand)]
+enum SchemaCommand {
+    /// Generate a .env.example file or reference documentation from a schema
+    Render {
+        /// Schema file to render
+        #[arg(value_name = "PATH")]
+        schema: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = RenderFormat::Env)]
+        format: RenderFormat,
+
+        /// Write the result to the file instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Validate a schema file and report problems with their locations
+    Check {
+        /// Schema file to validate
+        #[arg(value_name = "PATH")]
+        schema: PathBuf,
+    },
+    /// Convert a schema to JSON Schema (draft 2020-12)
+    Export {
+        /// Schema file to export
+        #[arg(value_name = "PATH")]
+        schema: PathBuf,
+
+        /// Write the result to the file instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Convert a JSON Schema document to a schema
+    Import {
+        /// JSON Schema file to import
+        #[arg(value_name = "PATH")]
+        json_schema: PathBuf,
+
+        /// Write the result to the file instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum RenderFormat {
+    /// .env.example file
+    Env,
+    /// Markdown reference documentation
+    Markdown,
+    /// HTML reference documentation
+    Html,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum SymlinkMode {
+    /// Write the fixed content to the file the link points to
+    #[default]
+    Follow,
+    /// Don't fix files which are symbolic links
+    Refuse,
+    /// Replace the link with a regular file, the file it points to is not changed
+    Replace,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum LineEndingStyle {
+    /// Unix line endings (\n)
+    Lf,
+    /// Windows line endings (\r\n)
+    Crlf,
+}
+
+impl From<LineEndingStyle> for LineEnding {
+    fn from(style: LineEndingStyle) -> Self {
+        match style {
+            LineEndingStyle::Lf => LineEnding::Lf,
+            LineEndingStyle::Crlf => LineEnding::CrLf,
+        }
+    }
+}
+
+/// 8-bit encodings of the files which aren't valid UTF-8
+#[derive(Clone, Copy, ValueEnum)]
+pub enum FileEncoding {
+    /// ISO-8859-1
+    Latin1,
+    Windows1252,
+}
+
+impl From<FileEncoding> for Encoding {
+    fn from(encoding: FileEncoding) -> Self {
+        match encoding {
+            FileEncoding::Latin1 => Encoding::Latin1,
+            FileEncoding::Windows1252 => Encoding::Windows1252,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffFormat {
+    /// Lists of missing and extra keys
+    Text,
+    /// Unified diff with the lines of missing and extra keys
+    Unified,
+    /// JSON document with missing and extra keys of each file
+    Json,
+}
+
+/// Style of the key names checked by `NamingConvention`
+#[derive(Clone, Copy, ValueEnum)]
+pub enum KeyStyle {
+    /// SCREAMING_SNAKE_CASE without a leading digit, double underscores and a trailing underscore
+    Strict,
+    /// Only the max length, prefixes and reserved names are checked
+    Loose,
+}
+
+/// Values of the keys added by `sync`
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SyncValues {
+    /// Add keys with empty values (`KEY=`)
+    Empty,
+    /// Copy values from the reference file as placeholders
+    Reference,
+}
+
 #[derive(Args)]
 struct CommonArgs {
-    /// Files or directories to exclude from linting or fixing
+    /// Files or directories to exclude from linting, fixing or comparing
     #[arg(short = 'e', long, value_name = "PATH")]
     exclude: Vec<PathBuf>,
 
@@ -115,6 +482,143 @@ struct CommonArgs {
     recursive: bool,
 }
 
+#[derive(Args)]
+struct BackupArgs {
+    /// Directory to keep backups in, mirroring the paths of the files
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = backup::DEFAULT_DIR,
+        env = backup::DIR_ENV
+    )]
+    backup_dir: PathBuf,
+
+    /// Number of backups to keep for each file, older backups are removed
+    #[arg(
+        long,
+        value_name = "COUNT",
+        default_value_t = backup::DEFAULT_KEEP,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    keep_backups: usize,
+}
+
+/// Backup arguments of `check`, they are used only with `--fix`. The arguments have no default
+/// values (the environment variable is read when they are converted), so `requires` rejects them
+/// only when they are passed without `--fix`.
+#[derive(Args)]
+struct CheckBackupArgs {
+    /// Directory to keep backups in, mirroring the paths of the files (with --fix)
+    /// [default: .dotenv-linter/backups] [env: DOTENV_LINTER_BACKUP_DIR]
+    #[arg(long, value_name = "PATH", requires = "fix")]
+    backup_dir: Option<PathBuf>,
+
+    /// Number of backups to keep for each file, older backups are removed (with --fix)
+    /// [default: 5]
+    #[arg(
+        long,
+        value_name = "COUNT",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        requires = "fix"
+    )]
+    keep_backups: Option<usize>,
+}
+
+impl From<CheckBackupArgs> for BackupOptions {
+    fn from(args: CheckBackupArgs) -> Self {
+        let dir = args
+            .backup_dir
+            .or_else(|| std::env::var_os(backup::DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(backup::DEFAULT_DIR));
+
+        Self {
+            dir,
+            keep: args.keep_backups.unwrap_or(backup::DEFAULT_KEEP),
+        }
+    }
+}
+
+impl From<BackupArgs> for BackupOptions {
+    fn from(args: BackupArgs) -> Self {
+        Self {
+            dir: args.backup_dir,
+            keep: args.keep_backups,
+        }
+    }
+}
+
+#[derive(Args)]
+struct NamingArgs {
+    /// Style of the key names (NamingConvention)
+    #[arg(long, value_name = "STYLE", default_value = "loose")]
+    key_style: KeyStyle,
+
+    /// Max length of the key names (NamingConvention)
+    #[arg(long, value_name = "LENGTH")]
+    max_key_length: Option<usize>,
+
+    /// Prefix which the keys should start with, in all the files or in the files of a
+    /// directory (NamingConvention)
+    #[arg(long, value_name = "[DIR=]PREFIX", value_delimiter = ',')]
+    key_prefix: Vec<String>,
+
+    /// Names which the keys can't have (NamingConvention)
+    #[arg(
+        long,
+        value_name = "NAME",
+        value_delimiter = ',',
+        default_values = DEFAULT_RESERVED_NAMES
+    )]
+    reserved_names: Vec<String>,
+}
+
+#[derive(Args)]
+struct EncodingArgs {
+    /// Encoding of the files which aren't valid UTF-8 (UTF-16 files with a byte order mark
+    /// are read anyway). The files read in this encoding are written in UTF-8
+    #[arg(long, value_name = "ENCODING")]
+    encoding: Option<FileEncoding>,
+}
+
+impl From<NamingArgs> for NamingOptions {
+    fn from(args: NamingArgs) -> Self {
+        Self {
+            convention: NamingConvention {
+                strict: matches!(args.key_style, KeyStyle::Strict),
+                max_key_length: args.max_key_length,
+                prefixes: Vec::new(),
+                reserved_names: args.reserved_names,
+            },
+            prefixes: args
+                .key_prefix
+                .iter()
+                .map(|prefix| NamingOptions::parse_prefix(prefix))
+                .collect(),
+        }
+    }
+}
+
+/// Returns the checks to skip: the ignored ones and, if `only` is set, the ones not in `only`
+fn ignored_checks(mut ignore_checks: Vec<LintKind>, only: &[LintKind]) -> Vec<LintKind> {
+    if !only.is_empty() {
+        ignore_checks.extend(
+            LintKind::ALL
+                .into_iter()
+                .filter(|kind| !only.contains(kind)),
+        );
+    }
+
+    ignore_checks
+}
+
+/// Returns the checks in `only` (or all the checks if it is empty) which are not in `except`
+fn selected_checks(only: &[LintKind], except: &[LintKind]) -> Vec<LintKind> {
+    LintKind::ALL
+        .into_iter()
+        .filter(|kind| (only.is_empty() || only.contains(kind)) && !except.contains(kind))
+        .collect()
+}
+
 pub fn run() -> Result<i32> {
     #[cfg(windows)]
     colored::control::set_virtual_terminal(true).ok();
@@ -130,32 +634,48 @@ pub fn run() -> Result<i32> {
         Command::Check {
             files,
             common,
+            only,
+            encoding,
             schema,
+            naming,
+            fix,
+            unsafe_fixes,
+            fix_only,
+            no_fix,
+            no_backup,
+            backup,
+            symlinks,
+            layers,
+            layer_order,
             #[cfg(feature = "update-informer")]
                 skip_updates: not_check_updates,
         } => {
-            let mut dotenv_schema = None;
-            if let Some(path) = schema {
-                dotenv_schema = match DotEnvSchema::load(path) {
-                    Ok(schema) => Some(schema),
-                    Err(err) => {
-                        println!("Error loading schema: {err}");
-                        std::process::exit(1);
-                    }
-                };
-            }
+            let dotenv_schema = schema.as_ref().map(load_schema);
 
-            let total_warnings = crate::check(
+            let result = crate::check(
                 &CheckOptions {
                     files: files.iter().collect(),
-                    ignore_checks: common.ignore_checks,
+                    ignore_checks: ignored_checks(common.ignore_checks, &only),
                     exclude: common.exclude.iter().collect(),
                     recursive: common.recursive,
                     quiet: cli.quiet,
+                    encoding: encoding.encoding.map(Into::into),
                     schema: dotenv_schema,
+                    naming: naming.into(),
+                    fix,
+                    fix_checks: selected_checks(&fix_only, &no_fix),
+                    unsafe_fixes,
+                    no_backup,
+                    backups: backup.into(),
+                    symlinks,
+                    layers: layers.then_some(layer_order),
                 },
                 &current_dir,
-            )?;
+            );
+            let total_warnings = match result {
+                Ok(total_warnings) => total_warnings,
+                Err(err) => return exit_code(err),
+            };
 
             #[cfg(feature = "update-informer")]
             if !not_check_updates && !cli.quiet {
@@ -169,43 +689,218 @@ pub fn run() -> Result<i32> {
         Command::Fix {
             files,
             common,
+            only,
+            fix_only,
+            no_fix,
+            encoding,
+            schema,
+            naming,
+            unsafe_fixes,
             no_backup,
+            backup,
             dry_run,
+            symlinks,
+            line_ending,
+            diff,
+            interactive,
         } => {
-            crate::fix(
+            let result = crate::fix(
                 &FixOptions {
                     files: files.iter().collect(),
-                    ignore_checks: common.ignore_checks,
+                    ignore_checks: ignored_checks(common.ignore_checks, &only),
+                    fix_checks: selected_checks(&fix_only, &no_fix),
                     exclude: common.exclude.iter().collect(),
                     recursive: common.recursive,
                     quiet: cli.quiet,
-
+                    encoding: encoding.encoding.map(Into::into),
+                    schema: schema.as_ref().map(load_schema),
+                    naming: naming.into(),
+                    unsafe_fixes,
                     no_backup,
+                    backups: backup.into(),
                     dry_run,
+                    symlinks,
+                    line_ending: line_ending.map(Into::into),
+                    diff,
+                    interactive,
                 },
                 &current_dir,
-            )?;
+            );
+            let changed_files = match result {
+                Ok(changed_files) => changed_files,
+                Err(err) => return exit_code(err),
+            };
+
+            if diff && changed_files > 0 {
+                return Ok(1);
+            }
 
             return Ok(0);
         }
-        Command::Diff { files } => {
-            let total_warnings = crate::diff(
+        Command::Diff {
+            files,
+            common,
+            encoding,
+            ignore_keys,
+            reference,
+            allow_extra_keys,
+            values,
+            expand,
+            format,
+            show_secrets,
+        } => {
+            let result = crate::diff(
                 &DiffOptions {
                     files: files.iter().collect(),
+                    exclude: common.exclude.iter().collect(),
+                    recursive: common.recursive,
+                    encoding: encoding.encoding.map(Into::into),
+                    ignore_keys,
+                    reference: reference.as_ref(),
+                    allow_extra_keys,
+                    values,
+                    expand,
+                    format,
+                    show_secrets,
                     quiet: cli.quiet,
                 },
                 &current_dir,
-            )?;
+            );
+            let total_warnings = match result {
+                Ok(total_warnings) => total_warnings,
+                Err(err) => return exit_code(err),
+            };
 
             if total_warnings == 0 {
                 return Ok(0);
             }
         }
+        Command::Sync {
+            files,
+            from,
+            values,
+            prune,
+            encoding,
+            no_backup,
+            backup,
+            dry_run,
+            symlinks,
+        } => {
+            let result = crate::sync(
+                &SyncOptions {
+                    files: files.iter().collect(),
+                    from: &from,
+                    values,
+                    prune,
+                    encoding: encoding.encoding.map(Into::into),
+                    quiet: cli.quiet,
+                    no_backup,
+                    backups: backup.into(),
+                    dry_run,
+                    symlinks,
+                },
+                &current_dir,
+            );
+            let result = match result {
+                Ok(result) => result,
+                Err(err) => return exit_code(err),
+            };
+
+            if result == 0 {
+                return Ok(0);
+            }
+        }
+        Command::Restore {
+            file,
+            list,
+            backup,
+            backup_dir,
+            symlinks,
+        } => {
+            let result = crate::restore(
+                &RestoreOptions {
+                    file: file.as_ref(),
+                    list,
+                    backup: backup.as_ref(),
+                    backups: BackupOptions {
+                        dir: backup_dir,
+                        ..BackupOptions::default()
+                    },
+                    quiet: cli.quiet,
+                    symlinks,
+                },
+                &current_dir,
+            )?;
+
+            if result == 0 {
+                return Ok(0);
+            }
+        }
+        Command::Schema { command } => match command {
+            SchemaCommand::Render {
+                schema,
+                format,
+                output,
+            } => {
+                let schema = load_schema(&schema);
+
+                crate::schema::render(&RenderOptions {
+                    schema: &schema,
+                    format,
+                    output: output.as_ref(),
+                })?;
+
+                return Ok(0);
+            }
+            SchemaCommand::Check { schema } => {
+                if crate::schema::check(&schema) == 0 {
+                    return Ok(0);
+                }
+            }
+            SchemaCommand::Export { schema, output } => {
+                let schema = load_schema(&schema);
+
+                crate::schema::export(&schema, output.as_ref())?;
+
+                return Ok(0);
+            }
+            SchemaCommand::Import {
+                json_schema,
+                output,
+            } => {
+                if let Err(err) = crate::schema::import(&json_schema, output.as_ref()) {
+                    println!("Error importing schema: {err}");
+                    return Ok(1);
+                }
+
+                return Ok(0);
+            }
+        },
     }
 
     Ok(1)
 }
 
+/// Returns the exit code for the files which can't be read, the other errors are returned
+fn exit_code(err: Box<dyn std::error::Error>) -> Result<i32> {
+    if err.is::<UnreadableFiles>() {
+        return Ok(UnreadableFiles::EXIT_CODE);
+    }
+
+    Err(err)
+}
+
+/// Loads a schema file or exits with an error message
+fn load_schema(path: &PathBuf) -> DotEnvSchema {
+    match DotEnvSchema::load(path) {
+        Ok(schema) => schema,
+        Err(err) => {
+            println!("Error loading schema: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,40 +1,558 @@
-use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    path::PathBuf,
+};
+
+use dotenv_core::{LineEntry, is_escaped};
+
+/// Parts of key names which mark values as secrets (e.g. `DB_PASSWORD`, `API_KEY`)
+const SECRET_KEY_PARTS: &[&str] = &[
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "PWD",
+    "TOKEN",
+    "KEY",
+    "PRIVATE",
+    "CREDENTIAL",
+    "CREDENTIALS",
+    "AUTH",
+    "SALT",
+    "DSN",
+];
+/// Maximum depth of nested substitutions, protects from cycles like `A=$B` and `B=$A`
+const MAX_EXPANSION_DEPTH: usize = 10;
 
 // A structure used to compare environment files
 pub struct DiffFileType {
     path: PathBuf,
-    keys: Vec<String>,
+    lines: Vec<LineEntry>,
 }
 
 impl DiffFileType {
-    pub fn new(path: PathBuf, keys: Vec<String>) -> Self {
-        Self { path, keys }
+    pub fn new(path: PathBuf, lines: Vec<LineEntry>) -> Self {
+        Self { path, lines }
     }
 
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
 
-    pub fn keys(&self) -> &[String] {
-        &self.keys
+    /// Removes the keys matching any of the glob patterns from the comparison
+    pub fn ignore_keys(&mut self, patterns: &[String]) {
+        self.lines.retain(|line| {
+            line.get_key().is_none_or(|key| {
+                !patterns
+                    .iter()
+                    .any(|pattern| dotenv_core::matches_glob(pattern, key))
+            })
+        });
+    }
+
+    /// Returns keys with the numbers of the lines they are declared on, in the file order.
+    /// A duplicated key is returned only once. `export FOO` and `FOO` declare the same key.
+    pub fn keys(&self) -> Vec<(&str, usize)> {
+        let mut seen = HashSet::new();
+
+        self.lines
+            .iter()
+            .filter_map(|line| line.get_key().map(|key| (key, line.number)))
+            .filter(|(key, _)| seen.insert(*key))
+            .collect()
+    }
+
+    /// Returns the first line which declares the key
+    pub fn key_line(&self, key: &str) -> Option<KeyLine> {
+        let line = self.lines.iter().find(|line| line.get_key() == Some(key))?;
+
+        Some(KeyLine {
+            key: key.to_string(),
+            path: self.path.clone(),
+            number: line.number,
+            line: line.raw_string.clone(),
+        })
+    }
+
+    pub fn has_key(&self, key: &str) -> bool {
+        self.lines.iter().any(|line| line.get_key() == Some(key))
+    }
+
+    /// Returns unquoted values by keys. The first value of a duplicated key is used.
+    /// With `expand`, substitutions (`$KEY` and `${KEY}`) are replaced with the values of
+    /// the keys from the same file.
+    pub fn values(&self, expand: bool) -> HashMap<&str, String> {
+        let mut values = HashMap::new();
+        for line in &self.lines {
+            if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+                values.entry(key).or_insert(value);
+            }
+        }
+
+        values
+            .iter()
+            .map(|(key, raw_value)| {
+                let value = if expand {
+                    expand_value(raw_value, &values, 0)
+                } else {
+                    unquote(raw_value).to_string()
+                };
+                (*key, value)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueStatus {
+    Equal,
+    Different,
+    Missing,
+}
+
+/// Values of a key in the compared files
+pub struct ValueRow {
+    key: String,
+    status: ValueStatus,
+    /// Values in the order of the files, `None` if the key is missing in a file
+    values: Vec<Option<String>>,
+}
+
+impl ValueRow {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn status(&self) -> ValueStatus {
+        self.status
+    }
+
+    pub fn values(&self) -> &[Option<String>] {
+        &self.values
+    }
+}
+
+/// Compares values of the keys in the files, rows are sorted by key
+pub fn compare_values(files: &[DiffFileType], expand: bool) -> Vec<ValueRow> {
+    let values: Vec<HashMap<&str, String>> = files.iter().map(|file| file.values(expand)).collect();
+    let all_keys: BTreeSet<&str> = values.iter().flat_map(|v| v.keys().copied()).collect();
+
+    all_keys
+        .into_iter()
+        .map(|key| {
+            let values: Vec<Option<String>> = values.iter().map(|v| v.get(key).cloned()).collect();
+
+            let status = if values.iter().any(Option::is_none) {
+                ValueStatus::Missing
+            } else if values.windows(2).any(|pair| pair[0] != pair[1]) {
+                ValueStatus::Different
+            } else {
+                ValueStatus::Equal
+            };
+
+            ValueRow {
+                key: key.to_string(),
+                status,
+                values,
+            }
+        })
+        .collect()
+}
+
+/// Checks if the key looks like it holds a secret, so its value shouldn't be shown
+pub fn is_secret_key(key: &str) -> bool {
+    key.to_uppercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|part| SECRET_KEY_PARTS.contains(&part))
+}
+
+/// Removes surrounding whitespace and quotes from a raw value
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+
+    for quote in ['"', '\''] {
+        if let Some(unquoted) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return unquoted;
+        }
+    }
+
+    value
+}
+
+/// Unquotes the raw value and replaces substitutions with the values from `values`.
+/// Values in single quotes and escaped `$` are taken literally, unknown keys are kept as is.
+fn expand_value(raw_value: &str, values: &HashMap<&str, &str>, depth: usize) -> String {
+    let raw_value = raw_value.trim();
+    let value = unquote(raw_value);
+    if raw_value.starts_with('\'') || depth >= MAX_EXPANSION_DEPTH {
+        return value.to_string();
     }
+
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(index) = rest.find('$') {
+        let (prefix, tail) = rest.split_at(index);
+        expanded.push_str(prefix);
+
+        let reference = &tail[1..];
+        let (key, after) = match reference.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], &braced[end + 1..]),
+                None => ("", reference),
+            },
+            None => {
+                let end = reference
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(reference.len());
+                reference.split_at(end)
+            }
+        };
+
+        match values.get(key) {
+            Some(substitution) if !key.is_empty() && !is_escaped(prefix) => {
+                expanded.push_str(&expand_value(substitution, values, depth + 1));
+                rest = after;
+            }
+            _ => {
+                expanded.push('$');
+                rest = reference;
+            }
+        }
+    }
+
+    expanded.push_str(rest);
+    expanded
+}
+
+/// A key with the line it is declared on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyLine {
+    pub key: String,
+    /// File which contains the line
+    pub path: PathBuf,
+    pub number: usize,
+    pub line: String,
 }
 
 pub struct DiffWarning {
     path: PathBuf,
-    missing_keys: Vec<String>,
+    /// The reference file the file is compared with
+    reference: Option<PathBuf>,
+    /// Keys missing in the file with the lines from the files which have them
+    missing_keys: Vec<KeyLine>,
+    /// Keys which are not in the reference file with their lines
+    extra_keys: Vec<KeyLine>,
 }
 
 impl DiffWarning {
-    pub fn new(path: PathBuf, missing_keys: Vec<String>) -> Self {
-        Self { path, missing_keys }
+    pub fn new(
+        path: PathBuf,
+        reference: Option<PathBuf>,
+        mut missing_keys: Vec<KeyLine>,
+        mut extra_keys: Vec<KeyLine>,
+    ) -> Self {
+        missing_keys.sort_by(|a, b| a.key.cmp(&b.key));
+        extra_keys.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Self {
+            path,
+            reference,
+            missing_keys,
+            extra_keys,
+        }
     }
 
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
 
-    pub fn missing_keys(&self) -> &[String] {
+    pub fn reference(&self) -> Option<&PathBuf> {
+        self.reference.as_ref()
+    }
+
+    /// Missing keys sorted by name
+    pub fn missing_keys(&self) -> &[KeyLine] {
         &self.missing_keys
     }
+
+    /// Extra keys sorted by name
+    pub fn extra_keys(&self) -> &[KeyLine] {
+        &self.extra_keys
+    }
+}
+
+/// Splits files into groups by their directories, e.g. services of a monorepo,
+/// so that files are compared only with the files next to them
+pub fn group_by_directory(files: Vec<DiffFileType>) -> Vec<Vec<DiffFileType>> {
+    let mut groups: BTreeMap<PathBuf, Vec<DiffFileType>> = BTreeMap::new();
+
+    for file in files {
+        let directory = file.path.parent().map(PathBuf::from).unwrap_or_default();
+        groups.entry(directory).or_default().push(file);
+    }
+
+    groups.into_values().collect()
+}
+
+/// Compares every file with the union of the keys of all the files.
+/// The line of a missing key is taken from the first file which has the key.
+pub fn compare_all(files: &[DiffFileType]) -> Vec<DiffWarning> {
+    let all_keys: BTreeSet<&str> = files
+        .iter()
+        .flat_map(|file| file.keys().into_iter().map(|(key, _)| key))
+        .collect();
+
+    files
+        .iter()
+        .filter_map(|file| {
+            let missing_keys: Vec<KeyLine> = all_keys
+                .iter()
+                .filter(|key| !file.has_key(key))
+                .filter_map(|key| files.iter().find_map(|other| other.key_line(key)))
+                .collect();
+
+            (!missing_keys.is_empty())
+                .then(|| DiffWarning::new(file.path().clone(), None, missing_keys, Vec::new()))
+        })
+        .collect()
+}
+
+/// Compares every file with the reference file: the keys of the reference missing in a file and
+/// the keys of a file which are not in the reference. Extra keys matching `allowed_extra_keys`
+/// (glob patterns) are not reported.
+pub fn compare_with_reference(
+    reference: &DiffFileType,
+    files: &[DiffFileType],
+    allowed_extra_keys: &[String],
+) -> Vec<DiffWarning> {
+    files
+        .iter()
+        .filter_map(|file| {
+            let missing_keys: Vec<KeyLine> = reference
+                .keys()
+                .into_iter()
+                .filter(|(key, _)| !file.has_key(key))
+                .filter_map(|(key, _)| reference.key_line(key))
+                .collect();
+
+            let extra_keys: Vec<KeyLine> = file
+                .keys()
+                .into_iter()
+                .filter(|(key, _)| !reference.has_key(key))
+                .filter(|(key, _)| {
+                    !allowed_extra_keys
+                        .iter()
+                        .any(|pattern| dotenv_core::matches_glob(pattern, key))
+                })
+                .filter_map(|(key, _)| file.key_line(key))
+                .collect();
+
+            (!missing_keys.is_empty() || !extra_keys.is_empty()).then(|| {
+                DiffWarning::new(
+                    file.path().clone(),
+                    Some(reference.path().clone()),
+                    missing_keys,
+                    extra_keys,
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff_file(path: &str, lines: &[&str]) -> DiffFileType {
+        let lines = (1..)
+            .zip(lines)
+            .map(|(number, line)| LineEntry::new(number, *line, number == lines.len()))
+            .collect();
+
+        DiffFileType::new(PathBuf::from(path), lines)
+    }
+
+    fn keys(key_lines: &[KeyLine]) -> Vec<&str> {
+        key_lines.iter().map(|k| k.key.as_str()).collect()
+    }
+
+    #[test]
+    fn compare_all_test() {
+        let files = [
+            diff_file(".env1", &["B=1", "A=1"]),
+            diff_file(".env2", &["C=1", "A=1"]),
+        ];
+
+        let warnings = compare_all(&files);
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(keys(warnings[0].missing_keys()), ["C"]);
+        assert_eq!(warnings[0].missing_keys()[0].path, PathBuf::from(".env2"));
+        assert_eq!(keys(warnings[1].missing_keys()), ["B"]);
+        assert_eq!(warnings[1].missing_keys()[0].line, "B=1");
+    }
+
+    #[test]
+    fn export_prefix_test() {
+        let files = [
+            diff_file(".env1", &["export A=1"]),
+            diff_file(".env2", &["A=1"]),
+        ];
+
+        assert!(compare_all(&files).is_empty());
+    }
+
+    #[test]
+    fn ignore_keys_test() {
+        let mut file = diff_file(".env", &["A=1", "LOCAL_A=1", "# LOCAL_B=1", "LOCAL_C=1"]);
+        file.ignore_keys(&["LOCAL_*".to_string()]);
+
+        assert_eq!(file.keys(), [("A", 1)]);
+        assert_eq!(file.lines.len(), 2);
+    }
+
+    #[test]
+    fn group_by_directory_test() {
+        let files = vec![
+            diff_file("a/.env", &[]),
+            diff_file("a/.env.production", &[]),
+            diff_file("b/.env", &[]),
+            diff_file(".env", &[]),
+        ];
+
+        let groups = group_by_directory(files);
+        let paths: Vec<Vec<&str>> = groups
+            .iter()
+            .map(|group| group.iter().filter_map(|f| f.path().to_str()).collect())
+            .collect();
+
+        assert_eq!(
+            paths,
+            [
+                vec![".env"],
+                vec!["a/.env", "a/.env.production"],
+                vec!["b/.env"]
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_values_test() {
+        let files = [
+            diff_file(
+                ".env.staging",
+                &["A=1", "B='x'", "C=1", "URL=http://${HOST}:$PORT"],
+            ),
+            diff_file(
+                ".env.production",
+                &["A=\"1\"", "B=y", "URL=http://${HOST}:$PORT"],
+            ),
+        ];
+
+        let rows = compare_values(&files, false);
+        let statuses: Vec<_> = rows.iter().map(|r| (r.key(), r.status())).collect();
+
+        assert_eq!(
+            statuses,
+            [
+                ("A", ValueStatus::Equal),
+                ("B", ValueStatus::Different),
+                ("C", ValueStatus::Missing),
+                ("URL", ValueStatus::Equal),
+            ]
+        );
+        assert_eq!(
+            rows[1].values(),
+            [Some("x".to_string()), Some("y".to_string())]
+        );
+        assert_eq!(rows[2].values(), [Some("1".to_string()), None]);
+    }
+
+    #[test]
+    fn compare_expanded_values_test() {
+        let files = [
+            diff_file(
+                ".env.staging",
+                &["HOST=staging", "URL=http://${HOST}/$PATH"],
+            ),
+            diff_file(
+                ".env.production",
+                &["HOST=prod", "URL=http://${HOST}/$PATH"],
+            ),
+        ];
+
+        let rows = compare_values(&files, true);
+
+        assert_eq!(rows[1].key(), "URL");
+        assert_eq!(rows[1].status(), ValueStatus::Different);
+        assert_eq!(
+            rows[1].values(),
+            [
+                Some("http://staging/$PATH".to_string()),
+                Some("http://prod/$PATH".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_value_test() {
+        let values = HashMap::from([
+            ("A", "a"),
+            ("B", "\"${A}-$A\""),
+            ("C", "'$A'"),
+            ("LOOP", "$LOOP"),
+        ]);
+
+        assert_eq!(expand_value("$B/${C}", &values, 0), "a-a/$A");
+        assert_eq!(expand_value("\\$A ${A", &values, 0), "\\$A ${A");
+        assert_eq!(expand_value("$", &values, 0), "$");
+        assert_eq!(expand_value("$LOOP", &values, 0), "$LOOP");
+    }
+
+    #[test]
+    fn is_secret_key_test() {
+        for key in [
+            "API_KEY",
+            "db_password",
+            "GITHUB_TOKEN",
+            "SENTRY_DSN",
+            "SECRET",
+        ] {
+            assert!(is_secret_key(key), "{key}");
+        }
+
+        for key in ["KEYBOARD", "PORT", "DATABASE_HOST", "MONKEY"] {
+            assert!(!is_secret_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn compare_with_reference_test() {
+        let reference = diff_file(".env.example", &["# Comment", "A=", "B=", "C="]);
+        let files = [
+            diff_file(".env", &["A=1", "B=1", "C=1"]),
+            diff_file(
+                ".env.production",
+                &["C=1", "LOCAL=1", "A=1", "LOCAL=2", "DEBUG_SQL=1"],
+            ),
+        ];
+
+        let warnings = compare_with_reference(&reference, &files, &["DEBUG_*".to_string()]);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path(), &PathBuf::from(".env.production"));
+        assert_eq!(keys(warnings[0].missing_keys()), ["B"]);
+        assert_eq!(
+            warnings[0].extra_keys(),
+            [KeyLine {
+                key: "LOCAL".to_string(),
+                path: PathBuf::from(".env.production"),
+                number: 2,
+                line: "LOCAL=1".to_string(),
+            }]
+        );
+    }
 }
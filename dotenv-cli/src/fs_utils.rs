@@ -1,40 +1,101 @@
 use std::{
-    fs::{File, copy},
+    fs,
     io::{self, Write},
-    path::{Path, PathBuf},
-    time::SystemTime,
+    path::Path,
 };
 
 use dotenv_core::LineEntry;
 use dotenv_finder::FileEntry;
 
-use crate::Result;
+use crate::cli::SymlinkMode;
 
-/// In the future versions we should create a backup copy, or at least notify the user about it
-pub fn write_file(path: &Path, lines: Vec<LineEntry>) -> io::Result<()> {
-    let mut file = File::create(path)?;
+const LF: &str = "\n";
+const BOM: char = '\u{feff}';
 
-    // We don't write the last line, because it contains only LF (common::FileEntry::from)
-    // and writeln! already adds LF.
-    for line in lines[..lines.len() - 1].iter() {
-        writeln!(file, "{}", line.raw_string)?;
+/// Writes the lines to the file atomically (see `write_bytes`). A UTF-16 file is written
+/// in UTF-16, other files in UTF-8.
+pub fn write_file(
+    file: &FileEntry,
+    lines: Vec<LineEntry>,
+    symlinks: SymlinkMode,
+) -> io::Result<()> {
+    let content = file_content(&lines);
+    let content = match file.utf16 {
+        Some(byte_order) => byte_order.encode(&content),
+        None => content.into_bytes(),
+    };
+
+    write_bytes(&file.path, &content, symlinks)
+}
+
+/// Writes the content to the file atomically: the content is written to a temporary file in the
+/// same directory, which then replaces the file. The permissions (and the owner, if possible)
+/// of the file are kept.
+pub fn write_bytes(path: &Path, content: &[u8], symlinks: SymlinkMode) -> io::Result<()> {
+    let target = match symlinks {
+        SymlinkMode::Follow if path.is_symlink() => fs::canonicalize(path)?,
+        SymlinkMode::Refuse if path.is_symlink() => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is a symbolic link", path.display()),
+            ));
+        }
+        _ => path.to_path_buf(),
+    };
+    let dir = match target.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let mut file = tempfile::Builder::new()
+        .prefix(".dotenv-linter")
+        .tempfile_in(dir)?;
+    file.write_all(content)?;
+
+    // The metadata of the file the content comes from (the target of a symbolic link)
+    if let Ok(metadata) = fs::metadata(path) {
+        file.as_file().set_permissions(metadata.permissions())?;
+
+        // Only a privileged user can give the file to another owner, so it is kept if possible
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let _ = std::os::unix::fs::fchown(
+                file.as_file(),
+                Some(metadata.uid()),
+                Some(metadata.gid()),
+            );
+        }
     }
 
+    file.as_file().sync_all()?;
+    file.persist(&target).map_err(|e| e.error)?;
+
     Ok(())
 }
 
-pub fn backup_file(fe: &FileEntry) -> Result<PathBuf> {
-    let timestamp = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)?
-        .as_secs();
+/// Returns the content of a file with the lines. The last line which contains only LF
+/// (common::FileEntry::from) stands for the LF at the end of the file.
+pub fn file_content(lines: &[LineEntry]) -> String {
+    let (lines, final_lf) = match lines.split_last() {
+        Some((last, rest)) if last.raw_string == LF => (rest, true),
+        _ => (lines, false),
+    };
 
-    let mut new_path = fe.path.to_owned();
-    new_path.set_file_name(format!("{}_{}.bak", &fe.file_name, timestamp));
+    let mut content = String::new();
+    if lines.first().is_some_and(|line| line.bom) {
+        content.push(BOM);
+    }
+
+    for (index, line) in lines.iter().enumerate() {
+        // Multiline values keep the line endings of their lines in `raw_string`
+        content.push_str(&line.raw_string);
+        if final_lf || index + 1 < lines.len() {
+            content.push_str(line.ending.as_str());
+        }
+    }
 
-    copy(&fe.path, &new_path)
-        .map(|_| new_path)
-        .map_err(Box::new)
-        .map_err(Into::into)
+    content
 }
 
 // #[cfg(test)]
@@ -0,0 +1,120 @@
+//! Prompts of `fix --interactive`: the fix of each warning is shown and applied if it is approved
+
+use std::io::{self, BufRead, Write};
+
+use colored::*;
+use dotenv_analyzer::{LintKind, Warning};
+use dotenv_core::LineEntry;
+use dotenv_finder::FileEntry;
+
+use crate::output::fix::FixOutput;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Answer {
+    /// Apply the fix
+    Apply,
+    /// Don't apply the fix
+    Skip,
+    /// Apply the fix and all the next fixes of the same check
+    ApplyAll,
+    /// Don't apply the fix and any of the next fixes
+    Quit,
+}
+
+impl Answer {
+    fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => Some(Self::Apply),
+            "n" | "no" => Some(Self::Skip),
+            "a" | "all" => Some(Self::ApplyAll),
+            "q" | "quit" => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+pub struct Prompt<R> {
+    input: R,
+    /// Checks which fixes are applied without asking
+    apply_all: Vec<LintKind>,
+    quit: bool,
+}
+
+impl<R: BufRead> Prompt<R> {
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            apply_all: Vec::new(),
+            quit: false,
+        }
+    }
+
+    /// Shows the fix of the warning and returns whether it is approved
+    pub fn approve(
+        &mut self,
+        output: &FixOutput,
+        file: &FileEntry,
+        warning: &Warning,
+        lines: &[LineEntry],
+    ) -> bool {
+        if self.quit {
+            return false;
+        }
+        if self.apply_all.contains(warning.check_name()) {
+            return true;
+        }
+
+        output.print_proposed_fix(file, warning, lines);
+        let answer = loop {
+            print!(
+                "{} [y]es, [n]o, [a]ll {} fixes, [q]uit: ",
+                "Apply this fix?".bold(),
+                warning.check_name()
+            );
+            let _ = io::stdout().flush();
+
+            let mut input = String::new();
+            // There are no answers at the end of the input
+            match self.input.read_line(&mut input) {
+                Ok(0) | Err(_) => {
+                    println!();
+                    break Answer::Quit;
+                }
+                Ok(_) => {}
+            }
+
+            if let Some(answer) = Answer::parse(&input) {
+                break answer;
+            }
+        };
+        println!();
+
+        match answer {
+            Answer::Apply => true,
+            Answer::Skip => false,
+            Answer::ApplyAll => {
+                self.apply_all.push(*warning.check_name());
+                true
+            }
+            Answer::Quit => {
+                self.quit = true;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answer_test() {
+        assert_eq!(Some(Answer::Apply), Answer::parse("y\n"));
+        assert_eq!(Some(Answer::Skip), Answer::parse(" No "));
+        assert_eq!(Some(Answer::ApplyAll), Answer::parse("a"));
+        assert_eq!(Some(Answer::Quit), Answer::parse("quit\r\n"));
+        assert_eq!(None, Answer::parse(""));
+        assert_eq!(None, Answer::parse("maybe"));
+    }
+}
@@ -1,188 +1,722 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
 
-use dotenv_analyzer::LintKind;
+use dotenv_analyzer::{FixConfig, FixResult, LintKind, Warning};
+use dotenv_core::{LineEnding, LineEntry};
+use dotenv_finder::{Encoding, FileEntry};
 use dotenv_schema::DotEnvSchema;
 
 use crate::{
-    diff::{DiffFileType, DiffWarning},
-    output::{check::CheckOutput, diff::DiffOutput, fix::FixOutput},
+    backup::BackupOptions,
+    cli::{DiffFormat, SymlinkMode, SyncValues},
+    diff::DiffFileType,
+    interactive::Prompt,
+    naming::NamingOptions,
+    output::{
+        check::CheckOutput, diff::DiffOutput, fix::FixOutput, restore::RestoreOutput,
+        sync::SyncOutput,
+    },
 };
 
 mod fs_utils;
 
+pub mod backup;
 pub mod cli;
 mod diff;
+mod interactive;
+pub mod naming;
 mod output;
+mod patch;
+mod schema;
+mod sync;
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+/// Error returned after processing the other files when some of the files can't be read
+/// (e.g. they aren't valid UTF-8), with the number of these files
+#[derive(Debug)]
+pub struct UnreadableFiles(pub usize);
+
+impl UnreadableFiles {
+    /// Exit code of the program, it differs from the exit code of the found warnings
+    pub const EXIT_CODE: i32 = 3;
+}
+
+impl fmt::Display for UnreadableFiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} file(s) can't be read", self.0)
+    }
+}
+
+impl Error for UnreadableFiles {}
+
+/// Returns the result of a command which has processed the files it could read
+fn with_unreadable_files<T>(result: T, unreadable: usize) -> Result<T> {
+    if unreadable == 0 {
+        return Ok(result);
+    }
+
+    Err(Box::new(UnreadableFiles(unreadable)))
+}
+
 pub struct CheckOptions<'a> {
     pub files: Vec<&'a PathBuf>,
     pub ignore_checks: Vec<LintKind>,
     pub exclude: Vec<&'a PathBuf>,
     pub quiet: bool,
     pub recursive: bool,
+    /// Encoding of the files which aren't valid UTF-8
+    pub encoding: Option<Encoding>,
     pub schema: Option<DotEnvSchema>,
+    /// Naming convention of the keys
+    pub naming: NamingOptions,
+    /// Fix the files before reporting the warnings which remain
+    pub fix: bool,
+    /// Checks to fix the warnings of
+    pub fix_checks: Vec<LintKind>,
+    pub unsafe_fixes: bool,
+    pub no_backup: bool,
+    /// Where to keep the backups and how many of them
+    pub backups: BackupOptions,
+    /// How to fix the files which are symbolic links
+    pub symlinks: SymlinkMode,
+    /// File names in the order they override each other (e.g. `.env`, `.env.{env}`) to check
+    /// the files of each directory together
+    pub layers: Option<Vec<String>>,
 }
 
+/// Checks the files and returns the number of warnings. The files which can't be read are
+/// reported and `UnreadableFiles` is returned after checking the other files.
 pub fn check(opts: &CheckOptions, current_dir: &PathBuf) -> Result<usize> {
     let files = dotenv_finder::FinderBuilder::new(current_dir)
         .with_paths(&opts.files)
         .exclude(&opts.exclude)
         .recursive(opts.recursive)
+        .encoding(opts.encoding)
         .build()
         .find();
 
     let output = CheckOutput::new(opts.quiet);
+    let unreadable = output::print_file_errors(files.errors(), opts.quiet);
 
     if files.is_empty() {
         output.print_nothing_to_check();
-        return Ok(0);
+        return with_unreadable_files(0, unreadable);
     }
 
     let output = output.files_count(files.len());
 
-    let warnings_count = files
-        .into_iter()
-        .enumerate()
-        .fold(0, |acc, (index, (fe, lines))| {
-            output.print_processing_info(&fe);
+    // The files are fixed before any warnings are printed, so the layers are checked with
+    // the fixed lines of all the files
+    let mut checked_files = Vec::with_capacity(files.len());
+    for (fe, mut lines) in files {
+        let mut file_fix = FileFix::Unchanged;
+        if opts.fix {
+            let schema = schema_for_file(opts.schema.as_ref(), &fe);
+            let naming = opts.naming.for_file(&fe.path);
+            let original = lines.clone();
+            dotenv_analyzer::fix(
+                &mut lines,
+                &FixConfig {
+                    skip_checks: &opts.ignore_checks,
+                    fix_checks: &opts.fix_checks,
+                    schema: schema.as_deref(),
+                    naming: Some(&naming),
+                    unsafe_fixes: opts.unsafe_fixes,
+                    line_ending: None,
+                },
+            );
+
+            if lines != original && opts.symlinks == SymlinkMode::Refuse && fe.path.is_symlink() {
+                lines = original;
+                file_fix = FileFix::SymlinkRefused;
+            } else if lines != original {
+                let backup_file = if opts.no_backup {
+                    None
+                } else {
+                    Some(opts.backups.create(&fe.path)?)
+                };
+
+                fs_utils::write_file(&fe, lines.clone(), opts.symlinks)?;
+                file_fix = FileFix::Written(backup_file);
+            }
+        }
+
+        checked_files.push((fe, lines, file_fix));
+    }
+
+    let mut layer_warnings = match &opts.layers {
+        Some(order) => check_layers(&checked_files, order, &opts.ignore_checks),
+        None => vec![Vec::new(); checked_files.len()],
+    };
+
+    let mut warnings_count = 0;
+    for (index, (fe, lines, file_fix)) in checked_files.iter().enumerate() {
+        output.print_processing_info(fe);
+        match file_fix {
+            FileFix::Written(Some(backup_file)) => output.print_backup(backup_file),
+            FileFix::SymlinkRefused => output.print_symlink_refused(fe),
+            _ => {}
+        }
 
-            let warnings =
-                dotenv_analyzer::check(&lines, &opts.ignore_checks, opts.schema.as_ref());
-            output.print_warnings(&fe, &warnings, index);
-            acc + warnings.len()
-        });
+        let schema = schema_for_file(opts.schema.as_ref(), fe);
+        let naming = opts.naming.for_file(&fe.path);
+        let mut warnings =
+            dotenv_analyzer::check(lines, &opts.ignore_checks, schema.as_deref(), Some(&naming));
+        warnings.append(&mut layer_warnings[index]);
+        warnings.sort_by_key(Warning::line_number);
+
+        output.print_warnings(fe, &warnings, index);
+        warnings_count += warnings.len();
+    }
 
     output.print_total(warnings_count);
-    Ok(warnings_count)
+    with_unreadable_files(warnings_count, unreadable)
+}
+
+/// What `check --fix` did with a file
+enum FileFix {
+    Unchanged,
+    /// The fixed file is written, the backup is made unless `--no-backup` is set
+    Written(Option<PathBuf>),
+    /// The file is not fixed, because it is a symbolic link (see `SymlinkMode::Refuse`)
+    SymlinkRefused,
+}
+
+/// Checks the files of each directory together as layers overriding each other and returns
+/// the warnings of each file
+fn check_layers<T>(
+    files: &[(FileEntry, Vec<LineEntry>, T)],
+    order: &[String],
+    skip_checks: &[LintKind],
+) -> Vec<Vec<Warning>> {
+    let order: Vec<&str> = order.iter().map(String::as_str).collect();
+
+    let mut directories: BTreeMap<Option<&Path>, Vec<usize>> = BTreeMap::new();
+    for (index, (fe, _, _)) in files.iter().enumerate() {
+        directories.entry(fe.path.parent()).or_default().push(index);
+    }
+
+    let mut warnings = vec![Vec::new(); files.len()];
+    for indexes in directories.values() {
+        let layers: Vec<(&str, &[LineEntry])> = indexes
+            .iter()
+            .map(|&i| (files[i].0.file_name.as_str(), files[i].1.as_slice()))
+            .collect();
+
+        let layer_warnings = dotenv_analyzer::check_layers(&layers, &order, skip_checks);
+        for (&index, file_warnings) in indexes.iter().zip(layer_warnings) {
+            warnings[index] = file_warnings;
+        }
+    }
+
+    warnings
+}
+
+/// Applies the overlay for the environment from the file name (e.g. `.env.production`)
+fn schema_for_file<'a>(
+    schema: Option<&'a DotEnvSchema>,
+    fe: &FileEntry,
+) -> Option<Cow<'a, DotEnvSchema>> {
+    schema.map(|schema| match fe.environment() {
+        Some(environment) => schema.for_environment(environment),
+        None => Cow::Borrowed(schema),
+    })
 }
 
 pub struct FixOptions<'a> {
     pub files: Vec<&'a PathBuf>,
     pub ignore_checks: Vec<LintKind>,
+    /// Checks to fix the warnings of
+    pub fix_checks: Vec<LintKind>,
     pub exclude: Vec<&'a PathBuf>,
     pub quiet: bool,
     pub recursive: bool,
+    /// Encoding of the files which aren't valid UTF-8
+    pub encoding: Option<Encoding>,
+    pub schema: Option<DotEnvSchema>,
+    /// Naming convention of the keys
+    pub naming: NamingOptions,
+    pub unsafe_fixes: bool,
     pub no_backup: bool,
+    /// Where to keep the backups and how many of them
+    pub backups: BackupOptions,
     pub dry_run: bool,
+    /// How to write the files which are symbolic links
+    pub symlinks: SymlinkMode,
+    /// Line ending to fix mixed line endings with
+    pub line_ending: Option<LineEnding>,
+    /// Print a unified diff of the changes instead of changing the files
+    pub diff: bool,
+    /// Ask whether to apply the fix of each warning
+    pub interactive: bool,
 }
 
-pub fn fix(opts: &FixOptions, current_dir: &PathBuf) -> Result<()> {
+/// Fixes the files and returns the number of the changed files
+/// (or the files which would be changed with `dry_run` or `diff`). The files which can't be
+/// read are reported and `UnreadableFiles` is returned after fixing the other files.
+pub fn fix(opts: &FixOptions, current_dir: &PathBuf) -> Result<usize> {
     let files = dotenv_finder::FinderBuilder::new(current_dir)
         .with_paths(&opts.files)
         .exclude(&opts.exclude)
         .recursive(opts.recursive)
+        .encoding(opts.encoding)
         .build()
         .find();
 
-    let output = FixOutput::new(opts.quiet);
+    // Only the diff is printed, so it can be applied with `git apply`
+    let output = FixOutput::new(opts.quiet || opts.diff);
+    let unreadable = output::print_file_errors(files.errors(), opts.quiet || opts.diff);
 
     if files.is_empty() {
         output.print_nothing_to_fix();
-        return Ok(());
+        return with_unreadable_files(0, unreadable);
     }
 
     let output = output.files_count(files.len());
 
-    let mut warnings_count = 0;
+    // The prompt is shared by the files, so the answers to apply all fixes of a check and
+    // to quit hold for the next files too
+    let mut prompt = opts.interactive.then(|| Prompt::new(io::stdin().lock()));
+
+    let mut changed_count = 0;
+    let mut fixed_count = 0;
+    let mut unsafe_skipped_count = 0;
+    let mut declined_count = 0;
+    let mut remaining_count = 0;
+    let mut refused_count = 0;
     for (index, (fe, mut lines)) in files.into_iter().enumerate() {
         output.print_processing_info(&fe);
 
-        let warnings = dotenv_analyzer::check(&lines, &opts.ignore_checks, None);
-        if warnings.is_empty() {
+        let schema = schema_for_file(opts.schema.as_ref(), &fe);
+        let naming = opts.naming.for_file(&fe.path);
+
+        // A symbolic link which can't be written is only checked, its warnings are not fixed
+        let is_written = !opts.dry_run && !opts.diff;
+        if is_written && opts.symlinks == SymlinkMode::Refuse && fe.path.is_symlink() {
+            let warnings = dotenv_analyzer::check(
+                &lines,
+                &opts.ignore_checks,
+                schema.as_deref(),
+                Some(&naming),
+            );
+            if warnings.is_empty() {
+                continue;
+            }
+
+            output.print_symlink_refused(&fe);
+            refused_count += 1;
+            remaining_count += warnings.len();
+            let result = FixResult {
+                remaining: warnings,
+                ..FixResult::default()
+            };
+            output.print_warnings(&fe, &result, index);
             continue;
         }
 
-        let fixes_done = dotenv_analyzer::fix(&warnings, &mut lines, &opts.ignore_checks);
-        if fixes_done != warnings.len() {
-            output.print_not_all_warnings_fixed();
+        let original = lines.clone();
+        let result = dotenv_analyzer::fix_with(
+            &mut lines,
+            &FixConfig {
+                skip_checks: &opts.ignore_checks,
+                fix_checks: &opts.fix_checks,
+                schema: schema.as_deref(),
+                naming: Some(&naming),
+                unsafe_fixes: opts.unsafe_fixes,
+                line_ending: opts.line_ending,
+            },
+            |warning, lines| match &mut prompt {
+                Some(prompt) => prompt.approve(&output, &fe, warning, lines),
+                None => true,
+            },
+        );
+        if result.fixed.is_empty()
+            && result.unsafe_skipped.is_empty()
+            && result.declined.is_empty()
+            && result.remaining.is_empty()
+        {
+            continue;
         }
 
-        if opts.dry_run {
+        if lines != original {
+            changed_count += 1;
+        }
+
+        if opts.diff {
+            let old = fs_utils::file_content(&original);
+            let new = fs_utils::file_content(&lines);
+            match patch::header_path(&fe.path) {
+                Some(path) => {
+                    if let Some(patch) = patch::unified_diff(&path, &old, &new) {
+                        output.print_patch(&patch);
+                    }
+                }
+                None if lines != original => output.print_patch_skipped(&fe),
+                None => {}
+            }
+        } else if opts.dry_run {
             output.print_dry_run(&lines);
-        } else if fixes_done > 0 {
+        } else if lines != original {
             let should_backup = !opts.no_backup;
             // create backup copy unless user specifies not to
             if should_backup {
-                let backup_file = fs_utils::backup_file(&fe)?;
+                let backup_file = opts.backups.create(&fe.path)?;
                 output.print_backup(&backup_file);
             }
 
             // write corrected file
-            fs_utils::write_file(&fe.path, lines)?;
+            fs_utils::write_file(&fe, lines, opts.symlinks)?;
         }
 
-        output.print_warnings(&fe, &warnings, index);
-        warnings_count += warnings.len();
+        output.print_warnings(&fe, &result, index);
+        fixed_count += result.fixed.len();
+        unsafe_skipped_count += result.unsafe_skipped.len();
+        declined_count += result.declined.len();
+        remaining_count += result.remaining.len();
     }
 
-    output.print_total(warnings_count);
-    Ok(())
+    if !opts.diff {
+        output.print_total(
+            fixed_count,
+            unsafe_skipped_count,
+            declined_count,
+            remaining_count,
+            refused_count,
+        );
+    }
+    with_unreadable_files(changed_count, unreadable)
 }
 
 pub struct DiffOptions<'a> {
     pub files: Vec<&'a PathBuf>,
+    pub exclude: Vec<&'a PathBuf>,
+    /// Compare files only with the files in the same directory
+    pub recursive: bool,
+    /// Encoding of the files which aren't valid UTF-8
+    pub encoding: Option<Encoding>,
+    /// Keys (glob patterns) which are not compared
+    pub ignore_keys: Vec<String>,
+    /// File to compare the other files with instead of comparing them with each other
+    pub reference: Option<&'a PathBuf>,
+    /// Keys (glob patterns) which are allowed in files but not in the reference
+    pub allow_extra_keys: Vec<String>,
+    /// Compare values of the keys instead of key sets
+    pub values: bool,
+    /// Compare values with substitutions expanded
+    pub expand: bool,
+    /// Output format of the key comparison
+    pub format: DiffFormat,
+    /// Show values of secret-looking keys instead of masking them
+    pub show_secrets: bool,
     pub quiet: bool,
 }
 
 // Compares if different environment files contains the same variables and returns warnings if not
 pub fn diff(opts: &DiffOptions, current_dir: &PathBuf) -> Result<usize> {
+    // Only the JSON document is printed in the JSON format
+    let is_json = opts.format == DiffFormat::Json;
+    let output = DiffOutput::new(opts.quiet || is_json);
+
+    let reference = match opts.reference {
+        Some(path) => match load_reference(path, current_dir, opts.encoding, opts.quiet)? {
+            Some(mut reference) => {
+                reference.ignore_keys(&opts.ignore_keys);
+                Some(reference)
+            }
+            None => {
+                output.print_reference_not_found(path);
+                return Ok(1);
+            }
+        },
+        None => None,
+    };
+
     let files = dotenv_finder::FinderBuilder::new(current_dir)
         .with_paths(&opts.files)
+        .exclude(&opts.exclude)
+        .recursive(opts.recursive)
+        .encoding(opts.encoding)
         .build()
         .find();
-    let output = DiffOutput::new(opts.quiet);
+    let unreadable = output::print_file_errors(files.errors(), opts.quiet);
 
-    if files.is_empty() || files.len() < 2 {
-        output.print_nothing_to_compare();
-        return Ok(0);
+    // The reference can be found among the files when its directory is compared
+    let files: Vec<DiffFileType> = files
+        .into_iter()
+        .filter(|(fe, _)| reference.as_ref().is_none_or(|r| r.path() != &fe.path))
+        .map(|(fe, lines)| {
+            let mut file = DiffFileType::new(fe.path, lines);
+            file.ignore_keys(&opts.ignore_keys);
+            file
+        })
+        .collect();
+
+    // In a recursive scan of a monorepo, files are compared with each other per directory.
+    // With a reference file, all the files are compared with it.
+    let min_files = if reference.is_some() { 1 } else { 2 };
+    let groups: Vec<Vec<DiffFileType>> = if opts.recursive && reference.is_none() {
+        diff::group_by_directory(files)
+    } else {
+        vec![files]
     }
+    .into_iter()
+    .filter(|group| group.len() >= min_files)
+    .collect();
 
-    // Create DiffFileType structures for each file
-    let mut all_keys: HashSet<String> = HashSet::new();
-    let mut files_to_compare: Vec<DiffFileType> = Vec::new();
-    for (fe, lines) in files.into_iter() {
-        output.print_processing_info(&fe);
+    if groups.is_empty() {
+        if is_json {
+            output.print_json(&[], opts.show_secrets);
+        }
+        output.print_nothing_to_compare();
+        return with_unreadable_files(0, unreadable);
+    }
 
-        let mut keys: Vec<String> = Vec::new();
+    for file in groups.iter().flatten() {
+        output.print_processing_info(file.path());
+    }
 
-        for line in lines {
-            if let Some(key) = line.get_key() {
-                all_keys.insert(key.to_string());
-                keys.push(key.to_string());
+    if opts.values {
+        let mut differences = 0;
+        for (index, files) in groups.iter().enumerate() {
+            if index > 0 {
+                output.print_group_separator();
             }
+
+            let rows = diff::compare_values(files, opts.expand);
+            let paths: Vec<PathBuf> = files.iter().map(|f| f.path().clone()).collect();
+            output.print_values(&paths, &rows, opts.show_secrets);
+
+            differences += rows
+                .iter()
+                .filter(|row| row.status() != diff::ValueStatus::Equal)
+                .count();
         }
+        return with_unreadable_files(differences, unreadable);
+    }
 
-        let file_to_compare: DiffFileType = DiffFileType::new(fe.path, keys);
+    let mut warnings: Vec<_> = groups
+        .iter()
+        .flat_map(|files| match &reference {
+            Some(reference) => {
+                diff::compare_with_reference(reference, files, &opts.allow_extra_keys)
+            }
+            None => diff::compare_all(files),
+        })
+        .collect();
+    warnings.sort_by(|a, b| a.path().cmp(b.path()));
+
+    if is_json {
+        output.print_json(&warnings, opts.show_secrets);
+        return with_unreadable_files(warnings.len(), unreadable);
+    }
 
-        files_to_compare.push(file_to_compare);
+    // Create success message if no warnings found.
+    if warnings.is_empty() {
+        output.print_no_difference_found();
+        return with_unreadable_files(0, unreadable);
     }
 
-    // Create warnings if any file misses any key
-    let mut warnings: Vec<DiffWarning> = Vec::new();
-    for file in files_to_compare {
-        let missing_keys: Vec<_> = all_keys
-            .iter()
-            .filter(|key| !file.keys().contains(key))
-            .map(|key| key.to_owned())
-            .collect();
+    match opts.format {
+        DiffFormat::Unified => output.print_unified(&warnings, opts.show_secrets),
+        _ => output.print_warnings(&warnings),
+    }
+    with_unreadable_files(warnings.len(), unreadable)
+}
+
+/// Loads the reference file for `diff`
+fn load_reference(
+    path: &PathBuf,
+    current_dir: &PathBuf,
+    encoding: Option<Encoding>,
+    quiet: bool,
+) -> Result<Option<DiffFileType>> {
+    let file = load_file(path, current_dir, encoding, quiet)?;
+    Ok(file.map(|(fe, lines)| DiffFileType::new(fe.path, lines)))
+}
+
+/// Loads a single file, e.g. a reference file. The error of an unreadable file is printed
+/// and returned.
+fn load_file(
+    path: &PathBuf,
+    current_dir: &PathBuf,
+    encoding: Option<Encoding>,
+    quiet: bool,
+) -> Result<Option<(FileEntry, Vec<LineEntry>)>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let paths = [path];
+    let files = dotenv_finder::FinderBuilder::new(current_dir)
+        .with_paths(&paths)
+        .encoding(encoding)
+        .build()
+        .find();
+    let unreadable = output::print_file_errors(files.errors(), quiet);
+
+    with_unreadable_files(files.into_iter().next(), unreadable)
+}
+
+pub struct SyncOptions<'a> {
+    pub files: Vec<&'a PathBuf>,
+    /// Reference file (e.g. .env.example) to take the keys from
+    pub from: &'a PathBuf,
+    /// Values of the added keys
+    pub values: SyncValues,
+    /// Remove keys which are not in the reference file
+    pub prune: bool,
+    /// Encoding of the files which aren't valid UTF-8
+    pub encoding: Option<Encoding>,
+    pub quiet: bool,
+    pub no_backup: bool,
+    pub dry_run: bool,
+    /// Where to keep the backups and how many of them
+    pub backups: BackupOptions,
+    /// How to change the files which are symbolic links
+    pub symlinks: SymlinkMode,
+}
+
+/// Adds keys of the reference file which are missing in the files
+pub fn sync(opts: &SyncOptions, current_dir: &PathBuf) -> Result<usize> {
+    let output = SyncOutput::new(opts.quiet);
+
+    let Some((reference, reference_lines)) =
+        load_file(opts.from, current_dir, opts.encoding, opts.quiet)?
+    else {
+        output.print_reference_not_found(opts.from);
+        return Ok(1);
+    };
+
+    let files = dotenv_finder::FinderBuilder::new(current_dir)
+        .with_paths(&opts.files)
+        .encoding(opts.encoding)
+        .build()
+        .find();
+    let unreadable = output::print_file_errors(files.errors(), opts.quiet);
+
+    let files: Vec<_> = files
+        .into_iter()
+        .filter(|(fe, _)| fe.path != reference.path)
+        .collect();
+
+    if files.is_empty() {
+        output.print_nothing_to_sync();
+        return with_unreadable_files(0, unreadable);
+    }
+
+    let mut refused_count = 0;
+    for (index, (fe, lines)) in files.into_iter().enumerate() {
+        output.print_processing_info(&fe, index);
 
-        if !missing_keys.is_empty() {
-            let warning = DiffWarning::new(file.path().clone(), missing_keys);
+        let (lines, result) = sync::sync_lines(&lines, &reference_lines, opts.values, opts.prune);
+        output.print_result(&result);
+
+        if opts.dry_run {
+            output.print_dry_run(&lines);
+        } else if !result.is_empty() && opts.symlinks == SymlinkMode::Refuse && fe.path.is_symlink()
+        {
+            output.print_symlink_refused(&fe);
+            refused_count += 1;
+        } else if !result.is_empty() {
+            // create backup copy unless user specifies not to
+            if !opts.no_backup {
+                let backup_file = opts.backups.create(&fe.path)?;
+                output.print_backup(&backup_file);
+            }
 
-            warnings.push(warning)
+            fs_utils::write_file(&fe, lines, opts.symlinks)?;
         }
     }
 
-    // Create success message if no warnings found.
-    if warnings.is_empty() {
-        output.print_no_difference_found();
+    // The files which are not synced because of `--symlinks refuse` fail the command
+    let result = if refused_count > 0 { 1 } else { 0 };
+    with_unreadable_files(result, unreadable)
+}
+
+pub struct RestoreOptions<'a> {
+    /// File to restore or to list the backups of (all the files if it isn't set)
+    pub file: Option<&'a PathBuf>,
+    /// List the backups instead of restoring the file
+    pub list: bool,
+    /// Backup to restore, the latest backup of the file by default
+    pub backup: Option<&'a PathBuf>,
+    pub backups: BackupOptions,
+    pub quiet: bool,
+    pub symlinks: SymlinkMode,
+}
+
+/// Restores the file from its backup or lists the backups
+pub fn restore(opts: &RestoreOptions, current_dir: &PathBuf) -> Result<usize> {
+    let output = RestoreOutput::new(opts.quiet);
+
+    // The backups mirror the paths relative to the current directory
+    let file = opts
+        .file
+        .map(|file| file.strip_prefix(current_dir).unwrap_or(file).to_path_buf());
+
+    if opts.list {
+        let files = match &file {
+            Some(file) => BTreeMap::from([(file.clone(), opts.backups.list(file)?)]),
+            None => opts.backups.list_all()?,
+        };
+
+        if files.values().all(Vec::is_empty) {
+            output.print_no_backups(file.as_deref());
+            return Ok(1);
+        }
+
+        for (index, (file, backups)) in files.iter().enumerate() {
+            output.print_backups(file, backups, index);
+        }
         return Ok(0);
     }
 
-    output.print_warnings(&warnings);
-    Ok(warnings.len())
+    // The file is required without `list`
+    let Some(file) = file else {
+        return Ok(1);
+    };
+
+    let backup = match opts.backup {
+        Some(backup) if !backup.is_file() => {
+            output.print_backup_not_found(backup);
+            return Ok(1);
+        }
+        Some(backup) if !opts.backups.is_backup_of(backup, &file)? => {
+            output.print_not_backup_of(backup, &file);
+            return Ok(1);
+        }
+        Some(backup) => backup.clone(),
+        None => match opts.backups.list(&file)?.into_iter().next() {
+            Some(backup) => backup.path,
+            None => {
+                output.print_no_backups(Some(&file));
+                return Ok(1);
+            }
+        },
+    };
+
+    if opts.symlinks == SymlinkMode::Refuse && file.is_symlink() {
+        output.print_symlink_refused(&file);
+        return Ok(1);
+    }
+
+    // The backup is read before the current file is backed up, which can remove it
+    let contents = fs::read(&backup)?;
+    if file.is_file() {
+        let current_backup = opts.backups.create(&file)?;
+        output.print_backup(&current_backup);
+    }
+
+    fs_utils::write_bytes(&file, &contents, opts.symlinks)?;
+    output.print_restored(&file, &backup);
+
+    Ok(0)
 }
 
 /// Checks for updates and prints information about the new version to `STDOUT`
@@ -0,0 +1,89 @@
+//! Naming convention of the keys with the prefixes required in some directories
+
+use std::{
+    borrow::Cow,
+    path::{Component, Path, PathBuf},
+};
+
+use dotenv_analyzer::NamingConvention;
+
+#[derive(Clone, Debug, Default)]
+pub struct NamingOptions {
+    /// Convention of all the files, without prefixes
+    pub convention: NamingConvention,
+    /// Prefixes required in the directories (relative to the current directory, `None` for all
+    /// the files). The prefixes of the closest directory of a file are used.
+    pub prefixes: Vec<(Option<PathBuf>, String)>,
+}
+
+impl NamingOptions {
+    /// Parses a required prefix given as `PREFIX` or `DIR=PREFIX`
+    pub fn parse_prefix(value: &str) -> (Option<PathBuf>, String) {
+        match value.split_once('=') {
+            Some((dir, prefix)) => (Some(PathBuf::from(dir)), prefix.to_string()),
+            None => (None, value.to_string()),
+        }
+    }
+
+    /// Returns the convention of the file (a path relative to the current directory)
+    pub fn for_file(&self, file: &Path) -> Cow<'_, NamingConvention> {
+        let file_dir = normalize(file.parent().unwrap_or(Path::new("")));
+
+        // Prefixes of the closest directory which contains the file
+        let closest = self
+            .prefixes
+            .iter()
+            .filter_map(|(dir, _)| {
+                let dir = normalize(dir.as_deref().unwrap_or(Path::new("")));
+                file_dir.starts_with(&dir).then_some(dir)
+            })
+            .max_by_key(|dir| dir.components().count());
+
+        let Some(closest) = closest else {
+            return Cow::Borrowed(&self.convention);
+        };
+
+        let prefixes = self
+            .prefixes
+            .iter()
+            .filter(|(dir, _)| normalize(dir.as_deref().unwrap_or(Path::new(""))) == closest)
+            .map(|(_, prefix)| prefix.clone())
+            .collect();
+
+        Cow::Owned(NamingConvention {
+            prefixes,
+            ..self.convention.clone()
+        })
+    }
+}
+
+/// Removes the `.` components, so `./web` and `web` are the same directory
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_file_test() {
+        let options = NamingOptions {
+            prefixes: ["APP_", "web=NEXT_PUBLIC_", "./web=VITE_", "web/api=API_"]
+                .into_iter()
+                .map(NamingOptions::parse_prefix)
+                .collect(),
+            ..NamingOptions::default()
+        };
+
+        let prefixes = |file: &str| options.for_file(Path::new(file)).prefixes.clone();
+
+        assert_eq!(vec!["APP_"], prefixes(".env"));
+        assert_eq!(vec!["APP_"], prefixes("server/.env"));
+        assert_eq!(vec!["NEXT_PUBLIC_", "VITE_"], prefixes("web/.env"));
+        assert_eq!(vec!["NEXT_PUBLIC_", "VITE_"], prefixes("./web/app/.env"));
+        assert_eq!(vec!["API_"], prefixes("web/api/.env"));
+    }
+}
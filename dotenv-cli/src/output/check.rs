@@ -1,7 +1,14 @@
+use std::path::Path;
+
 use colored::*;
 use dotenv_analyzer::Warning;
 use dotenv_finder::FileEntry;
 
+use super::symlink_refused;
+
+/// Prefix for the backup output
+const BACKUP_PREFIX: &str = "Original file was backed up to: ";
+
 pub struct CheckOutput {
     // Quiet program output mode
     is_quiet_mode: bool,
@@ -38,6 +45,20 @@ pub fn print_processing_info(&self, file: &FileEntry) {
         }
     }
 
+    /// Prints the path of the backup made before fixing the file
+    pub fn print_backup(&self, backup_path: &Path) {
+        if !self.is_quiet_mode {
+            println!("{BACKUP_PREFIX}{backup_path:?}");
+        }
+    }
+
+    /// Prints that the file is not fixed, because it is a symbolic link
+    pub fn print_symlink_refused(&self, file: &FileEntry) {
+        if !self.is_quiet_mode {
+            println!("{}", symlink_refused(file).yellow());
+        }
+    }
+
     /// Prints warnings without any additional information
     pub fn print_warnings(&self, file: &FileEntry, warnings: &[Warning], file_index: usize) {
         warnings.iter().for_each(|w| {
@@ -1,7 +1,17 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
 use colored::Colorize;
-use dotenv_finder::FileEntry;
+use serde_json::json;
+
+use crate::diff::{self, DiffWarning, KeyLine, ValueRow, ValueStatus};
 
-use crate::diff::DiffWarning;
+/// Shown instead of the value of a key which is missing in a file
+const MISSING_VALUE: &str = "<missing>";
+/// Shown instead of secret values
+const MASKED_VALUE: &str = "********";
 
 pub struct DiffOutput {
     // Quiet program output mode
@@ -14,27 +24,185 @@ pub fn new(is_quiet_mode: bool) -> Self {
     }
 
     /// Prints information about a file in process
-    pub fn print_processing_info(&self, file: &FileEntry) {
+    pub fn print_processing_info(&self, path: &Path) {
         if !self.is_quiet_mode {
-            println!("Comparing {file}");
+            println!("Comparing {}", path.display());
         }
     }
 
+    /// Prints a blank line between the results of directories
+    pub fn print_group_separator(&self) {
+        println!();
+    }
+
     /// Prints warnings without any additional information
     pub fn print_warnings(&self, warnings: &[DiffWarning]) {
         warnings.iter().for_each(|w| {
-            println!(
-                "{} is missing keys: {}",
-                w.path().display().to_string().italic(),
-                w.missing_keys()
-                    .iter()
-                    .map(|k| k.red().bold().to_string())
-                    .collect::<Vec<String>>()
-                    .join(", ")
-            )
+            let path = w.path().display().to_string().italic();
+
+            if !w.missing_keys().is_empty() {
+                println!(
+                    "{path} is missing keys: {}",
+                    w.missing_keys()
+                        .iter()
+                        .map(|k| k.key.red().bold().to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
+
+            if !w.extra_keys().is_empty() {
+                println!(
+                    "{path} has extra keys: {}",
+                    w.extra_keys()
+                        .iter()
+                        .map(|k| format!("{} (line {})", k.key.yellow().bold(), k.number))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
         })
     }
 
+    /// Prints warnings like a unified diff: the lines of missing keys from the files which have
+    /// them (`-`) and the lines of extra keys (`+`). Secret values are masked unless `show_secrets`.
+    pub fn print_unified(&self, warnings: &[DiffWarning], show_secrets: bool) {
+        for w in warnings {
+            // The lines of missing keys by the files they are taken from
+            let mut blocks: BTreeMap<&PathBuf, Vec<String>> = BTreeMap::new();
+            for k in w.missing_keys() {
+                for line in key_text(k, show_secrets).lines() {
+                    blocks
+                        .entry(&k.path)
+                        .or_default()
+                        .push(format!("-{line}").red().to_string());
+                }
+            }
+
+            if let Some(reference) = w.reference() {
+                let block = blocks.entry(reference).or_default();
+                for k in w.extra_keys() {
+                    for line in key_text(k, show_secrets).lines() {
+                        block.push(format!("+{line}").green().to_string());
+                    }
+                }
+            }
+
+            for (source, lines) in blocks {
+                println!("{}", format!("--- {}", source.display()).bold());
+                println!("{}", format!("+++ {}", w.path().display()).bold());
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+        }
+    }
+
+    /// Prints warnings as a JSON document with the missing and extra keys of each file.
+    /// Secret values are masked unless `show_secrets`.
+    pub fn print_json(&self, warnings: &[DiffWarning], show_secrets: bool) {
+        let files: Vec<_> = warnings
+            .iter()
+            .map(|w| {
+                json!({
+                    "path": w.path(),
+                    "reference": w.reference(),
+                    "missing_keys": w
+                        .missing_keys()
+                        .iter()
+                        .map(|k| json!({
+                            "key": k.key,
+                            "path": k.path,
+                            "line": k.number,
+                            "text": key_text(k, show_secrets),
+                        }))
+                        .collect::<Vec<_>>(),
+                    "extra_keys": w
+                        .extra_keys()
+                        .iter()
+                        .map(|k| json!({
+                            "key": k.key,
+                            "line": k.number,
+                            "text": key_text(k, show_secrets),
+                        }))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        let document = json!({ "files": files });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).unwrap_or_default()
+        );
+    }
+
+    /// Prints a table of values: a row per key and a column per file.
+    /// Values of secret-looking keys are masked unless `show_secrets` is set.
+    pub fn print_values(&self, paths: &[PathBuf], rows: &[ValueRow], show_secrets: bool) {
+        let mut table: Vec<Vec<String>> = Vec::with_capacity(rows.len() + 1);
+
+        let mut header = vec!["Key".to_string(), "Status".to_string()];
+        header.extend(paths.iter().map(|path| path.display().to_string()));
+        table.push(header);
+
+        for row in rows {
+            let status = match row.status() {
+                ValueStatus::Equal => "equal",
+                ValueStatus::Different => "different",
+                ValueStatus::Missing => "missing",
+            };
+
+            let mut cells = vec![row.key().to_string(), status.to_string()];
+            cells.extend(row.values().iter().map(|value| match value {
+                None => MISSING_VALUE.to_string(),
+                Some(_) if !show_secrets && diff::is_secret_key(row.key()) => {
+                    MASKED_VALUE.to_string()
+                }
+                Some(value) if value.is_empty() => "\"\"".to_string(),
+                Some(value) => value.clone(),
+            }));
+            table.push(cells);
+        }
+
+        let widths: Vec<usize> = (0..table[0].len())
+            .map(|column| {
+                table
+                    .iter()
+                    .map(|cells| cells[column].chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for (index, cells) in table.iter().enumerate() {
+            let line = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<String>>()
+                .join("  ");
+            let line = line.trim_end();
+
+            let line = match index
+                .checked_sub(1)
+                .and_then(|i| rows.get(i))
+                .map(ValueRow::status)
+            {
+                None => line.bold(),
+                Some(ValueStatus::Equal) => line.normal(),
+                Some(ValueStatus::Different) => line.yellow(),
+                Some(ValueStatus::Missing) => line.red(),
+            };
+            println!("{line}");
+        }
+    }
+
+    /// Prints a message that the reference file doesn't exist
+    pub fn print_reference_not_found(&self, path: &Path) {
+        println!("Reference file not found: {}", path.display());
+    }
+
     /// Prints 'Nothing to compare' in the absence of '.env' files for compare
     pub fn print_nothing_to_compare(&self) {
         if !self.is_quiet_mode {
@@ -49,3 +217,12 @@ pub fn print_no_difference_found(&self) {
         }
     }
 }
+
+/// Returns the line of the key with the value masked if the key looks like a secret
+fn key_text(key: &KeyLine, show_secrets: bool) -> String {
+    if show_secrets || !diff::is_secret_key(&key.key) {
+        return key.line.clone();
+    }
+
+    format!("{}={MASKED_VALUE}", key.key)
+}
@@ -1,10 +1,14 @@
 use std::path::Path;
 
 use colored::*;
-use dotenv_analyzer::Warning;
+use dotenv_analyzer::{FixResult, Warning};
 use dotenv_core::LineEntry;
 use dotenv_finder::FileEntry;
 
+use super::symlink_refused;
+
+const LF: &str = "\n";
+
 /// Prefix for the backup output
 const BACKUP_PREFIX: &str = "Original file was backed up to: ";
 
@@ -37,9 +41,39 @@ pub fn print_processing_info(&self, file: &FileEntry) {
         }
     }
 
-    pub fn print_total(&self, total: usize) {
-        if total != 0 {
-            println!("\nAll warnings are fixed. Total: {total}");
+    pub fn print_total(
+        &self,
+        fixed: usize,
+        unsafe_skipped: usize,
+        declined: usize,
+        remaining: usize,
+        refused: usize,
+    ) {
+        if remaining != 0 || unsafe_skipped != 0 || declined != 0 {
+            println!(
+                "\nFixed warnings: {fixed}. {}",
+                format!("Not fixed: {}", remaining + unsafe_skipped + declined)
+                    .red()
+                    .bold()
+            );
+            if unsafe_skipped != 0 {
+                println!(
+                    "{}",
+                    format!("Unsafe fixes skipped: {unsafe_skipped} (use --unsafe-fixes to apply)")
+                        .yellow()
+                );
+            }
+            if declined != 0 {
+                println!("{}", format!("Fixes declined: {declined}").yellow());
+            }
+            if refused != 0 {
+                println!(
+                    "{}",
+                    format!("Symbolic links skipped: {refused} (see --symlinks)").yellow()
+                );
+            }
+        } else if fixed != 0 {
+            println!("\nAll warnings are fixed. Total: {fixed}");
         } else {
             println!("\nNo warnings found");
         }
@@ -53,13 +87,13 @@ pub fn print_backup(&self, backup_path: &Path) {
         }
     }
 
-    /// Prints warnings without any additional information
-    pub fn print_warnings(&self, file: &FileEntry, warnings: &[Warning], file_index: usize) {
+    /// Prints the fixed warnings and then the warnings which remain after fixing
+    pub fn print_warnings(&self, file: &FileEntry, result: &FixResult, file_index: usize) {
         if self.is_quiet_mode {
             return;
         }
 
-        warnings.iter().for_each(|w| {
+        let print = |w: &Warning| {
             let warning = format!(
                 "{} {}: {}",
                 format!("{}", w.line_number()).italic(),
@@ -69,14 +103,72 @@ pub fn print_warnings(&self, file: &FileEntry, warnings: &[Warning], file_index:
 
             let file = format!("{file}:").italic();
             println!("{file}{warning}")
-        });
+        };
+
+        result.fixed.iter().for_each(print);
+
+        if !result.unsafe_skipped.is_empty() {
+            println!("{}", "Unsafe fixes skipped:".yellow().bold());
+            result.unsafe_skipped.iter().for_each(print);
+        }
+
+        if !result.declined.is_empty() {
+            println!("{}", "Fixes declined:".yellow().bold());
+            result.declined.iter().for_each(print);
+        }
+
+        if !result.remaining.is_empty() {
+            println!("{}", "Could not fix:".red().bold());
+            result.remaining.iter().for_each(print);
+        }
 
         let is_last_file = file_index == self.files_count - 1;
-        if !warnings.is_empty() && !is_last_file {
+        if !is_last_file {
             println!();
         }
     }
 
+    /// Prints the warning with the lines before and after its fix (for `--interactive`).
+    /// It is printed in quiet mode too.
+    pub fn print_proposed_fix(&self, file: &FileEntry, warning: &Warning, lines: &[LineEntry]) {
+        println!(
+            "{} {}: {}",
+            format!("{file}:{}", warning.line_number()).italic(),
+            warning.check_name().to_string().red().bold(),
+            warning.message()
+        );
+
+        let Some(edit) = warning.edit() else {
+            return;
+        };
+        // The last line with only LF stands for the LF at the end of the file and a multiline
+        // value is shown on its lines
+        let print = |prefix: &str, line: &str| {
+            let line = if line == LF { "" } else { line };
+            for line in line.split(LF) {
+                let line = format!("{prefix} {line}");
+                match prefix {
+                    "-" => println!("{}", line.red()),
+                    _ => println!("{}", line.green()),
+                }
+            }
+        };
+
+        for line in lines.get(edit.range().clone()).unwrap_or_default() {
+            print("-", &line.raw_string);
+        }
+        for line in edit.lines() {
+            print("+", line);
+        }
+    }
+
+    /// Prints that the file is not fixed, because it is a symbolic link
+    pub fn print_symlink_refused(&self, file: &FileEntry) {
+        if !self.is_quiet_mode {
+            println!("{}", symlink_refused(file).yellow());
+        }
+    }
+
     /// Prints no files found message
     pub fn print_nothing_to_fix(&self) {
         if self.is_quiet_mode || self.files_count > 0 {
@@ -86,13 +178,21 @@ pub fn print_nothing_to_fix(&self) {
         println!("Nothing to fix");
     }
 
-    /// Prints not all warnings fixed message
-    pub fn print_not_all_warnings_fixed(&self) {
-        if self.is_quiet_mode {
-            return;
-        }
+    /// Prints a unified diff of the changes in a file. It is printed in quiet mode too.
+    pub fn print_patch(&self, patch: &str) {
+        print!("{patch}");
+    }
 
-        println!("{}", "Could not fix all warnings".red().bold());
+    /// Prints to stderr that the diff of the file is not printed, because it is outside the current
+    /// directory (see `patch::header_path`). It is printed in quiet mode too.
+    pub fn print_patch_skipped(&self, file: &FileEntry) {
+        eprintln!(
+            "{}",
+            format!(
+                "Skipped {file}: the diff of a file outside the current directory can't be applied"
+            )
+            .yellow()
+        );
     }
 
     /// Prints dry run message
@@ -1,3 +1,42 @@
+use std::{fmt, path::PathBuf};
+
+use colored::*;
+use dotenv_finder::FileError;
+
 pub mod check;
 pub mod diff;
 pub mod fix;
+pub mod restore;
+pub mod sync;
+
+/// Prints the files which are not read to stderr: the errors and, unless in the quiet mode,
+/// the skipped binary files. Returns the number of the errors.
+pub fn print_file_errors(errors: &[(PathBuf, FileError)], is_quiet_mode: bool) -> usize {
+    let mut count = 0;
+    for (path, error) in errors {
+        if error.is_skipped() {
+            if !is_quiet_mode {
+                eprintln!("Skipping {}: {error}", path.display());
+            }
+            continue;
+        }
+
+        count += 1;
+        let hint = match error {
+            FileError::InvalidUtf8 { .. } => " (use --encoding to read it)",
+            _ => "",
+        };
+        eprintln!(
+            "{} {}: {error}{hint}",
+            "Error reading".red().bold(),
+            path.display()
+        );
+    }
+
+    count
+}
+
+/// Returns the message about a file which is not changed, because it is a symbolic link
+fn symlink_refused(file: impl fmt::Display) -> String {
+    format!("Skipped {file}: it is a symbolic link (see --symlinks)")
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use colored::*;
+
+use super::symlink_refused;
+use crate::backup::Backup;
+
+pub struct RestoreOutput {
+    // Quiet program output mode
+    is_quiet_mode: bool,
+}
+
+impl RestoreOutput {
+    pub fn new(is_quiet_mode: bool) -> Self {
+        RestoreOutput { is_quiet_mode }
+    }
+
+    /// Prints the backups of a file, the latest one first
+    pub fn print_backups(&self, file: &Path, backups: &[Backup], file_index: usize) {
+        if file_index > 0 {
+            println!();
+        }
+
+        println!("Backups of {} (latest first):", file.display());
+        for backup in backups {
+            println!("  {}", backup.path.display());
+        }
+    }
+
+    /// Prints no backups found message
+    pub fn print_no_backups(&self, file: Option<&Path>) {
+        match file {
+            Some(file) => println!("No backups of {} found", file.display()),
+            None => println!("No backups found"),
+        }
+    }
+
+    /// Prints the backup not found message
+    pub fn print_backup_not_found(&self, backup: &Path) {
+        println!("Backup not found: {}", backup.display());
+    }
+
+    /// Prints that the file isn't backed up to the backup
+    pub fn print_not_backup_of(&self, backup: &Path, file: &Path) {
+        println!("{} is not a backup of {}", backup.display(), file.display());
+    }
+
+    /// Prints the backup of the current file made before restoring it
+    pub fn print_backup(&self, backup: &Path) {
+        if !self.is_quiet_mode {
+            println!("Current file was backed up to: {}", backup.display());
+        }
+    }
+
+    /// Prints that the file is not restored, because it is a symbolic link
+    pub fn print_symlink_refused(&self, file: &Path) {
+        println!("{}", symlink_refused(file.display()).yellow());
+    }
+
+    /// Prints the restored file and the backup it is restored from
+    pub fn print_restored(&self, file: &Path, backup: &Path) {
+        if self.is_quiet_mode {
+            return;
+        }
+
+        println!(
+            "Restored {} from {}",
+            file.display().to_string().green().bold(),
+            backup.display()
+        );
+    }
+}
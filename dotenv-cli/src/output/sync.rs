@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use colored::*;
+use dotenv_core::LineEntry;
+use dotenv_finder::FileEntry;
+
+use super::symlink_refused;
+use crate::sync::SyncResult;
+
+/// Prefix for the backup output
+const BACKUP_PREFIX: &str = "Original file was backed up to: ";
+
+pub struct SyncOutput {
+    // Quiet program output mode
+    is_quiet_mode: bool,
+}
+
+impl SyncOutput {
+    pub fn new(is_quiet_mode: bool) -> Self {
+        SyncOutput { is_quiet_mode }
+    }
+
+    /// Prints information about a file in process
+    pub fn print_processing_info(&self, file: &FileEntry, file_index: usize) {
+        if self.is_quiet_mode {
+            return;
+        }
+
+        if file_index > 0 {
+            println!();
+        }
+        println!("Syncing {file}");
+    }
+
+    /// Prints added and removed keys
+    pub fn print_result(&self, result: &SyncResult) {
+        if result.is_empty() {
+            if !self.is_quiet_mode {
+                println!("Already in sync");
+            }
+            return;
+        }
+
+        if !result.added.is_empty() {
+            println!(
+                "Added keys: {}",
+                result
+                    .added
+                    .iter()
+                    .map(|k| k.green().bold().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+
+        if !result.removed.is_empty() {
+            println!(
+                "Removed keys: {}",
+                result
+                    .removed
+                    .iter()
+                    .map(|k| k.red().bold().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    /// Prints the backup file's path
+    pub fn print_backup(&self, backup_path: &Path) {
+        println!("{BACKUP_PREFIX}{backup_path:?}");
+    }
+
+    /// Prints that the file is not changed, because it is a symbolic link
+    pub fn print_symlink_refused(&self, file: &FileEntry) {
+        println!("{}", symlink_refused(file).yellow());
+    }
+
+    /// Prints dry run message
+    pub fn print_dry_run(&self, lines: &[LineEntry]) {
+        if self.is_quiet_mode {
+            return;
+        }
+
+        println!(
+            "{}\n",
+            "Dry run - not changing any files on disk.".yellow().bold()
+        );
+
+        // The last line contains only LF (see `sync::sync_lines`)
+        for line in &lines[..lines.len() - 1] {
+            println!("{}", line.raw_string);
+        }
+    }
+
+    /// Prints the reference file not found message
+    pub fn print_reference_not_found(&self, path: &Path) {
+        println!("Reference file not found: {}", path.display());
+    }
+
+    /// Prints no files found message
+    pub fn print_nothing_to_sync(&self) {
+        if self.is_quiet_mode {
+            return;
+        }
+
+        println!("Nothing to sync");
+    }
+}
@@ -0,0 +1,239 @@
+//! Unified diffs of fixed files in the format of `git diff`, which can be applied with `git apply`
+
+use std::{
+    fmt::Write,
+    ops::Range,
+    path::{Component, Path},
+};
+
+/// Number of unchanged lines around the changes
+const CONTEXT: usize = 3;
+const NO_NEWLINE: &str = "\\ No newline at end of file";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Returns the path of the file in the headers of its diff: the path relative to the current
+/// directory with `/` separators. `git apply` rejects the paths going out of the directory, so
+/// `None` is returned for them.
+pub fn header_path(path: &Path) -> Option<String> {
+    let components = path
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(components.join("/"))
+}
+
+/// Returns the diff between the old and the new content of the file (with its `header_path`)
+/// or `None` if they are equal
+pub fn unified_diff(path: &str, old: &str, new: &str) -> Option<String> {
+    if old == new {
+        return None;
+    }
+
+    // Lines with their LF, so a missing LF at the end of the file is a change too
+    let old: Vec<&str> = old.split_inclusive('\n').collect();
+    let new: Vec<&str> = new.split_inclusive('\n').collect();
+    let ops = diff_ops(&old, &new);
+
+    let mut output = format!("diff --git a/{path} b/{path}\n--- a/{path}\n+++ b/{path}\n");
+
+    for hunk in hunks(&ops) {
+        let (old_start, new_start) = position(&ops[..hunk.start]);
+        let ops = &ops[hunk];
+        let old_count = ops.iter().filter(|op| !matches!(op, Op::Insert(_))).count();
+        let new_count = ops.iter().filter(|op| !matches!(op, Op::Delete(_))).count();
+
+        let _ = writeln!(
+            output,
+            "@@ -{} +{} @@",
+            range(old_start, old_count),
+            range(new_start, new_count)
+        );
+
+        for op in ops {
+            let (prefix, line) = match *op {
+                Op::Equal(i, _) => (' ', old[i]),
+                Op::Delete(i) => ('-', old[i]),
+                Op::Insert(j) => ('+', new[j]),
+            };
+
+            output.push(prefix);
+            output.push_str(line);
+            if !line.ends_with('\n') {
+                output.push('\n');
+                output.push_str(NO_NEWLINE);
+                output.push('\n');
+            }
+        }
+    }
+
+    Some(output)
+}
+
+/// Returns the operations turning `old` lines into `new` lines (based on the longest common
+/// subsequence)
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<Op> {
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push(Op::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            // Deleted lines go before inserted ones
+            ops.push(Op::Delete(i));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(j));
+            j += 1;
+        }
+    }
+
+    ops
+}
+
+/// Returns ranges of the operations to show: the changes with `CONTEXT` lines around them.
+/// Changes which are close to each other share a hunk.
+fn hunks(ops: &[Op]) -> Vec<Range<usize>> {
+    let mut hunks: Vec<Range<usize>> = Vec::new();
+
+    for (index, op) in ops.iter().enumerate() {
+        if matches!(op, Op::Equal(..)) {
+            continue;
+        }
+
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + CONTEXT + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.end => hunk.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+
+    hunks
+}
+
+/// Returns the numbers of the old and the new lines taken by the operations
+fn position(ops: &[Op]) -> (usize, usize) {
+    ops.iter().fold((0, 0), |(old, new), op| match op {
+        Op::Equal(..) => (old + 1, new + 1),
+        Op::Delete(_) => (old + 1, new),
+        Op::Insert(_) => (old, new + 1),
+    })
+}
+
+/// Formats the range of a hunk header: the start is 1-based, or the line before the hunk
+/// if it is empty
+fn range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{count}", start + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_path_test() {
+        assert_eq!(Some(".env".to_string()), header_path(Path::new("./.env")));
+        assert_eq!(
+            Some("config/.env".to_string()),
+            header_path(Path::new("config/.env"))
+        );
+        assert_eq!(None, header_path(Path::new("../.env")));
+        assert_eq!(None, header_path(Path::new("/tmp/.env")));
+    }
+
+    #[test]
+    fn equal_content() {
+        assert_eq!(None, unified_diff(".env", "A=1\n", "A=1\n"));
+    }
+
+    #[test]
+    fn changed_lines() {
+        let old = "a=1\nB=2\nC=3\nD=4\nE=5\nF=6\nG=7\nH=8\nI=9 \n";
+        let new = "A=1\nB=2\nC=3\nD=4\nE=5\nF=6\nG=7\nH=8\nI=9\n";
+
+        let expected = "\
+diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1,4 +1,4 @@
+-a=1
++A=1
+ B=2
+ C=3
+ D=4
+@@ -6,4 +6,4 @@
+ F=6
+ G=7
+ H=8
+-I=9 
++I=9
+";
+
+        assert_eq!(Some(expected.to_string()), unified_diff(".env", old, new));
+    }
+
+    #[test]
+    fn deleted_line() {
+        let expected = "\
+diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1,3 +1,2 @@
+ A=1
+-
+ B=2
+";
+
+        assert_eq!(
+            Some(expected.to_string()),
+            unified_diff(".env", "A=1\n\nB=2\n", "A=1\nB=2\n")
+        );
+    }
+
+    #[test]
+    fn added_final_line_feed() {
+        let expected = "\
+diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1 +1 @@
+-A=1
+\\ No newline at end of file
++A=1
+";
+
+        assert_eq!(
+            Some(expected.to_string()),
+            unified_diff(".env", "A=1", "A=1\n")
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::{fs, path::PathBuf};
+
+use colored::*;
+use dotenv_schema::{DotEnvSchema, json_schema, render};
+
+use crate::{Result, cli::RenderFormat};
+
+pub struct RenderOptions<'a> {
+    pub schema: &'a DotEnvSchema,
+    pub format: RenderFormat,
+    pub output: Option<&'a PathBuf>,
+}
+
+/// Renders a schema as a `.env.example` file or reference documentation
+pub fn render(opts: &RenderOptions) -> Result<()> {
+    let content = match opts.format {
+        RenderFormat::Env => render::to_dotenv(opts.schema),
+        RenderFormat::Markdown => render::to_markdown(opts.schema),
+        RenderFormat::Html => render::to_html(opts.schema),
+    };
+
+    write_output(opts.output, &content)
+}
+
+/// Converts a schema to a JSON Schema document
+pub fn export(schema: &DotEnvSchema, output: Option<&PathBuf>) -> Result<()> {
+    let document = json_schema::to_json_schema(schema);
+    write_output(output, &to_json(&document)?)
+}
+
+/// Converts a JSON Schema document to a schema
+pub fn import(path: &PathBuf, output: Option<&PathBuf>) -> Result<()> {
+    let document = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let schema = json_schema::from_json_schema(&document)?;
+
+    // Going through `Value` sorts the keys, so the result is stable
+    write_output(output, &to_json(&serde_json::to_value(schema)?)?)
+}
+
+/// Validates a schema file (and the files it extends) and prints all the problems found.
+/// Returns the number of problems.
+pub fn check(path: &PathBuf) -> usize {
+    println!("Checking {}", path.display());
+
+    let errors = match DotEnvSchema::check(path) {
+        Ok(_) => {
+            println!("\n{}", "No problems found".green().bold());
+            return 0;
+        }
+        Err(errors) => errors,
+    };
+
+    for err in &errors {
+        let location = match err.position {
+            Some((line, column)) => format!("{}:{line}:{column}", err.path.display()),
+            None => err.path.display().to_string(),
+        };
+        println!("{} {}", location.italic(), err.message);
+    }
+
+    let problems = if errors.len() == 1 {
+        "problem"
+    } else {
+        "problems"
+    };
+    println!(
+        "\n{}",
+        format!("Found {} {problems}", errors.len()).red().bold()
+    );
+
+    errors.len()
+}
+
+fn to_json(value: &serde_json::Value) -> Result<String> {
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    Ok(json)
+}
+
+fn write_output(output: Option<&PathBuf>, content: &str) -> Result<()> {
+    match output {
+        Some(path) => fs::write(path, content)?,
+        None => print!("{content}"),
+    }
+
+    Ok(())
+}
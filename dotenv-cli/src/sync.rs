@@ -0,0 +1,386 @@
+use std::collections::HashSet;
+
+use dotenv_core::{LineEnding, LineEntry};
+
+use crate::cli::SyncValues;
+
+const LF: &str = "\n";
+
+/// Keys changed by `sync` in a file, in the order they were changed
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SyncResult {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl SyncResult {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Adds keys of the reference which are missing in the lines and, if `prune` is set,
+/// removes keys which are not in the reference.
+///
+/// A missing key is added with the comments above it in the reference. It is inserted into
+/// the group (see `dotenv_analyzer::is_group_separator`) with most of the keys of its reference
+/// group: in the sorted position if the group is sorted, or at the end of the group otherwise.
+/// If the file has none of the keys of a reference group, the group is added at the end.
+/// A pruned key is removed with the comments above it.
+///
+/// The lines keep their line endings and the byte order mark of the file, the added lines get
+/// the line ending of the file.
+pub fn sync_lines(
+    lines: &[LineEntry],
+    reference: &[LineEntry],
+    values: SyncValues,
+    prune: bool,
+) -> (Vec<LineEntry>, SyncResult) {
+    let ending = lines
+        .first()
+        .or(reference.first())
+        .map(|line| line.ending)
+        .unwrap_or_default();
+    let bom = lines.first().is_some_and(|line| line.bom);
+
+    let mut body: Vec<LineEntry> = lines
+        .iter()
+        .filter(|line| !is_final_lf(line))
+        .cloned()
+        .collect();
+    let mut result = SyncResult::default();
+
+    if prune {
+        let reference_keys: HashSet<&str> = reference.iter().filter_map(|l| l.get_key()).collect();
+        let mut pruned = vec![false; body.len()];
+        for (index, line) in body.iter().enumerate() {
+            let Some(key) = line.get_key().filter(|key| !reference_keys.contains(key)) else {
+                continue;
+            };
+
+            if !result.removed.iter().any(|k| k == key) {
+                result.removed.push(key.to_string());
+            }
+            pruned[comments_start(&body, index)..=index].fill(true);
+        }
+
+        let mut pruned = pruned.into_iter();
+        body.retain(|_| !pruned.next().unwrap_or_default());
+    }
+
+    for group in reference_groups(reference) {
+        let missing: Vec<usize> = group
+            .iter()
+            .copied()
+            .filter(|&index| {
+                reference[index].get_key().is_some_and(|key| {
+                    !has_key(&body, key) && !result.added.iter().any(|k| k == key)
+                })
+            })
+            .collect();
+        let group_keys: Vec<&str> = group
+            .iter()
+            .filter_map(|&i| reference[i].get_key())
+            .collect();
+
+        // None of the group keys are in the file, so the group is added as a whole at the end
+        if !group_keys.iter().any(|key| has_key(&body, key)) {
+            if !missing.is_empty() && body.last().is_some_and(|line| !line.is_empty()) {
+                body.push(new_line(String::new(), ending));
+            }
+
+            for &index in &missing {
+                body.extend(key_block(reference, index, values, ending));
+                result
+                    .added
+                    .extend(reference[index].get_key().map(str::to_string));
+            }
+            continue;
+        }
+
+        for &index in &missing {
+            let key = reference[index].get_key().unwrap_or_default();
+            let position = insert_position(&body, key, &group_keys).unwrap_or(body.len());
+            body.splice(
+                position..position,
+                key_block(reference, index, values, ending),
+            );
+            result.added.push(key.to_string());
+        }
+    }
+
+    let total = body.len() + 1;
+    let lines = body
+        .into_iter()
+        .chain([new_line(LF.to_string(), ending)])
+        .enumerate()
+        .map(|(index, line)| LineEntry {
+            number: index + 1,
+            is_last_line: index + 1 == total,
+            bom: bom && index == 0,
+            ..line
+        })
+        .collect();
+
+    (lines, result)
+}
+
+/// Returns a line to add to the file, it is numbered when all the lines are in place
+fn new_line(raw_string: String, ending: LineEnding) -> LineEntry {
+    LineEntry {
+        ending,
+        ..LineEntry::new(0, raw_string, false)
+    }
+}
+
+/// The trailing entry which the finder adds for the LF at the end of the file
+fn is_final_lf(line: &LineEntry) -> bool {
+    line.is_last_line && line.raw_string == LF
+}
+
+fn has_key(body: &[LineEntry], key: &str) -> bool {
+    body.iter().any(|line| line.get_key() == Some(key))
+}
+
+/// Returns indexes of the key lines of the reference split into groups
+fn reference_groups(reference: &[LineEntry]) -> Vec<Vec<usize>> {
+    let mut groups = vec![Vec::new()];
+
+    for (index, line) in reference.iter().enumerate() {
+        if dotenv_analyzer::is_group_separator(line) {
+            groups.push(Vec::new());
+        } else if line.get_key().is_some()
+            && let Some(group) = groups.last_mut()
+        {
+            group.push(index);
+        }
+    }
+
+    groups.retain(|group| !group.is_empty());
+    groups
+}
+
+/// Returns the lines to add for the key of the reference line: its comments and the key itself
+fn key_block(
+    reference: &[LineEntry],
+    index: usize,
+    values: SyncValues,
+    ending: LineEnding,
+) -> Vec<LineEntry> {
+    let line = &reference[index];
+    let value_line = match (values, line.get_key()) {
+        (SyncValues::Empty, Some(key)) => format!("{key}="),
+        _ => line.raw_string.clone(),
+    };
+
+    reference[comments_start(reference, index)..index]
+        .iter()
+        .map(|line| line.raw_string.clone())
+        .chain([value_line])
+        .map(|raw_string| new_line(raw_string, ending))
+        .collect()
+}
+
+/// Returns the index of the first line of the comments directly above the line
+fn comments_start(lines: &[LineEntry], index: usize) -> usize {
+    lines[..index]
+        .iter()
+        .rposition(|line| !line.is_comment() || dotenv_analyzer::is_group_separator(line))
+        .map_or(0, |position| position + 1)
+}
+
+/// Returns the index to insert the key (with its comments) at: a position in the group with most
+/// of the keys of its reference group or `None` if there is no such group
+fn insert_position(lines: &[LineEntry], key: &str, group_keys: &[&str]) -> Option<usize> {
+    // Ranges of the lines between the group separators
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for (index, line) in lines.iter().enumerate() {
+        if dotenv_analyzer::is_group_separator(line) {
+            groups.push(start..index);
+            start = index + 1;
+        }
+    }
+    groups.push(start..lines.len());
+
+    let mut best = None;
+    let mut best_count = 0;
+    for group in groups {
+        let count = lines[group.clone()]
+            .iter()
+            .filter_map(LineEntry::get_key)
+            .filter(|k| group_keys.contains(k))
+            .count();
+        if count > best_count {
+            best = Some(group);
+            best_count = count;
+        }
+    }
+    let group = best?;
+
+    let group_lines = &lines[group.clone()];
+    let group_keys: Vec<&str> = group_lines.iter().filter_map(LineEntry::get_key).collect();
+    if !group_keys.is_sorted() {
+        return Some(group.end);
+    }
+
+    // Insert before the first greater key and its comments
+    let position = group_lines
+        .iter()
+        .position(|line| line.get_key().is_some_and(|k| k > key))
+        .map(|index| group.start + comments_start(group_lines, index))
+        .unwrap_or(group.end);
+
+    Some(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_entries(raw: &str) -> Vec<LineEntry> {
+        let mut lines: Vec<&str> = raw.lines().collect();
+        if raw.ends_with('\n') {
+            lines.push(LF);
+        }
+
+        let total = lines.len();
+        (1..)
+            .zip(lines)
+            .map(|(number, line)| LineEntry::new(number, line, number == total))
+            .collect()
+    }
+
+    fn sync(
+        target: &str,
+        reference: &str,
+        values: SyncValues,
+        prune: bool,
+    ) -> (String, SyncResult) {
+        let (lines, result) = sync_lines(
+            &line_entries(target),
+            &line_entries(reference),
+            values,
+            prune,
+        );
+
+        let content = lines[..lines.len() - 1]
+            .iter()
+            .map(|line| format!("{}\n", line.raw_string))
+            .collect();
+
+        (content, result)
+    }
+
+    #[test]
+    fn inserts_into_sorted_group() {
+        let reference = "A=\n# The B key\nB=\nC=\n\nX=\n";
+        let (content, result) = sync("A=1\nC=3\n\nX=1\n", reference, SyncValues::Empty, false);
+
+        assert_eq!("A=1\n# The B key\nB=\nC=3\n\nX=1\n", content);
+        assert_eq!(vec!["B"], result.added);
+    }
+
+    #[test]
+    fn inserts_before_comments_of_greater_key() {
+        let reference = "A=\nB=\nC=\n";
+        let (content, _) = sync(
+            "A=1\n# C comment\nC=3\n",
+            reference,
+            SyncValues::Empty,
+            false,
+        );
+
+        assert_eq!("A=1\nB=\n# C comment\nC=3\n", content);
+    }
+
+    #[test]
+    fn appends_to_unsorted_group() {
+        let reference = "A=\nB=\nC=\n";
+        let (content, _) = sync("C=3\nA=1\n\nX=1\n", reference, SyncValues::Empty, false);
+
+        assert_eq!("C=3\nA=1\nB=\n\nX=1\n", content);
+    }
+
+    #[test]
+    fn appends_new_group() {
+        let reference = "A=\n\n# Cache\nCACHE_URL=redis://\nCACHE_TTL=60\n";
+        let (content, result) = sync("A=1\n", reference, SyncValues::Reference, false);
+
+        assert_eq!(
+            "A=1\n\n# Cache\nCACHE_URL=redis://\nCACHE_TTL=60\n",
+            content
+        );
+        assert_eq!(vec!["CACHE_URL", "CACHE_TTL"], result.added);
+    }
+
+    #[test]
+    fn control_comments_are_not_copied() {
+        let reference = "# dotenv-linter:off LowercaseKey\nA=\n";
+        let (content, _) = sync("", reference, SyncValues::Empty, false);
+
+        assert_eq!("A=\n", content);
+    }
+
+    #[test]
+    fn adds_final_line_feed() {
+        let (content, _) = sync("A=1", "A=\nB=\n", SyncValues::Empty, false);
+
+        assert_eq!("A=1\nB=\n", content);
+    }
+
+    #[test]
+    fn prunes_extra_keys() {
+        let reference = "A=\nB=\n";
+        let (content, result) = sync("A=1\nB=2\nLOCAL=3\n", reference, SyncValues::Empty, true);
+
+        assert_eq!("A=1\nB=2\n", content);
+        assert_eq!(vec!["LOCAL"], result.removed);
+        assert!(result.added.is_empty());
+    }
+
+    #[test]
+    fn prunes_comments_of_extra_keys() {
+        let reference = "# The A key\nA=\n";
+        let (content, _) = sync(
+            "# The A key\nA=1\n# Local\nLOCAL=3\n\n# dotenv-linter:off\nB=2\n",
+            reference,
+            SyncValues::Empty,
+            true,
+        );
+
+        assert_eq!("# The A key\nA=1\n\n# dotenv-linter:off\n", content);
+    }
+
+    #[test]
+    fn keeps_line_endings_and_bom() {
+        let mut lines = line_entries("A=1\nLOCAL=2\n");
+        for line in &mut lines {
+            line.ending = LineEnding::CrLf;
+        }
+        lines[0].bom = true;
+
+        let (lines, _) = sync_lines(&lines, &line_entries("A=\nB=\n"), SyncValues::Empty, true);
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|line| (line.raw_string.as_str(), line.ending, line.bom))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("A=1", LineEnding::CrLf, true),
+                ("B=", LineEnding::CrLf, false),
+                (LF, LineEnding::CrLf, false),
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn already_in_sync() {
+        let (content, result) = sync("B=2\nA=1\n", "A=\nB=\n", SyncValues::Empty, true);
+
+        assert_eq!("B=2\nA=1\n", content);
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = ["A=B\nFOO=BAR\n", "A=B\u{feff}\nFOO=BAR\n"];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        // U+FEFF which is not at the start of the file is reported by SuspiciousCharacter
+        let args = &[
+            "check",
+            testfile.as_str(),
+            "--ignore-checks",
+            "SuspiciousCharacter",
+        ];
+
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn incorrect_files() {
+    let contents = ["\u{feff}A=B\nFOO=BAR\n", "\u{feff}# Comment\r\nA=B\r\n"];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &["check", testfile.as_str()];
+
+        let expected_output =
+            check_output(&[(".env", &[".env:1 ByteOrderMark: Byte order mark detected"])]);
+
+        testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = ["FOO=BAR\nFOO_BAR=BAZ\n", "FOO=BAR\nFOO=BAZ\n"];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &[
+            "check",
+            testfile.as_str(),
+            "--ignore-checks",
+            "DuplicatedKey",
+        ];
+
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn incorrect_files() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR=1\nFOO=TEST\nfoo=test\n");
+    let args = &["check", testfile.as_str()];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:2 CaseInsensitiveDuplicatedKey: The FOO key differs only in case from foo",
+            ".env:3 LowercaseKey: The foo key should be in uppercase",
+            ".env:3 CaseInsensitiveDuplicatedKey: The foo key differs only in case from FOO",
+        ],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
@@ -0,0 +1,39 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = [
+        "A=B\nF=BAR\nFOO=BAR\n",
+        "A=B\r\nF=BAR\r\nFOO=BAR\r\n",
+        "# comment\r\nA=B\r\nFOO=BAR\r\n",
+    ];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &["check", testfile.as_str()];
+
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn incorrect_files() {
+    let contents = ["A=B\r\nF=BAR\nFOO=BAR\r\n", "A=B\nF=BAR\r\nFOO=BAR\n"];
+    let expected = ["LF instead of CRLF", "CRLF instead of LF"];
+
+    for (content, expected) in contents.iter().zip(expected) {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &["check", testfile.as_str()];
+
+        let expected_output = check_output(&[(
+            ".env",
+            &[format!(".env:2 MixedLineEndings: The line ends with {expected}").as_str()],
+        )]);
+
+        testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+    }
+}
@@ -1,3 +1,5 @@
+mod byte_order_mark;
+mod case_insensitive_duplicated_key;
 mod duplicated_key;
 mod ending_blank_line;
 mod extra_blank_line;
@@ -5,10 +7,14 @@
 mod key_without_value;
 mod leading_character;
 mod lowercase_key;
+mod mixed_line_endings;
+mod naming_convention;
 mod quote_character;
 mod schema;
 mod space_character;
 mod substitution_key;
+mod suspicious_character;
+mod syntax_error;
 mod trailing_whitespace;
 mod unordered_key;
 mod value_without_quotes;
@@ -0,0 +1,106 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = [
+        "A=B\nFOO_BAR=BAZ\n",
+        "_FOO=BAR\n",
+        "LOGGING__LOG_LEVEL=DEBUG\n",
+    ];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &["check", testfile.as_str()];
+
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn incorrect_files() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR_=1\nFOO__BAR=2\nPATH=/bin\n");
+    let args = &["check", testfile.as_str(), "--key-style", "strict"];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:1 NamingConvention: The BAR_ key should not end with an underscore",
+            ".env:2 NamingConvention: The FOO__BAR key should not contain double underscores",
+            ".env:3 NamingConvention: The PATH key is a reserved name",
+        ],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn default_style() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR_=1\nFOO__BAR=2\nPATH=/bin\n");
+    let args = &["check", testfile.as_str()];
+
+    // Only the reserved names are checked without --key-style strict
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:3 NamingConvention: The PATH key is a reserved name"],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn loose_style() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO__BAR=1\nPATH=/bin\n");
+    let args = &[
+        "check",
+        testfile.as_str(),
+        "--key-style",
+        "loose",
+        "--reserved-names",
+        "HOME",
+    ];
+
+    let expected_output = check_output(&[(".env", &[])]);
+
+    testdir.test_command_success_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn max_key_length() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "ABC=1\nABCDEF=2\n");
+    let args = &["check", testfile.as_str(), "--max-key-length", "5"];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:2 NamingConvention: The ABCDEF key is longer than 5 characters"],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn key_prefix_in_directory() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "API_URL=1\n");
+    let subdir = testdir.subdir();
+    subdir.create_testfile(".env", "API_URL=1\nVITE_API_URL=2\n");
+
+    let relative = testdir.relative_path(&subdir).to_string();
+    let path = format!("{relative}/.env");
+    let warning = format!(
+        "{path}:1 NamingConvention: The API_URL key should start with NEXT_PUBLIC_ or VITE_"
+    );
+    let expected_output = check_output(&[(".env", &[]), (path.as_str(), &[warning.as_str()])]);
+
+    let prefixes = format!("{relative}=NEXT_PUBLIC_,{relative}=VITE_");
+    testdir.test_command_fail_with_args(
+        with_default_args(&["check", ".", "--recursive", "--key-prefix", &prefixes]),
+        expected_output,
+    );
+}
@@ -90,3 +90,52 @@ fn load_missing_schema() {
     };
     testdir.test_command_fail_with_args(with_default_args(args), expected_output);
 }
+
+#[test]
+fn extends_and_overlay_by_file_name() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "base.json",
+        r#"{
+        "version": "1.0.0",
+        "entries": {
+            "NAME": {
+                "type": "String"
+            },
+            "SENTRY_DSN": {
+                "type": "Url"
+            }
+        }
+    }"#,
+    );
+    let test_schema = testdir.create_testfile(
+        "schema.json",
+        r#"{
+        "version": "1.0.0",
+        "extends": ["base.json"],
+        "overlays": {
+            "production": {
+                "entries": {
+                    "SENTRY_DSN": {
+                        "type": "Url",
+                        "required": true
+                    }
+                }
+            }
+        }
+    }"#,
+    );
+    testdir.create_testfile(".env.development", "NAME=JOE\n");
+    testdir.create_testfile(".env.production", "NAME=JOE\n");
+    let args = &["check", "--schema", test_schema.as_str(), "."];
+
+    let expected_output = check_output(&[
+        (".env.development", &[]),
+        (
+            ".env.production",
+            &[".env.production:2 SchemaViolation: The SENTRY_DSN key is required"],
+        ),
+    ]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
@@ -0,0 +1,41 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = ["A=B\n", "GREETING=\"Привет мир\"\n", "# Комментарий\nA=B\n"];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &["check", testfile.as_str()];
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn incorrect_files() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API\u{200B}_KEY=1\nB=p\u{0430}ssword\nF\u{041E}O=2\n",
+    );
+    let args = &[
+        "check",
+        testfile.as_str(),
+        "--ignore-checks",
+        "UnorderedKey",
+    ];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:1 SuspiciousCharacter: Invisible character U+200B (ZERO WIDTH SPACE) detected",
+            ".env:2 SuspiciousCharacter: The value of the B key mixes Latin and Cyrillic letters in a word",
+            ".env:3 SuspiciousCharacter: The F\u{041E}O key has a non-ASCII character U+041E",
+        ],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
@@ -0,0 +1,101 @@
+use crate::common::*;
+
+#[test]
+fn correct_files() {
+    let contents = [
+        "A=B\nFOO=BAR\\\\\n",
+        "A=\"multi\nline\"\nB=C\n",
+        "A='B \\' C'\nD=E\n",
+    ];
+
+    for content in contents {
+        let testdir = TestDir::new();
+        let testfile = testdir.create_testfile(".env", content);
+        let args = &[
+            "check",
+            testfile.as_str(),
+            "--ignore-checks",
+            "QuoteCharacter",
+        ];
+
+        let expected_output = check_output(&[(".env", &[])]);
+
+        testdir.test_command_success_with_args(with_default_args(args), expected_output);
+    }
+}
+
+#[test]
+fn unterminated_quote() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=\"foo\nB=bar\nC='baz\nD=qux\n");
+    let args = &[
+        "check",
+        testfile.as_str(),
+        "--ignore-checks",
+        "QuoteCharacter",
+    ];
+
+    // The lines after the unterminated quotes are checked as usual
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:1 SyntaxError: The A key has an unterminated double quote",
+            ".env:3 SyntaxError: The C key has an unterminated single quote",
+        ],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn unterminated_quote_before_quoted_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=\"foo\nB=\"bar\"\nc=baz\n");
+    let args = &[
+        "check",
+        testfile.as_str(),
+        "--ignore-checks",
+        "QuoteCharacter",
+    ];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:1 SyntaxError: The A key has an unterminated double quote",
+            ".env:3 LowercaseKey: The c key should be in uppercase",
+        ],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn multiline_value_with_assignment() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "SCRIPT=\"\nexport A='x'\necho hi\n\"\n");
+    let args = &[
+        "check",
+        testfile.as_str(),
+        "--ignore-checks",
+        "QuoteCharacter",
+    ];
+
+    // The line which looks like an assignment is a part of the value
+    let expected_output = check_output(&[(".env", &[])]);
+
+    testdir.test_command_success_with_args(with_default_args(args), expected_output);
+}
+
+#[test]
+fn trailing_backslash() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=foo\\\nB=bar\n");
+    let args = &["check", testfile.as_str()];
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:1 SyntaxError: The A key has a trailing unescaped backslash"],
+    )]);
+
+    testdir.test_command_fail_with_args(with_default_args(args), expected_output);
+}
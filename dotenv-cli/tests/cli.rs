@@ -6,3 +6,6 @@
 mod flags;
 mod options;
 mod output;
+mod restore;
+mod schema;
+mod sync;
@@ -61,6 +61,11 @@ pub fn create_testfile(&self, name: &str, contents: &str) -> TestFile {
         TestFile::new(&self.current_dir, name, contents)
     }
 
+    /// Create a TestFile with contents which can be not UTF-8 within the TestDir
+    pub fn create_testfile_with_bytes(&self, name: &str, contents: &[u8]) -> TestFile {
+        TestFile::with_bytes(&self.current_dir, name, contents)
+    }
+
     /// Create a new TestLink to a TestDir within the TestDir
     pub fn create_symlink(&self, source_test_dir: &Self, name: &str) {
         let dest = &self.current_dir.path().join(name);
@@ -120,6 +125,30 @@ pub fn test_command_fail_with_args<I, S, T>(self, args: I, expected_output: T)
         self.close();
     }
 
+    /// Run the default CLI binary, with command line arguments, in this TestDir and check
+    /// it exits with the code and prints the output and the errors.
+    ///
+    /// This method does NOT remove TestDir when finished
+    pub fn test_command_with_code<I, S>(
+        &self,
+        args: I,
+        code: i32,
+        expected_output: &str,
+        expected_errors: &str,
+    ) where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = self.init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .assert()
+            .code(code)
+            .stdout(expected_output.to_string())
+            .stderr(expected_errors.to_string());
+    }
+
     /// Run the default CLI binary, with "-f", in this TestDir and check it succeeds.
     ///
     /// This method does NOT remove TestDir when finished
@@ -212,6 +241,58 @@ pub fn test_command_success_and_get_output<I, S>(&self, args: I) -> String
         )
     }
 
+    /// Run the default CLI binary, with command line arguments and the input, in this TestDir
+    /// and check it succeeds. Return the output from the command.
+    ///
+    /// This method does NOT remove TestDir when finished
+    pub fn test_command_with_input_and_get_output<I, S>(&self, args: I, input: &str) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = self.init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        String::from(
+            from_utf8(
+                cmd.current_dir(&canonical_current_dir)
+                    .args(args)
+                    .write_stdin(input)
+                    .assert()
+                    .success()
+                    .get_output()
+                    .stdout
+                    .as_slice(),
+            )
+            .expect("convert to &str"),
+        )
+    }
+
+    /// Run the default CLI binary, with command line arguments, in this TestDir
+    /// and check it fails. Return the output from the command.
+    ///
+    /// This method does NOT remove TestDir when finished
+    pub fn test_command_fail_and_get_output<I, S>(&self, args: I) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = self.init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        String::from(
+            from_utf8(
+                cmd.current_dir(&canonical_current_dir)
+                    .args(args)
+                    .assert()
+                    .failure()
+                    .code(1)
+                    .get_output()
+                    .stdout
+                    .as_slice(),
+            )
+            .expect("convert to &str"),
+        )
+    }
+
     fn init_cmd(&self) -> Command {
         let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).expect("command from binary name");
 
@@ -10,9 +10,14 @@ pub struct TestFile {
 impl TestFile {
     /// Create a new file and write its contents
     pub fn new(test_dir: &TempDir, name: &str, contents: &str) -> Self {
+        Self::with_bytes(test_dir, name, contents.as_bytes())
+    }
+
+    /// Create a new file and write its contents which can be not UTF-8
+    pub fn with_bytes(test_dir: &TempDir, name: &str, contents: &[u8]) -> Self {
         let file_path = test_dir.path().join(name);
         let mut file = File::create(&file_path).expect("create testfile");
-        file.write_all(contents.as_bytes()).expect("write to file");
+        file.write_all(contents).expect("write to file");
 
         Self { file_path }
     }
@@ -35,4 +40,9 @@ pub fn shortname_as_str(&self) -> &str {
     pub fn contents(&self) -> String {
         fs::read_to_string(self.as_str()).expect("read file")
     }
+
+    /// Get file contents which can be not UTF-8
+    pub fn bytes(&self) -> Vec<u8> {
+        fs::read(self.as_str()).expect("read file")
+    }
 }
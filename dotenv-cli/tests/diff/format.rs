@@ -0,0 +1,125 @@
+use crate::common::TestDir;
+
+#[test]
+fn sorted_keys() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "ZOO=\nFOO=\nBAR=\n");
+    test_dir.create_testfile(".env", "Y=1\nX=1\n");
+    let expected_output = "\
+Comparing .env
+.env is missing keys: BAR, FOO, ZOO
+.env has extra keys: X (line 2), Y (line 1)
+";
+
+    test_dir.test_command_fail_with_args(
+        ["diff", "--reference", ".env.example", ".env"],
+        expected_output,
+    );
+}
+
+#[test]
+fn unified_with_reference() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=foo\nBAR=bar\nAPI_TOKEN=example\n");
+    test_dir.create_testfile(".env", "FOO=1\nLOCAL=1\n");
+    let expected_output = "\
+Comparing .env
+--- .env.example
++++ .env
+-API_TOKEN=********
+-BAR=bar
++LOCAL=1
+";
+
+    test_dir.test_command_fail_with_args(
+        [
+            "diff",
+            "--reference",
+            ".env.example",
+            "--format",
+            "unified",
+            ".env",
+        ],
+        expected_output,
+    );
+}
+
+#[test]
+fn unified_without_reference() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "FOO=1\n");
+    test_dir.create_testfile(".env2", "BAR=2\n");
+    test_dir.create_testfile(".env3", "FOO=3\nBAR=3\nBAZ=3\n");
+    let expected_output = "\
+Comparing .env1
+Comparing .env2
+Comparing .env3
+--- .env2
++++ .env1
+-BAR=2
+--- .env3
++++ .env1
+-BAZ=3
+--- .env1
++++ .env2
+-FOO=1
+--- .env3
++++ .env2
+-BAZ=3
+";
+
+    test_dir.test_command_fail_with_args(["diff", "-f", "unified", "."], expected_output);
+}
+
+#[test]
+fn json() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nDB_PASSWORD=secret\n");
+    test_dir.create_testfile(".env", "FOO=1\nLOCAL=1\n");
+    let expected_output = r#"{
+  "files": [
+    {
+      "extra_keys": [
+        {
+          "key": "LOCAL",
+          "line": 2,
+          "text": "LOCAL=1"
+        }
+      ],
+      "missing_keys": [
+        {
+          "key": "DB_PASSWORD",
+          "line": 2,
+          "path": ".env.example",
+          "text": "DB_PASSWORD=********"
+        }
+      ],
+      "path": ".env",
+      "reference": ".env.example"
+    }
+  ]
+}
+"#;
+
+    test_dir.test_command_fail_with_args(
+        [
+            "diff",
+            "--reference",
+            ".env.example",
+            "--format",
+            "json",
+            ".env",
+        ],
+        expected_output,
+    );
+}
+
+#[test]
+fn json_without_difference() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "FOO=1\n");
+    test_dir.create_testfile(".env2", "FOO=2\n");
+    let expected_output = "{\n  \"files\": []\n}\n";
+
+    test_dir.test_command_success_with_args(["diff", "--format", "json", "."], expected_output);
+}
@@ -1,5 +1,10 @@
 use crate::common::TestDir;
 
+mod format;
+mod options;
+mod reference;
+mod values;
+
 #[test]
 fn files_with_same_environment_variables() {
     let test_dir = TestDir::new();
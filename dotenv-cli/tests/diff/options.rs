@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use crate::common::TestDir;
+
+#[test]
+fn exclude() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "FOO=1\n");
+    test_dir.create_testfile(".env2", "FOO=1\n");
+    test_dir.create_testfile(".env3", "BAR=1\n");
+    let expected_output = "Comparing .env1\nComparing .env2\nNo difference found\n";
+
+    test_dir.test_command_success_with_args(["diff", "--exclude", ".env3", "."], expected_output);
+}
+
+#[test]
+fn ignore_keys() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "FOO=1\nLOCAL_DB=1\n");
+    test_dir.create_testfile(".env2", "FOO=1\nLOCAL_CACHE=1\nBAR=1\n");
+    let expected_output = "Comparing .env1\nComparing .env2\n.env1 is missing keys: BAR\n";
+
+    test_dir
+        .test_command_fail_with_args(["diff", "--ignore-keys", "LOCAL_*", "."], expected_output);
+}
+
+#[test]
+fn export_prefix() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "export FOO=1\n");
+    test_dir.create_testfile(".env2", "FOO=1\n");
+    let expected_output = "Comparing .env1\nComparing .env2\nNo difference found\n";
+
+    test_dir.test_command_success_with_args(["diff", "."], expected_output);
+}
+
+#[test]
+fn recursive_per_directory() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env1", "FOO=1\n");
+    test_dir.create_testfile(".env2", "FOO=1\n");
+    let test_subdir = test_dir.subdir();
+    test_subdir.create_testfile(".env", "FOO=1\nBAR=1\n");
+    test_subdir.create_testfile(".env.production", "FOO=1\n");
+    let subdir = Path::new(test_dir.relative_path(&test_subdir).as_ref()).to_path_buf();
+    let first = subdir.join(".env");
+    let second = subdir.join(".env.production");
+    let expected_output = format!(
+        "Comparing .env1\nComparing .env2\nComparing {}\nComparing {}\n{} is missing keys: BAR\n",
+        first.display(),
+        second.display(),
+        second.display()
+    );
+
+    test_dir.test_command_fail_with_args(["diff", "--recursive", "."], expected_output);
+}
+
+#[test]
+fn recursive_single_files_per_directory() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=1\n");
+    let test_subdir = test_dir.subdir();
+    test_subdir.create_testfile(".env", "BAR=1\n");
+    let expected_output = "Nothing to compare\n";
+
+    test_dir.test_command_success_with_args(["diff", "-r", "."], expected_output);
+}
@@ -0,0 +1,65 @@
+use crate::common::TestDir;
+
+#[test]
+fn missing_and_extra_keys() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "# Required\nFOO=\nBAR=\nBAZ=\n");
+    test_dir.create_testfile(".env", "FOO=1\nBAR=2\nBAZ=3\nLOCAL=4\n");
+    test_dir.create_testfile(".env.production", "BAZ=3\nFOO=1\n");
+    let expected_output = "\
+Comparing .env
+Comparing .env.production
+.env has extra keys: LOCAL (line 4)
+.env.production is missing keys: BAR
+";
+
+    test_dir.test_command_fail_with_args(
+        ["diff", "--reference", ".env.example", "."],
+        expected_output,
+    );
+}
+
+#[test]
+fn allow_extra_keys() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\n");
+    test_dir.create_testfile(".env", "FOO=1\nLOCAL_DB=2\nLOCAL_CACHE=3\nDEBUG=4\n");
+    let expected_output = "Comparing .env\n.env has extra keys: DEBUG (line 4)\n";
+
+    test_dir.test_command_fail_with_args(
+        [
+            "diff",
+            "--reference",
+            ".env.example",
+            "--allow-extra-keys",
+            "LOCAL_*",
+            ".env",
+        ],
+        expected_output,
+    );
+}
+
+#[test]
+fn no_difference() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nBAR=\n");
+    test_dir.create_testfile(".env", "BAR=1\nFOO=2\n");
+    let expected_output = "Comparing .env\nNo difference found\n";
+
+    test_dir.test_command_success_with_args(
+        ["diff", "--reference", ".env.example", ".env"],
+        expected_output,
+    );
+}
+
+#[test]
+fn reference_not_found() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=1\n");
+    let expected_output = "Reference file not found: .env.missing\n";
+
+    test_dir.test_command_fail_with_args(
+        ["diff", "--reference", ".env.missing", ".env"],
+        expected_output,
+    );
+}
@@ -0,0 +1,61 @@
+use crate::common::TestDir;
+
+#[test]
+fn values_matrix() {
+    let test_dir = TestDir::new();
+    let staging = test_dir.create_testfile(
+        ".env.staging",
+        "DEBUG=true\nHOST=staging\nPORT=\"80\"\nAPI_TOKEN=abc\nEMPTY=\n",
+    );
+    let production = test_dir.create_testfile(
+        ".env.production",
+        "HOST=prod\nPORT=80\nAPI_TOKEN=xyz\nEMPTY=\n",
+    );
+    let expected_output = "\
+Comparing .env.production
+Comparing .env.staging
+Key        Status     .env.production  .env.staging
+API_TOKEN  different  ********         ********
+DEBUG      missing    <missing>        true
+EMPTY      equal      \"\"               \"\"
+HOST       different  prod             staging
+PORT       equal      80               80
+";
+
+    test_dir.test_command_fail_with_args(
+        ["diff", "--values", staging.as_str(), production.as_str()],
+        expected_output,
+    );
+}
+
+#[test]
+fn show_secrets_and_expand() {
+    let test_dir = TestDir::new();
+    let staging = test_dir.create_testfile(
+        ".env.staging",
+        "HOST=staging\nURL=http://$HOST\nDB_PASSWORD=one\n",
+    );
+    let production = test_dir.create_testfile(
+        ".env.production",
+        "HOST=staging\nURL=http://${HOST}\nDB_PASSWORD=one\n",
+    );
+    let expected_output = "\
+Key          Status  .env.production  .env.staging
+DB_PASSWORD  equal   one              one
+HOST         equal   staging          staging
+URL          equal   http://staging   http://staging
+";
+
+    test_dir.test_command_success_with_args(
+        [
+            "diff",
+            "--quiet",
+            "--values",
+            "--expand",
+            "--show-secrets",
+            staging.as_str(),
+            production.as_str(),
+        ],
+        expected_output,
+    );
+}
@@ -0,0 +1,30 @@
+use crate::common::*;
+
+#[test]
+fn byte_order_mark() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "\u{feff}A=B\r\nFOO=BAR\r\n");
+    let expected_output =
+        fix_output(&[(".env", &[".env:1 ByteOrderMark: Byte order mark detected"])]);
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "A=B\r\nFOO=BAR\r\n");
+
+    testdir.close();
+}
+
+#[test]
+fn byte_order_mark_is_kept_by_other_fixes() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "\u{feff}A=B \nFOO=BAR\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:1 TrailingWhitespace: Trailing whitespace detected"],
+    )]);
+    testdir
+        .test_command_fix_success_with_args(expected_output, ["--ignore-checks", "ByteOrderMark"]);
+
+    assert_eq!(testfile.contents().as_str(), "\u{feff}A=B\nFOO=BAR\n");
+
+    testdir.close();
+}
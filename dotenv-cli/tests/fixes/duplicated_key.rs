@@ -12,7 +12,7 @@ fn duplicated_key() {
         ],
     )]);
 
-    testdir.test_command_fix_success(expected_output);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
 
     assert_eq!(
         testfile.contents().as_str(),
@@ -21,3 +21,23 @@ fn duplicated_key() {
 
     testdir.close();
 }
+
+#[test]
+fn unsafe_fix_is_skipped() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "ABC=DEF \nABC=XYZ\n");
+    let expected_output = "Fixing .env
+.env:1 TrailingWhitespace: Trailing whitespace detected
+Unsafe fixes skipped:
+.env:2 DuplicatedKey: The ABC key is duplicated
+
+Fixed warnings: 1. Not fixed: 1
+Unsafe fixes skipped: 1 (use --unsafe-fixes to apply)
+";
+
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "ABC=DEF\nABC=XYZ\n");
+
+    testdir.close();
+}
@@ -13,7 +13,7 @@ fn leading_character() {
         ],
     )]);
 
-    testdir.test_command_fix_success(expected_output);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
 
     assert_eq!(
         testfile.contents().as_str(),
@@ -11,7 +11,7 @@ fn lowercase_key() {
             ".env:3 LowercaseKey: The fOO key should be in uppercase",
         ],
     )]);
-    testdir.test_command_fix_success(expected_output);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
 
     assert_eq!(testfile.contents().as_str(), "ABC=DEF\n\nFOO=BAR\n");
 
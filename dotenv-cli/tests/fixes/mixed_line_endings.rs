@@ -0,0 +1,73 @@
+use crate::common::*;
+
+#[test]
+fn mixed_line_endings() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=B\r\nC=D\nE=F\r\nG=H\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[
+            ".env:2 MixedLineEndings: The line ends with LF instead of CRLF",
+            ".env:4 MixedLineEndings: The line ends with LF instead of CRLF",
+        ],
+    )]);
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "A=B\r\nC=D\r\nE=F\r\nG=H\r\n");
+
+    testdir.close();
+}
+
+#[test]
+fn configured_line_ending() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=B\r\nC=D\nE=F\r\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:2 MixedLineEndings: The line ends with LF instead of CRLF"],
+    )]);
+    testdir.test_command_fix_success_with_args(expected_output, ["--line-ending", "lf"]);
+
+    assert_eq!(testfile.contents().as_str(), "A=B\nC=D\nE=F\n");
+
+    testdir.close();
+}
+
+#[test]
+fn line_endings_are_kept_by_other_fixes() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "a=B \r\n\r\n\r\nC=\"multi\r\nline\"\r\nD=E");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[
+            ".env:1 LowercaseKey: The a key should be in uppercase",
+            ".env:1 TrailingWhitespace: Trailing whitespace detected",
+            ".env:3 ExtraBlankLine: Extra blank line detected",
+            ".env:6 EndingBlankLine: No blank line at the end of the file",
+        ],
+    )]);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "A=B\r\n\r\nC=\"multi\r\nline\"\r\nD=E\r\n"
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn line_endings_in_multiline_values_are_kept() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=1\r\nB=\"2\n3\"\r\nc=4\r\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:4 LowercaseKey: The c key should be in uppercase"],
+    )]);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
+
+    // The LF inside the value is a part of it
+    assert_eq!(testfile.contents().as_str(), "A=1\r\nB=\"2\n3\"\r\nC=4\r\n");
+
+    testdir.close();
+}
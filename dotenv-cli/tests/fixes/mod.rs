@@ -2,6 +2,7 @@
 
 use crate::common::*;
 
+mod byte_order_mark;
 mod duplicated_key;
 mod ending_blank_line;
 mod extra_blank_line;
@@ -9,12 +10,18 @@
 mod key_without_value;
 mod leading_character;
 mod lowercase_key;
+mod mixed_line_endings;
+mod naming_convention;
 mod quote_character;
+mod schema_violation;
 mod space_character;
 mod substitution_key;
+mod suspicious_character;
 mod trailing_whitespace;
 mod unordered_key;
 mod value_without_quotes;
+#[cfg(unix)]
+mod write;
 
 #[test]
 fn correct_file() {
@@ -33,20 +40,27 @@ fn skip_checks() {
     let testdir = TestDir::new();
     let testfile = testdir.create_testfile(".env", "A1=1\nA2=2\na0=0\na2=2\n");
 
-    let expected_output = fix_output(&[(
-        ".env",
-        &[
-            ".env:3 LowercaseKey: The a0 key should be in uppercase",
-            ".env:4 LowercaseKey: The a2 key should be in uppercase",
-        ],
-    )]);
+    // The a2 key is not uppercased, A2 would be a duplicate
+    let expected_output = r#"Fixing .env
+.env:3 LowercaseKey: The a0 key should be in uppercase
+Could not fix:
+.env:2 CaseInsensitiveDuplicatedKey: The A2 key differs only in case from a2
+.env:4 LowercaseKey: The a2 key should be in uppercase
+.env:4 CaseInsensitiveDuplicatedKey: The a2 key differs only in case from A2
+
+Fixed warnings: 1. Not fixed: 3
+"#;
 
     testdir.test_command_fix_success_with_args(
         expected_output,
-        ["--ignore-checks", "DuplicatedKey,UnorderedKey"],
+        [
+            "--unsafe-fixes",
+            "--ignore-checks",
+            "DuplicatedKey,UnorderedKey",
+        ],
     );
 
-    assert_eq!(testfile.contents().as_str(), "A1=1\nA2=2\nA0=0\nA2=2\n");
+    assert_eq!(testfile.contents().as_str(), "A1=1\nA2=2\nA0=0\na2=2\n");
 
     testdir.close();
 }
@@ -77,7 +91,7 @@ fn multiple_files() {
             ],
         ),
     ]);
-    testdir.test_command_fix_success(expected_output);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
 
     assert_eq!(testfile1.contents().as_str(), "AB=DEF\nD=BAR\n\nF=BAR\n");
     assert_eq!(testfile2.contents().as_str(), "ABC=DEF\n\nB=bbb\nF=BAR\n");
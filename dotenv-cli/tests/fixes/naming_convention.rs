@@ -0,0 +1,55 @@
+use crate::common::*;
+
+#[test]
+fn naming_convention() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO__BAR=1\nBAZ_=2\nPATH=/bin\n");
+    let expected_output = r#"Fixing .env
+.env:1 NamingConvention: The FOO__BAR key should not contain double underscores
+.env:2 NamingConvention: The BAZ_ key should not end with an underscore
+Could not fix:
+.env:3 NamingConvention: The PATH key is a reserved name
+
+Fixed warnings: 2. Not fixed: 1
+"#;
+
+    testdir.test_command_fix_success_with_args(
+        expected_output,
+        [
+            "--unsafe-fixes",
+            "--key-style",
+            "strict",
+            "--ignore-checks",
+            "UnorderedKey",
+        ],
+    );
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "FOO_BAR=1\nBAZ=2\nPATH=/bin\n"
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn key_prefix() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "API_URL=1\nVITE_PORT=2\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:1 NamingConvention: The API_URL key should start with VITE_"],
+    )]);
+
+    testdir.test_command_fix_success_with_args(
+        expected_output,
+        ["--unsafe-fixes", "--key-prefix", "VITE_"],
+    );
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "VITE_API_URL=1\nVITE_PORT=2\n"
+    );
+
+    testdir.close();
+}
@@ -0,0 +1,71 @@
+use crate::common::*;
+
+const SCHEMA: &str = r#"{
+    "version": "1.0.0",
+    "entries": {
+        "DATABASE_URL": { "type": "Url" },
+        "DB_URL": { "type": "Url", "deprecated": true, "replaced_by": "DATABASE_URL" },
+        "PORT": { "type": "Integer" }
+    }
+}"#;
+
+#[test]
+fn rename_deprecated_key() {
+    let testdir = TestDir::new();
+    testdir.create_testfile("schema.json", SCHEMA);
+    let testfile = testdir.create_testfile(".env", "DB_URL=postgres://localhost\nPORT=80\n");
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:1 SchemaViolation: The DB_URL key is deprecated, use DATABASE_URL instead"],
+    )]);
+
+    testdir.test_command_fix_success_with_args(
+        expected_output,
+        ["--schema", "schema.json", "--unsafe-fixes"],
+    );
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "DATABASE_URL=postgres://localhost\nPORT=80\n"
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn unsafe_fix_is_skipped() {
+    let testdir = TestDir::new();
+    testdir.create_testfile("schema.json", SCHEMA);
+    let testfile = testdir.create_testfile(".env", "DB_URL=postgres://localhost\n");
+    let expected_output = "Fixing .env
+Unsafe fixes skipped:
+.env:1 SchemaViolation: The DB_URL key is deprecated, use DATABASE_URL instead
+
+Fixed warnings: 0. Not fixed: 1
+Unsafe fixes skipped: 1 (use --unsafe-fixes to apply)
+";
+
+    testdir.test_command_fix_success_with_args(expected_output, ["--schema", "schema.json"]);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "DB_URL=postgres://localhost\n"
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn without_schema() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "DB_URL=postgres://localhost\n");
+
+    testdir.test_command_fix_success_without_output();
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "DB_URL=postgres://localhost\n"
+    );
+
+    testdir.close();
+}
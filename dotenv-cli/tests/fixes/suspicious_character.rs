@@ -0,0 +1,42 @@
+use crate::common::*;
+
+#[test]
+fn suspicious_character() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A\u{200B}=1\nB=\u{202E}2\nC=\"3\u{00A0}4\"\n");
+    let expected_output = "Fixing .env
+.env:1 SuspiciousCharacter: Invisible character U+200B (ZERO WIDTH SPACE) detected
+.env:2 SuspiciousCharacter: Invisible character U+202E (RIGHT-TO-LEFT OVERRIDE) detected
+Could not fix:
+.env:3 SuspiciousCharacter: Invisible character U+00A0 (NO-BREAK SPACE) detected
+
+Fixed warnings: 2. Not fixed: 1
+";
+
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
+
+    assert_eq!(testfile.contents().as_str(), "A=1\nB=2\nC=\"3\u{00A0}4\"\n");
+
+    testdir.close();
+}
+
+#[test]
+fn keys_without_unsafe_fixes() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A\u{200B}=1\nB=\u{202E}2\n");
+    let expected_output = "Fixing .env
+.env:1 SuspiciousCharacter: Invisible character U+200B (ZERO WIDTH SPACE) detected
+Unsafe fixes skipped:
+.env:2 SuspiciousCharacter: Invisible character U+202E (RIGHT-TO-LEFT OVERRIDE) detected
+
+Fixed warnings: 1. Not fixed: 1
+Unsafe fixes skipped: 1 (use --unsafe-fixes to apply)
+";
+
+    // Only the character of the key is removed, the value is not changed
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "A=1\nB=\u{202E}2\n");
+
+    testdir.close();
+}
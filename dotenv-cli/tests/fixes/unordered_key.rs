@@ -16,7 +16,7 @@ fn unordered_key() {
             ".env:16 UnorderedKey: The I key should go before the K key",
         ],
     )]);
-    testdir.test_command_fix_success(expected_output);
+    testdir.test_command_fix_success_with_args(expected_output, ["--unsafe-fixes"]);
 
     assert_eq!(
         testfile.contents().as_str(),
@@ -0,0 +1,155 @@
+use std::{
+    fs,
+    os::unix::fs::{PermissionsExt, symlink},
+    path::Path,
+};
+
+use crate::common::*;
+
+fn mode(path: &str) -> u32 {
+    fs::metadata(path)
+        .expect("file metadata")
+        .permissions()
+        .mode()
+        & 0o777
+}
+
+#[test]
+fn keeps_permissions() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=1 \n");
+    fs::set_permissions(testfile.as_str(), fs::Permissions::from_mode(0o600))
+        .expect("set permissions");
+
+    testdir.test_command_fix_success_without_output();
+
+    assert_eq!(testfile.contents().as_str(), "A=1\n");
+    assert_eq!(mode(testfile.as_str()), 0o600);
+    // No temporary files are left
+    assert_eq!(fs::read_dir(testdir.as_str()).expect("read dir").count(), 1);
+
+    testdir.close();
+}
+
+#[test]
+fn follows_symlink() {
+    let testdir = TestDir::new();
+    let target = testdir.create_testfile("target", "A=1 \n");
+    let link = Path::new(testdir.as_str()).join(".env");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    testdir.test_command_fix_success_with_args(
+        fix_output(&[(
+            ".env",
+            &[".env:1 TrailingWhitespace: Trailing whitespace detected"],
+        )]),
+        ["--symlinks", "follow"],
+    );
+
+    assert!(link.is_symlink());
+    assert_eq!(target.contents().as_str(), "A=1\n");
+
+    testdir.close();
+}
+
+#[test]
+fn refuses_symlink() {
+    let testdir = TestDir::new();
+    let target = testdir.create_testfile("target", "A=1 \n");
+    let link = Path::new(testdir.as_str()).join(".env");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    let expected_output = "Fixing .env
+Skipped .env: it is a symbolic link (see --symlinks)
+Could not fix:
+.env:1 TrailingWhitespace: Trailing whitespace detected
+
+Fixed warnings: 0. Not fixed: 1
+Symbolic links skipped: 1 (see --symlinks)
+";
+    testdir.test_command_fix_success_with_args(expected_output, ["--symlinks", "refuse"]);
+
+    assert!(link.is_symlink());
+    assert_eq!(target.contents().as_str(), "A=1 \n");
+
+    testdir.close();
+}
+
+#[test]
+fn refuses_symlink_in_check() {
+    let testdir = TestDir::new();
+    let target = testdir.create_testfile("target", "A=1 \n");
+    let link = Path::new(testdir.as_str()).join(".env");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    let expected_output = "Checking .env
+Skipped .env: it is a symbolic link (see --symlinks)
+.env:1 TrailingWhitespace: Trailing whitespace detected
+
+Found 1 problem
+";
+    let output = testdir.test_command_fail_and_get_output(with_default_args(&[
+        "check",
+        ".env",
+        "--fix",
+        "--symlinks",
+        "refuse",
+    ]));
+
+    assert_eq!(output, expected_output);
+
+    assert!(link.is_symlink());
+    assert_eq!(target.contents().as_str(), "A=1 \n");
+
+    testdir.close();
+}
+
+#[test]
+fn refuses_symlink_in_sync() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env.example", "A=\nB=\n");
+    let target = testdir.create_testfile("target", "A=1\n");
+    let link = Path::new(testdir.as_str()).join(".env");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    testdir.test_command_with_code(
+        [
+            "sync",
+            "--from",
+            ".env.example",
+            "--symlinks",
+            "refuse",
+            ".env",
+        ],
+        1,
+        "Syncing .env\nAdded keys: B\nSkipped .env: it is a symbolic link (see --symlinks)\n",
+        "",
+    );
+
+    assert!(link.is_symlink());
+    assert_eq!(target.contents().as_str(), "A=1\n");
+
+    testdir.close();
+}
+
+#[test]
+fn replaces_symlink() {
+    let testdir = TestDir::new();
+    let target = testdir.create_testfile("target", "A=1 \n");
+    let link = Path::new(testdir.as_str()).join(".env");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    testdir.test_command_fix_success_with_args(
+        fix_output(&[(
+            ".env",
+            &[".env:1 TrailingWhitespace: Trailing whitespace detected"],
+        )]),
+        ["--symlinks", "replace"],
+    );
+
+    assert!(!link.is_symlink());
+    assert_eq!(fs::read_to_string(&link).expect("read file"), "A=1\n");
+    assert_eq!(target.contents().as_str(), "A=1 \n");
+
+    testdir.close();
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use std::{fs, path::Path};
 
 use crate::common::TestDir;
 
@@ -8,14 +8,13 @@ fn output_backup_file() {
     let testdir = TestDir::new();
     let content = "foo=bar\n";
     let testfile = testdir.create_testfile(".env", content);
-    let args = &["fix", testfile.as_str()];
+    let args = &["fix", "--unsafe-fixes", testfile.as_str()];
 
     testdir.test_command_success_with_args_without_closing(args);
 
-    let backup_file = fs::read_dir(testdir.as_str())
+    let backup_file = fs::read_dir(Path::new(testdir.as_str()).join(".dotenv-linter/backups"))
         .expect("read dir")
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().as_os_str() != testfile.as_str())
         .find(|e| e.path().is_file())
         .expect("get backup file");
 
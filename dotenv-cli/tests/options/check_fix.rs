@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::common::*;
+
+#[test]
+fn all_warnings_fixed() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1 \nB=2\n");
+
+    let output = test_dir.test_command_success_and_get_output(with_default_args(&[
+        "check",
+        ".",
+        "--fix",
+        "--no-backup",
+    ]));
+
+    assert_eq!(output, check_output(&[(".env", &[])]));
+    assert_eq!(testfile.contents().as_str(), "A=1\nB=2\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn remaining_warnings() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let output = test_dir.test_command_fail_and_get_output(with_default_args(&[
+        "check",
+        ".",
+        "--fix",
+        "--no-backup",
+    ]));
+
+    assert_eq!(
+        output,
+        check_output(&[(
+            ".env",
+            &[".env:2 UnorderedKey: The A key should go before the B key"],
+        )])
+    );
+    assert_eq!(testfile.contents().as_str(), "B=1\nA=2\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn unsafe_fixes() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let output = test_dir.test_command_success_and_get_output(with_default_args(&[
+        "check",
+        ".",
+        "--fix",
+        "--unsafe-fixes",
+        "--no-backup",
+    ]));
+
+    assert_eq!(output, check_output(&[(".env", &[])]));
+    assert_eq!(testfile.contents().as_str(), "A=2\nB=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn backup() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1 \n");
+
+    let output =
+        test_dir.test_command_success_and_get_output(with_default_args(&["check", ".", "--fix"]));
+
+    assert!(output.contains("Original file was backed up to: "));
+    assert!(output.ends_with("\nNo problems found\n"));
+
+    test_dir.close();
+}
+
+#[test]
+fn backup_options_without_fix() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+
+    let expected_errors = "\
+error: the following required arguments were not provided:
+  --fix
+
+Usage: dotenv-linter check --fix --keep-backups <COUNT> --skip-updates <FILES>...
+
+For more information, try '--help'.
+";
+    test_dir.test_command_with_code(
+        with_default_args(&["check", ".", "--keep-backups", "2"]),
+        2,
+        "",
+        expected_errors,
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn backup_dir_env_without_fix() {
+    let envs = HashMap::from([(
+        String::from("DOTENV_LINTER_BACKUP_DIR"),
+        String::from("backups"),
+    )]);
+    let test_dir = TestDir::with_envs(envs);
+    test_dir.create_testfile(".env", "A=1\n");
+
+    let output = test_dir.test_command_success_and_get_output(with_default_args(&["check", "."]));
+
+    assert_eq!(output, check_output(&[(".env", &[])]));
+
+    test_dir.close();
+}
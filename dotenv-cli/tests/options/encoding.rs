@@ -0,0 +1,162 @@
+use crate::common::*;
+
+#[test]
+fn not_utf8_file() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "A=1\n");
+    testdir.create_testfile_with_bytes(".env.latin1", b"A=caf\xE9\n");
+
+    testdir.test_command_with_code(
+        with_default_args(&["check", "."]),
+        3,
+        "Checking .env\n\nNo problems found\n",
+        "Error reading .env.latin1: invalid UTF-8 at byte 5 (use --encoding to read it)\n",
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn binary_file() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "a=1\n");
+    testdir.create_testfile_with_bytes(".env.gpg", b"\x85\x02\x0c\x00\x01");
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:1 LowercaseKey: The a key should be in uppercase"],
+    )]);
+    testdir.test_command_with_code(
+        with_default_args(&["check", "."]),
+        1,
+        &expected_output,
+        "Skipping .env.gpg: binary file\n",
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn encoding() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile_with_bytes(".env", b"a=\x93caf\xE9\x94\n");
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:1 LowercaseKey: The a key should be in uppercase"],
+    )]);
+    testdir.test_command_with_code(
+        with_default_args(&["check", ".", "--encoding", "windows1252"]),
+        1,
+        &expected_output,
+        "",
+    );
+
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:1 LowercaseKey: The a key should be in uppercase"],
+    )]);
+    testdir.test_command_with_code(
+        [
+            "fix",
+            "--no-backup",
+            "--unsafe-fixes",
+            ".",
+            "--encoding",
+            "windows1252",
+        ],
+        0,
+        &expected_output,
+        "",
+    );
+
+    // The fixed file is written in UTF-8
+    assert_eq!("A=“café”\n".as_bytes(), testfile.bytes());
+
+    testdir.close();
+}
+
+#[test]
+fn utf16_file() {
+    let testdir = TestDir::new();
+    let utf16 = |content: &str| -> Vec<u8> {
+        [0xFF, 0xFE]
+            .into_iter()
+            .chain(content.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect()
+    };
+    let testfile = testdir.create_testfile_with_bytes(".env", &utf16("B=1\nc=2\n"));
+
+    // The byte order mark is a part of the encoding, it isn't reported
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:2 LowercaseKey: The c key should be in uppercase"],
+    )]);
+    testdir.test_command_with_code(with_default_args(&["check", "."]), 1, &expected_output, "");
+
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:2 LowercaseKey: The c key should be in uppercase"],
+    )]);
+    testdir.test_command_with_code(
+        ["fix", "--no-backup", "--unsafe-fixes", "."],
+        0,
+        &expected_output,
+        "",
+    );
+
+    // The fixed file is written back in UTF-16
+    assert_eq!(utf16("B=1\nC=2\n"), testfile.bytes());
+
+    testdir.close();
+}
+
+#[test]
+fn not_utf8_file_in_diff() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "A=1\n");
+    testdir.create_testfile(".env.local", "A=2\n");
+    testdir.create_testfile_with_bytes(".env.latin1", b"A=caf\xE9\n");
+
+    testdir.test_command_with_code(
+        ["diff", "."],
+        3,
+        "Comparing .env\nComparing .env.local\nNo difference found\n",
+        "Error reading .env.latin1: invalid UTF-8 at byte 5 (use --encoding to read it)\n",
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn not_utf8_file_in_sync() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env.example", "A=\n");
+    let testfile = testdir.create_testfile_with_bytes(".env", b"B=caf\xE9\n");
+
+    testdir.test_command_with_code(
+        ["sync", "--from", ".env.example", "--no-backup", "."],
+        3,
+        "Nothing to sync\n",
+        "Error reading .env: invalid UTF-8 at byte 5 (use --encoding to read it)\n",
+    );
+    assert_eq!(b"B=caf\xE9\n".to_vec(), testfile.bytes());
+
+    testdir.test_command_with_code(
+        [
+            "sync",
+            "--from",
+            ".env.example",
+            "--no-backup",
+            "--encoding",
+            "latin1",
+            ".",
+        ],
+        0,
+        "Syncing .env\nAdded keys: A\n",
+        "",
+    );
+    assert_eq!("B=café\n\nA=\n", testfile.contents());
+
+    testdir.close();
+}
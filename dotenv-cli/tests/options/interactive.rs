@@ -0,0 +1,95 @@
+use std::{fs, path::Path};
+
+use crate::common::*;
+
+const PROMPT: &str = "Apply this fix? [y]es, [n]o, [a]ll TrailingWhitespace fixes, [q]uit: \n";
+
+#[test]
+fn apply_and_decline() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1 \nB=2 \n");
+    let expected_output = format!(
+        "Fixing .env
+.env:1 TrailingWhitespace: Trailing whitespace detected
+- A=1 
++ A=1
+{PROMPT}.env:2 TrailingWhitespace: Trailing whitespace detected
+- B=2 
++ B=2
+{PROMPT}.env:1 TrailingWhitespace: Trailing whitespace detected
+Fixes declined:
+.env:2 TrailingWhitespace: Trailing whitespace detected
+
+Fixed warnings: 1. Not fixed: 1
+Fixes declined: 1
+"
+    );
+
+    let output = test_dir.test_command_with_input_and_get_output(
+        ["fix", "--interactive", "--no-backup", "."],
+        "y\nn\n",
+    );
+
+    assert_eq!(output, expected_output);
+    assert_eq!(testfile.contents(), "A=1\nB=2 \n");
+
+    test_dir.close();
+}
+
+#[test]
+fn apply_all_fixes_of_check() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1 \nB=2 \nC=3 \n");
+
+    let output = test_dir.test_command_with_input_and_get_output(
+        ["fix", "--interactive", "--no-backup", "."],
+        "a\n",
+    );
+
+    assert_eq!(output.matches(PROMPT).count(), 1);
+    assert!(output.ends_with("All warnings are fixed. Total: 3\n"));
+    assert_eq!(testfile.contents(), "A=1\nB=2\nC=3\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn quit() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1 \nB=2 \n");
+
+    for input in ["invalid\nq\n", ""] {
+        let output =
+            test_dir.test_command_with_input_and_get_output(["fix", "--interactive", "."], input);
+
+        assert!(output.ends_with("Fixed warnings: 0. Not fixed: 2\nFixes declined: 2\n"));
+        assert_eq!(testfile.contents(), "A=1 \nB=2 \n");
+        assert!(!Path::new(test_dir.as_str()).join(".dotenv-linter").exists());
+    }
+
+    test_dir.close();
+}
+
+#[test]
+fn backup_of_approved_fixes() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1 \nB=2 \n");
+
+    let output =
+        test_dir.test_command_with_input_and_get_output(["fix", "--interactive", "."], "n\ny\n");
+
+    assert!(output.contains("Original file was backed up to: "));
+    assert_eq!(testfile.contents(), "A=1 \nB=2\n");
+
+    let backup = fs::read_dir(Path::new(test_dir.as_str()).join(".dotenv-linter/backups"))
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .next()
+        .expect("get backup file");
+    assert_eq!(
+        fs::read_to_string(backup.path()).expect("read backup"),
+        "A=1 \nB=2 \n"
+    );
+
+    test_dir.close();
+}
@@ -0,0 +1,101 @@
+use crate::common::*;
+
+#[test]
+fn layers() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\nB=2\n");
+    test_dir.create_testfile(".env.local", "C=3\n");
+    test_dir.create_testfile(".env.production", "A=1\nB=3\n");
+
+    let expected_output = check_output(&[
+        (".env", &[]),
+        (
+            ".env.local",
+            &[".env.local:1 LocalOnlyKey: The C key is defined only in local files"],
+        ),
+        (
+            ".env.production",
+            &[
+                ".env.production:1 RedundantOverride: The A key has the same value in .env, which it overrides",
+            ],
+        ),
+    ]);
+
+    test_dir.test_command_fail_with_args(
+        with_default_args(&["check", ".", "--layers"]),
+        expected_output,
+    );
+}
+
+#[test]
+fn without_layers() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+    test_dir.create_testfile(".env.production", "A=1\n");
+
+    let expected_output = check_output(&[(".env", &[]), (".env.production", &[])]);
+
+    test_dir.test_command_success_with_args(with_default_args(&["check", "."]), expected_output);
+}
+
+#[test]
+fn templates_are_not_layers() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+    test_dir.create_testfile(".env.example", "A=1\n");
+
+    let expected_output = check_output(&[(".env", &[]), (".env.example", &[])]);
+
+    test_dir.test_command_success_with_args(
+        with_default_args(&["check", ".", "--layers"]),
+        expected_output,
+    );
+}
+
+#[test]
+fn layer_order() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.local", "A=1\n");
+    test_dir.create_testfile(".env.production", "A=1\nB=2\n");
+
+    // .env.local overrides .env.production
+    let expected_output = check_output(&[
+        (
+            ".env.local",
+            &[
+                ".env.local:1 RedundantOverride: The A key has the same value in .env.production, which it overrides",
+            ],
+        ),
+        (".env.production", &[]),
+    ]);
+
+    test_dir.test_command_fail_with_args(
+        with_default_args(&[
+            "check",
+            ".",
+            "--layers",
+            "--layer-order",
+            ".env,.env.{env},.env.local",
+            "--ignore-checks",
+            "LocalOnlyKey",
+        ]),
+        expected_output,
+    );
+}
+
+#[test]
+fn layers_in_directories() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+    let subdir = test_dir.subdir();
+    subdir.create_testfile(".env.production", "A=1\n");
+
+    let relative = test_dir.relative_path(&subdir).to_string();
+    let path = format!("{relative}/.env.production");
+    let expected_output = check_output(&[(".env", &[]), (path.as_str(), &[])]);
+
+    test_dir.test_command_success_with_args(
+        with_default_args(&["check", ".", "--recursive", "--layers"]),
+        expected_output,
+    );
+}
@@ -1 +1,6 @@
+mod check_fix;
+mod encoding;
 mod exclude;
+mod interactive;
+mod layers;
+mod only;
@@ -0,0 +1,77 @@
+use crate::common::*;
+
+#[test]
+fn check_only() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[".env:2 UnorderedKey: The A key should go before the B key"],
+    )]);
+
+    test_dir.test_command_fail_with_args(
+        with_default_args(&["check", ".", "--only", "UnorderedKey"]),
+        expected_output,
+    );
+}
+
+#[test]
+fn fix_only() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let expected_output = "Fixing .env
+.env:1 TrailingWhitespace: Trailing whitespace detected
+Could not fix:
+.env:2 UnorderedKey: The A key should go before the B key
+
+Fixed warnings: 1. Not fixed: 1
+";
+
+    test_dir.test_command_fix_success_with_args(
+        expected_output,
+        ["--unsafe-fixes", "--fix-only", "TrailingWhitespace"],
+    );
+    assert_eq!(testfile.contents().as_str(), "B=1\nA=2\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn no_fix() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let expected_output = "Fixing .env
+.env:2 UnorderedKey: The A key should go before the B key
+Could not fix:
+.env:2 TrailingWhitespace: Trailing whitespace detected
+
+Fixed warnings: 1. Not fixed: 1
+";
+
+    test_dir.test_command_fix_success_with_args(
+        expected_output,
+        ["--unsafe-fixes", "--no-fix", "TrailingWhitespace"],
+    );
+    assert_eq!(testfile.contents().as_str(), "A=2\nB=1 \n");
+
+    test_dir.close();
+}
+
+#[test]
+fn fix_with_only() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "B=1 \nA=2\n");
+
+    let expected_output = fix_output(&[(
+        ".env",
+        &[".env:1 TrailingWhitespace: Trailing whitespace detected"],
+    )]);
+
+    test_dir.test_command_fix_success_with_args(expected_output, ["--only", "TrailingWhitespace"]);
+    assert_eq!(testfile.contents().as_str(), "B=1\nA=2\n");
+
+    test_dir.close();
+}
@@ -1,6 +1,6 @@
 //! Tests that output from fixes are correct. Mainly needed to ensure that
 //! newlines are printed correctly.
-use std::fs;
+use std::{fs, path::Path};
 
 use crate::common::*;
 
@@ -9,7 +9,7 @@ fn warnings() {
     let test_dir = TestDir::new();
     test_dir.create_testfile(".env", "abc=DEF\n");
 
-    let args: &[&str] = &[];
+    let args: &[&str] = &["--unsafe-fixes"];
     let expected_output = r#"Fixing .env
 .env:1 LowercaseKey: The abc key should be in uppercase
 
@@ -26,23 +26,27 @@ fn warnings_dry_run() {
     let test_str = "abc=DEF\nABC=DEF\nA=DEF\nABC=DEF\n";
     test_dir.create_testfile(".env", test_str);
 
-    let args: &[&str] = &["--dry-run"];
+    let args: &[&str] = &["--unsafe-fixes", "--dry-run"];
     let expected_output = r#"Fixing .env
 Dry run - not changing any files on disk.
 
 A=DEF
 ABC=DEF
 # ABC=DEF
-# ABC=DEF
+abc=DEF
 
 
-.env:1 LowercaseKey: The abc key should be in uppercase
 .env:2 UnorderedKey: The ABC key should go before the abc key
 .env:3 UnorderedKey: The A key should go before the ABC key
 .env:4 DuplicatedKey: The ABC key is duplicated
 .env:4 UnorderedKey: The ABC key should go before the ABC key
+.env:4 CaseInsensitiveDuplicatedKey: The ABC key differs only in case from abc
+Could not fix:
+.env:2 CaseInsensitiveDuplicatedKey: The ABC key differs only in case from abc
+.env:4 LowercaseKey: The abc key should be in uppercase
+.env:4 CaseInsensitiveDuplicatedKey: The abc key differs only in case from ABC
 
-All warnings are fixed. Total: 5
+Fixed warnings: 5. Not fixed: 3
 "#;
 
     test_dir.test_command_fix_success_with_args(expected_output, args);
@@ -61,7 +65,7 @@ fn warnings_multiple_files() {
     test_dir.create_testfile(".env_1", "ABC=DEF\n\n");
     test_dir.create_testfile(".env_2", "ABC=DEF\nABC=DEF\n");
 
-    let args: &[&str] = &[];
+    let args: &[&str] = &["--unsafe-fixes"];
     let expected_output = r#"Fixing .env
 .env:1 LowercaseKey: The abc key should be in uppercase
 
@@ -83,7 +87,7 @@ fn no_warnings() {
     let test_dir = TestDir::new();
     test_dir.create_testfile(".env", "ABC=DEF\nB=bbb\nF=BAR\n");
 
-    let args: &[&str] = &[];
+    let args: &[&str] = &["--unsafe-fixes"];
     let expected_output = r#"Fixing .env
 
 No warnings found
@@ -100,7 +104,7 @@ fn no_warnings_multiple_files() {
     test_dir.create_testfile(".env_1", "ABC=DEF\nB=bbb\nF=BAR\n");
     test_dir.create_testfile(".env_2", "ABC=DEF\nB=bbb\nF=BAR\n");
 
-    let args: &[&str] = &[];
+    let args: &[&str] = &["--unsafe-fixes"];
     let expected_output = r#"Fixing .env
 Fixing .env_1
 Fixing .env_2
@@ -119,7 +123,7 @@ fn mixed_warnings_multiple_files() {
     test_dir.create_testfile(".env_1", "ABC=DEF\n");
     test_dir.create_testfile(".env_2", "ABC=DEF\nABC=DEF\n");
 
-    let args: &[&str] = &[];
+    let args: &[&str] = &["--unsafe-fixes"];
     let expected_output = r#"Fixing .env
 .env:1 LowercaseKey: The abc key should be in uppercase
 
@@ -165,7 +169,7 @@ fn quiet() {
     let test_dir = TestDir::new();
     test_dir.create_testfile(".env", "abc=DEF\n\nF=BAR\nB=bbb\n");
 
-    let args = &["--quiet"];
+    let args = &["--unsafe-fixes", "--quiet"];
     let expected_output = r#"
 All warnings are fixed. Total: 2
 "#;
@@ -179,7 +183,7 @@ fn quiet_no_warnings() {
     let test_dir = TestDir::new();
     test_dir.create_testfile(".env", "ABC=DEF\nB=bbb\nF=BAR\n");
 
-    let args = &["--quiet"];
+    let args = &["--unsafe-fixes", "--quiet"];
     let expected_output = r#"
 No warnings found
 "#;
@@ -191,22 +195,21 @@ fn quiet_no_warnings() {
 #[test]
 fn backup() {
     let test_dir = TestDir::new();
-    let test_file = test_dir.create_testfile(".env", "abc=DEF\n\nF=BAR\nB=bbb\n");
+    test_dir.create_testfile(".env", "abc=DEF\n\nF=BAR\nB=bbb\n");
 
-    let args = &["fix", "."];
+    let args = &["fix", "--unsafe-fixes", "."];
     let output = test_dir.test_command_success_and_get_output(args);
 
-    let backup_file = fs::read_dir(test_dir.as_str())
+    let backup_file = fs::read_dir(Path::new(test_dir.as_str()).join(".dotenv-linter/backups"))
         .expect("read dir")
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().as_os_str() != test_file.as_str())
         .find(|e| e.path().is_file())
         .expect("get backup file");
     let backup_filename = backup_file.file_name();
     let backup_filename = backup_filename.to_str().expect("convert to string");
     let expected_output = format!(
         r#"Fixing .env
-Original file was backed up to: "{backup_filename}"
+Original file was backed up to: ".dotenv-linter/backups/{backup_filename}"
 
 .env:1 LowercaseKey: The abc key should be in uppercase
 .env:4 UnorderedKey: The B key should go before the F key
@@ -222,21 +225,20 @@ fn backup() {
 #[test]
 fn quiet_backup() {
     let test_dir = TestDir::new();
-    let test_file = test_dir.create_testfile(".env", "abc=DEF\n\nF=BAR\nB=bbb\n");
+    test_dir.create_testfile(".env", "abc=DEF\n\nF=BAR\nB=bbb\n");
 
-    let args = &["fix", "-q", "."];
+    let args = &["fix", "--unsafe-fixes", "-q", "."];
     let output = test_dir.test_command_success_and_get_output(args);
 
-    let backup_file = fs::read_dir(test_dir.as_str())
+    let backup_file = fs::read_dir(Path::new(test_dir.as_str()).join(".dotenv-linter/backups"))
         .expect("read dir")
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().as_os_str() != test_file.as_str())
         .find(|e| e.path().is_file())
         .expect("get backup file");
     let backup_filename = backup_file.file_name();
     let backup_filename = backup_filename.to_str().expect("convert to string");
     let expected_output = format!(
-        r#"Original file was backed up to: "{backup_filename}"
+        r#"Original file was backed up to: ".dotenv-linter/backups/{backup_filename}"
 
 All warnings are fixed. Total: 2
 "#
@@ -261,9 +263,95 @@ fn no_files() {
 fn quiet_no_files() {
     let test_dir = TestDir::new();
 
-    let args = &["--quiet"];
+    let args = &["--unsafe-fixes", "--quiet"];
     let expected_output = "";
 
     test_dir.test_command_fix_success_with_args(expected_output, args);
     test_dir.close()
 }
+
+#[test]
+fn warnings_not_fixed() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        "schema.json",
+        r#"{ "version": "1.0.0", "entries": { "PORT": { "type": "Integer" } } }"#,
+    );
+    let testfile = test_dir.create_testfile(".env", "HOST=localhost\nPORT=80 \n");
+
+    let expected_output = r#"Fixing .env
+.env:2 TrailingWhitespace: Trailing whitespace detected
+.env:2 SchemaViolation: The PORT key is not an integer
+Could not fix:
+.env:1 SchemaViolation: The HOST key is not defined in the schema
+
+Fixed warnings: 2. Not fixed: 1
+"#;
+
+    test_dir.test_command_fix_success_with_args(expected_output, ["--schema", "schema.json"]);
+    assert_eq!(testfile.contents().as_str(), "HOST=localhost\nPORT=80\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn diff() {
+    let test_dir = TestDir::new();
+    let test_str = "A=1\n\n\nb=2 \nC=3";
+    let testfile = test_dir.create_testfile(".env", test_str);
+    test_dir.create_testfile(".env.example", "A=1\n");
+
+    let expected_output = r#"diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1,5 +1,4 @@
+ A=1
+ 
+-
+-b=2 
+-C=3
+\ No newline at end of file
++b=2
++C=3
+"#;
+
+    let output = test_dir.test_command_fail_and_get_output(["fix", "--diff", "."]);
+    assert_eq!(output, expected_output);
+    assert_eq!(testfile.contents().as_str(), test_str);
+
+    test_dir.close();
+}
+
+#[test]
+fn diff_outside_current_dir() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+    let subdir = test_dir.subdir();
+    subdir.create_testfile(".env", "b=2\n");
+
+    let expected_output = r#"diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1 +1 @@
+-b=2
++B=2
+"#;
+    subdir.test_command_with_code(
+        ["fix", "--diff", "--unsafe-fixes", ".", ".."],
+        1,
+        expected_output,
+        "Skipped ../.env: the diff of a file outside the current directory can't be applied\n",
+    );
+    assert_eq!(testfile.contents().as_str(), "a=1\n");
+
+    subdir.close();
+    test_dir.close();
+}
+
+#[test]
+fn diff_without_changes() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+
+    test_dir.test_command_success_with_args(["fix", "--diff", "."], "");
+}
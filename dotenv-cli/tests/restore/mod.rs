@@ -0,0 +1,237 @@
+use std::{fs, os::unix::fs::symlink, path::Path};
+
+use crate::common::TestDir;
+
+const BACKUP_DIR: &str = ".dotenv-linter/backups";
+
+/// Returns the paths of the backups (relative to the test directory), the latest one first
+fn backups(test_dir: &TestDir, dir: &str) -> Vec<String> {
+    let mut backups: Vec<String> = fs::read_dir(Path::new(test_dir.as_str()).join(dir))
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            format!(
+                "{dir}/{}",
+                e.file_name().to_str().expect("convert to string")
+            )
+        })
+        .collect();
+    backups.sort();
+    backups.reverse();
+    backups
+}
+
+#[test]
+fn restore_latest_backup() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "."]);
+    assert_eq!(testfile.contents(), "A=1\n");
+
+    let backup = backups(&test_dir, BACKUP_DIR).remove(0);
+    let output = test_dir.test_command_success_and_get_output(["restore", ".env"]);
+
+    // The current file is backed up before it is restored
+    let current_backup = backups(&test_dir, BACKUP_DIR).remove(0);
+    assert_eq!(
+        output,
+        format!("Current file was backed up to: {current_backup}\nRestored .env from {backup}\n")
+    );
+    assert_eq!(testfile.contents(), "a=1\n");
+    assert_eq!(
+        fs::read_to_string(Path::new(test_dir.as_str()).join(current_backup)).expect("read"),
+        "A=1\n"
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn file_outside_current_dir() {
+    let test_dir = TestDir::new();
+    let parent_file = test_dir.create_testfile(".env", "a=1\n");
+    let subdir = test_dir.subdir();
+    let testfile = subdir.create_testfile(".env", "b=1\n");
+
+    subdir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", ".."]);
+    assert_eq!(parent_file.contents(), "A=1\n");
+
+    // The backup of `../.env` is not a backup of `.env`
+    let output = subdir.test_command_fail_and_get_output(["restore", ".env"]);
+    assert_eq!(output, "No backups of .env found\n");
+    assert_eq!(testfile.contents(), "b=1\n");
+
+    subdir.test_command_success_with_args_without_closing(["restore", "../.env"]);
+    assert_eq!(parent_file.contents(), "a=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn backup_of_other_file() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+    test_dir.create_testfile(".env.local", "b=1\n");
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "."]);
+
+    let backup = backups(&test_dir, BACKUP_DIR)
+        .into_iter()
+        .find(|backup| backup.contains(".env.local_"))
+        .expect("backup of .env.local");
+
+    let output =
+        test_dir.test_command_fail_and_get_output(["restore", ".env", "--backup", &backup]);
+    assert_eq!(output, format!("{backup} is not a backup of .env\n"));
+    assert_eq!(testfile.contents(), "A=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn list_and_restore_chosen_backup() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "."]);
+    fs::write(testfile.as_str(), "b=2\n").expect("write file");
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "."]);
+
+    let backups = backups(&test_dir, BACKUP_DIR);
+    let expected_output = format!(
+        "Backups of .env (latest first):\n  {}\n  {}\n",
+        backups[0], backups[1]
+    );
+
+    let output = test_dir.test_command_success_and_get_output(["restore", "--list"]);
+    assert_eq!(output, expected_output);
+    let output = test_dir.test_command_success_and_get_output(["restore", "--list", ".env"]);
+    assert_eq!(output, expected_output);
+
+    test_dir.test_command_success_with_args_without_closing([
+        "restore",
+        ".env",
+        "--backup",
+        &backups[1],
+    ]);
+    assert_eq!(testfile.contents(), "a=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn nested_files() {
+    let test_dir = TestDir::new();
+    let subdir = test_dir.subdir();
+    let testfile = subdir.create_testfile(".env", "a=1\n");
+    let path = format!("{}/.env", test_dir.relative_path(&subdir));
+
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "-r", "."]);
+
+    let output = test_dir.test_command_success_and_get_output(["restore", "--list"]);
+    assert!(output.starts_with(&format!(
+        "Backups of {path} (latest first):\n  {BACKUP_DIR}/{path}_"
+    )));
+
+    test_dir.test_command_success_with_args_without_closing(["restore", &path]);
+    assert_eq!(testfile.contents(), "a=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn no_backups() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\n");
+
+    let output = test_dir.test_command_fail_and_get_output(["restore", ".env"]);
+    assert_eq!(output, "No backups of .env found\n");
+
+    let output = test_dir.test_command_fail_and_get_output(["restore", "--list"]);
+    assert_eq!(output, "No backups found\n");
+
+    let output =
+        test_dir.test_command_fail_and_get_output(["restore", ".env", "--backup", "missing.bak"]);
+    assert_eq!(output, "Backup not found: missing.bak\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn keep_backups() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+
+    for value in ["a=2\n", "a=3\n", "a=4\n"] {
+        test_dir.test_command_success_with_args_without_closing([
+            "fix",
+            "--unsafe-fixes",
+            "--keep-backups",
+            "2",
+            ".",
+        ]);
+        fs::write(testfile.as_str(), value).expect("write file");
+    }
+
+    let backups = backups(&test_dir, BACKUP_DIR);
+    assert_eq!(backups.len(), 2);
+    let contents: Vec<String> = backups
+        .iter()
+        .map(|backup| fs::read_to_string(Path::new(test_dir.as_str()).join(backup)).expect("read"))
+        .collect();
+    assert_eq!(contents, ["a=3\n", "a=2\n"]);
+
+    test_dir.close();
+}
+
+#[test]
+fn backup_dir() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "a=1\n");
+
+    test_dir.test_command_success_with_args_without_closing([
+        "fix",
+        "--unsafe-fixes",
+        "--backup-dir",
+        "backups",
+        ".",
+    ]);
+    assert_eq!(backups(&test_dir, "backups").len(), 1);
+
+    test_dir.test_command_success_with_args_without_closing([
+        "restore",
+        "--backup-dir",
+        "backups",
+        ".env",
+    ]);
+    assert_eq!(testfile.contents(), "a=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn restore_symlink() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "a=1\n");
+    test_dir.test_command_success_with_args_without_closing(["fix", "--unsafe-fixes", "."]);
+
+    let target = test_dir.create_testfile("target", "A=2\n");
+    let link = Path::new(test_dir.as_str()).join(".env");
+    fs::remove_file(&link).expect("remove file");
+    symlink(target.as_str(), &link).expect("create symlink");
+
+    let output =
+        test_dir.test_command_fail_and_get_output(["restore", ".env", "--symlinks", "refuse"]);
+    assert_eq!(
+        output,
+        "Skipped .env: it is a symbolic link (see --symlinks)\n"
+    );
+    assert!(link.is_symlink());
+    assert_eq!(target.contents(), "A=2\n");
+
+    // The file the link points to is restored by default
+    test_dir.test_command_success_with_args_without_closing(["restore", ".env"]);
+    assert!(link.is_symlink());
+    assert_eq!(target.contents(), "a=1\n");
+
+    test_dir.close();
+}
@@ -0,0 +1,100 @@
+use crate::common::*;
+
+#[test]
+fn valid_schema() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "schema.json",
+        r#"{ "version": "1.0.0", "entries": { "NAME": { "key": "NAME" } } }"#,
+    );
+
+    let expected_output = "Checking schema.json\n\nNo problems found\n";
+
+    testdir.test_command_success_with_args(["schema", "check", "schema.json"], expected_output);
+}
+
+#[test]
+fn key_mismatch_and_version() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "schema.json",
+        r#"{
+    "version": "2.0.0",
+    "entries": {
+        "PORT": { "key": "PROT", "type": "Integer" }
+    }
+}"#,
+    );
+
+    let expected_output = "\
+Checking schema.json
+schema.json:2:5 unsupported schema version \"2.0.0\", expected 1.x.x
+schema.json:4:19 the key \"PROT\" does not match the entry name \"PORT\"
+
+Found 2 problems
+";
+
+    testdir.test_command_fail_with_args(["schema", "check", "schema.json"], expected_output);
+}
+
+#[test]
+fn unknown_field() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "schema.json",
+        r#"{
+    "version": "1.0.0",
+    "entries": {
+        "DEBUG": { "type": "Boolean", "requried": true }
+    }
+}"#,
+    );
+
+    let expected_output = "\
+Checking schema.json
+schema.json:4:48 unknown field `requried`, expected one of `key`, `required`, `type`, `regex`, `description`, `default`, `example`, `group`, `key_regex`, `deprecated`, `replaced_by`
+
+Found 1 problem
+";
+
+    testdir.test_command_fail_with_args(["schema", "check", "schema.json"], expected_output);
+}
+
+#[test]
+fn problems_in_extended_schema() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "base.json",
+        r#"{ "version": "1.0.0", "entries": { "A": { "key": "B" } } }"#,
+    );
+    testdir.create_testfile(
+        "schema.json",
+        r#"{ "version": "1.0.0", "extends": ["base.json"] }"#,
+    );
+
+    let expected_output = "\
+Checking schema.json
+base.json:1:43 the key \"B\" does not match the entry name \"A\"
+
+Found 1 problem
+";
+
+    testdir.test_command_fail_with_args(["schema", "check", "schema.json"], expected_output);
+}
+
+#[test]
+fn check_reports_invalid_schema() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        "schema.json",
+        r#"{ "version": "1.0.0", "entries": { "PORT": { "type": "Interger" } } }"#,
+    );
+    testdir.create_testfile(".env", "PORT=1\n");
+
+    let expected_output = "Error loading schema: unknown variant `Interger`, expected one of `String`, `Integer`, `Float`, `Boolean`, `Url`, `Email` at line 1 column 63\n";
+
+    testdir.test_command_fail_with_args(
+        with_default_args(&["check", "--schema", "schema.json", ".env"]),
+        expected_output,
+    );
+}
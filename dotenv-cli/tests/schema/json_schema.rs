@@ -0,0 +1,93 @@
+use crate::common::*;
+
+const SCHEMA: &str = r#"{
+    "version": "1.0.0",
+    "allow_other_keys": false,
+    "entries": {
+        "PORT": {
+            "type": "Integer",
+            "default": 8080
+        },
+        "NAME": {
+            "type": "String",
+            "regex": "^[a-z]+$",
+            "required": true
+        }
+    }
+}"#;
+
+#[test]
+fn export_to_stdout() {
+    let testdir = TestDir::new();
+    let schema = testdir.create_testfile("schema.json", SCHEMA);
+
+    let expected_output = r#"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "NAME": {
+      "pattern": "^[a-z]+$",
+      "type": "string"
+    },
+    "PORT": {
+      "default": 8080,
+      "type": "integer"
+    }
+  },
+  "required": [
+    "NAME"
+  ],
+  "type": "object"
+}
+"#;
+
+    testdir.test_command_success_with_args(["schema", "export", schema.as_str()], expected_output);
+}
+
+#[test]
+fn export_and_import() {
+    let testdir = TestDir::new();
+    let schema = testdir.create_testfile("schema.json", SCHEMA);
+    testdir.create_testfile(".env", "NAME=UPPER\nOTHER=1\n");
+
+    testdir.test_command_success_with_args_without_closing([
+        "schema",
+        "export",
+        schema.as_str(),
+        "-o",
+        "exported.json",
+    ]);
+    testdir.test_command_success_with_args_without_closing([
+        "schema",
+        "import",
+        "exported.json",
+        "-o",
+        "imported.json",
+    ]);
+
+    let expected_output = check_output(&[(
+        ".env",
+        &[
+            ".env:1 SchemaViolation: The NAME key does not match the regex",
+            ".env:2 SchemaViolation: The OTHER key is not defined in the schema",
+        ],
+    )]);
+    testdir.test_command_fail_with_args(
+        with_default_args(&["check", "--schema", "imported.json", ".env"]),
+        expected_output,
+    );
+}
+
+#[test]
+fn import_unsupported() {
+    let testdir = TestDir::new();
+    let json_schema = testdir.create_testfile(
+        "schema.json",
+        r#"{"type": "object", "properties": {"HOSTS": {"type": "array"}}}"#,
+    );
+
+    testdir.test_command_fail_with_args(
+        ["schema", "import", json_schema.as_str()],
+        "Error importing schema: unsupported type \"array\" at /properties/HOSTS/type\n",
+    );
+}
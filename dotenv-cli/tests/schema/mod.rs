@@ -0,0 +1,3 @@
+mod check;
+mod json_schema;
+mod render;
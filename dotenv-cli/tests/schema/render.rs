@@ -0,0 +1,82 @@
+use crate::common::*;
+
+const SCHEMA: &str = r#"{
+    "version": "1.0.0",
+    "entries": {
+        "PORT": {
+            "type": "Integer",
+            "default": 8080
+        },
+        "DATABASE_URL": {
+            "type": "Url",
+            "required": true,
+            "group": "Database",
+            "description": "Connection string",
+            "example": "postgres://localhost/app"
+        }
+    }
+}"#;
+
+#[test]
+fn render_env_to_stdout() {
+    let testdir = TestDir::new();
+    let schema = testdir.create_testfile("schema.json", SCHEMA);
+
+    let expected_output = "\
+# Type: Integer
+PORT=8080
+
+# Database
+# Connection string
+# Type: Url (required)
+DATABASE_URL=postgres://localhost/app
+";
+
+    testdir.test_command_success_with_args(["schema", "render", schema.as_str()], expected_output);
+}
+
+#[test]
+fn render_env_to_file_passes_check() {
+    let testdir = TestDir::new();
+    let schema = testdir.create_testfile("schema.json", SCHEMA);
+
+    testdir.test_command_success_with_args_without_closing([
+        "schema",
+        "render",
+        schema.as_str(),
+        "--output",
+        ".env.example",
+    ]);
+
+    testdir.test_command_success_with_args(
+        with_default_args(&["check", "--schema", schema.as_str(), ".env.example"]),
+        check_output(&[(".env.example", &[])]),
+    );
+}
+
+#[test]
+fn render_markdown() {
+    let testdir = TestDir::new();
+    let schema = testdir.create_testfile("schema.json", SCHEMA);
+
+    let expected_output = "\
+# Environment variables
+
+## General
+
+| Key | Type | Required | Default | Example | Description |
+| --- | --- | --- | --- | --- | --- |
+| `PORT` | Integer | No | `8080` |  |  |
+
+## Database
+
+| Key | Type | Required | Default | Example | Description |
+| --- | --- | --- | --- | --- | --- |
+| `DATABASE_URL` | Url | Yes |  | `postgres://localhost/app` | Connection string |
+";
+
+    testdir.test_command_success_with_args(
+        ["schema", "render", "--format", "markdown", schema.as_str()],
+        expected_output,
+    );
+}
@@ -0,0 +1,193 @@
+use crate::common::TestDir;
+
+const REFERENCE: &str = "\
+# Application
+APP_NAME=example
+# Port to listen on
+PORT=8080
+
+# Database
+DATABASE_URL=postgres://localhost/app
+";
+
+#[test]
+fn add_missing_keys() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", REFERENCE);
+    let testfile = test_dir.create_testfile(".env", "APP_NAME=app\n");
+    let expected_output = "Syncing .env\nAdded keys: PORT, DATABASE_URL\n";
+
+    let output = test_dir.test_command_success_and_get_output([
+        "sync",
+        "--from",
+        ".env.example",
+        "--no-backup",
+        ".",
+    ]);
+
+    assert_eq!(output, expected_output);
+    assert_eq!(
+        testfile.contents(),
+        "APP_NAME=app\n# Port to listen on\nPORT=\n\n# Database\nDATABASE_URL=\n"
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn reference_values() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", REFERENCE);
+    let testfile = test_dir.create_testfile(".env", "PORT=80\n\nDATABASE_URL=db\n");
+
+    test_dir.test_command_success_with_args_without_closing([
+        "sync",
+        "--from",
+        ".env.example",
+        "--values",
+        "reference",
+        "--no-backup",
+        ".env",
+    ]);
+
+    assert_eq!(
+        testfile.contents(),
+        "# Application\nAPP_NAME=example\nPORT=80\n\nDATABASE_URL=db\n"
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn prune() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", REFERENCE);
+    let testfile = test_dir.create_testfile(
+        ".env",
+        "APP_NAME=app\nDEBUG=true\nPORT=80\n\nDATABASE_URL=db\n",
+    );
+    let expected_output = "Syncing .env\nRemoved keys: DEBUG\n";
+
+    let output = test_dir.test_command_success_and_get_output([
+        "sync",
+        "--from",
+        ".env.example",
+        "--prune",
+        "--no-backup",
+        ".env",
+    ]);
+
+    assert_eq!(output, expected_output);
+    assert_eq!(
+        testfile.contents(),
+        "APP_NAME=app\nPORT=80\n\nDATABASE_URL=db\n"
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn prune_with_comments() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\n# The BAR key\nBAR=\n");
+    let testfile = test_dir.create_testfile(
+        ".env",
+        "FOO=1\n# Local debugging\n# (not in the reference)\nDEBUG=true\n# The BAR key\nBAR=2\n",
+    );
+
+    test_dir.test_command_success_with_args_without_closing([
+        "sync",
+        "--from",
+        ".env.example",
+        "--prune",
+        "--no-backup",
+        ".env",
+    ]);
+
+    assert_eq!(testfile.contents(), "FOO=1\n# The BAR key\nBAR=2\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn line_endings_and_bom() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nBAR=\n\nBAZ=\n");
+    let testfile = test_dir.create_testfile(".env", "\u{feff}FOO=1\r\nLOCAL=2\r\n");
+
+    test_dir.test_command_success_with_args_without_closing([
+        "sync",
+        "--from",
+        ".env.example",
+        "--prune",
+        "--no-backup",
+        ".env",
+    ]);
+
+    assert_eq!(testfile.contents(), "\u{feff}BAR=\r\nFOO=1\r\n\r\nBAZ=\r\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn dry_run() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nBAR=\n");
+    let testfile = test_dir.create_testfile(".env", "FOO=1\n");
+    let expected_output = "\
+Syncing .env
+Added keys: BAR
+Dry run - not changing any files on disk.
+
+BAR=
+FOO=1
+";
+
+    let output = test_dir.test_command_success_and_get_output([
+        "sync",
+        "--from",
+        ".env.example",
+        "--dry-run",
+        ".",
+    ]);
+
+    assert_eq!(output, expected_output);
+    assert_eq!(testfile.contents(), "FOO=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn backup() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nBAR=\n");
+    let testfile = test_dir.create_testfile(".env", "FOO=1\n");
+
+    let output =
+        test_dir.test_command_success_and_get_output(["sync", "--from", ".env.example", "."]);
+
+    assert!(output.contains("Original file was backed up to: "));
+    assert_eq!(testfile.contents(), "BAR=\nFOO=1\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn already_in_sync() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env.example", "FOO=\nBAR=\n");
+    test_dir.create_testfile(".env", "FOO=1\nBAR=2\n");
+    let expected_output = "Syncing .env\nAlready in sync\n";
+
+    test_dir
+        .test_command_success_with_args(["sync", "--from", ".env.example", "."], expected_output);
+}
+
+#[test]
+fn reference_not_found() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=1\n");
+    let expected_output = "Reference file not found: .env.example\n";
+
+    test_dir.test_command_fail_with_args(["sync", "--from", ".env.example", "."], expected_output);
+}
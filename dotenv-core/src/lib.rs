@@ -1,12 +1,42 @@
+/// Characters which end a line in a file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+impl std::fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineEnding::Lf => write!(f, "LF"),
+            LineEnding::CrLf => write!(f, "CRLF"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LineEntry {
     pub number: usize,
     pub raw_string: String,
 
-    /// Used in ExtraBlankLineFixer
-    pub is_deleted: bool,
     /// Used in EndingBlankLineChecker
     pub is_last_line: bool,
+
+    /// Characters which end the line in the file (they are not in `raw_string`)
+    pub ending: LineEnding,
+
+    /// The file starts with a UTF-8 byte order mark before the line (it is not in `raw_string`)
+    pub bom: bool,
 }
 
 impl LineEntry {
@@ -17,8 +47,9 @@ pub fn new<T>(number: usize, raw_string: T, is_last_line: bool) -> Self
         LineEntry {
             number,
             raw_string: raw_string.into(),
-            is_deleted: false,
             is_last_line,
+            ending: LineEnding::default(),
+            bom: false,
         }
     }
 
@@ -65,10 +96,6 @@ fn stripped_export_string(&self) -> &str {
             .unwrap_or(trimmed)
     }
 
-    pub fn mark_as_deleted(&mut self) {
-        self.is_deleted = true;
-    }
-
     // Maybe we should add the comment field to the LineEntry struct (but this requires some
     // refactoring of the line entries creation)
     // pub control_comment: Option<Comment<'a>>
@@ -128,6 +155,55 @@ pub fn is_escaped(prefix: &str) -> bool {
     prefix.chars().rev().take_while(|ch| *ch == '\\').count() % 2 == 1
 }
 
+/// Returns the quote which the value starts with if the value has no closing quote: the same
+/// quote which isn't escaped
+pub fn unclosed_quote(value: &str) -> Option<char> {
+    let value = value.trim_start();
+    let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let rest = &value[1..];
+
+    let is_closed = rest
+        .match_indices(quote)
+        .any(|(index, _)| !is_escaped(&rest[..index]));
+
+    (!is_closed).then_some(quote)
+}
+
+/// Matches the text against a glob pattern: `*` matches any sequence of characters
+/// (including an empty one) and `?` matches any single character
+pub fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and of the text it started to match
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                // Let the last `*` match one more character and try again
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +223,32 @@ fn is_escaped_value_test() {
         assert!(!is_escaped(random_string));
     }
 
+    #[test]
+    fn unclosed_quote_test() {
+        assert_eq!(Some('"'), unclosed_quote("\"foo"));
+        assert_eq!(Some('\''), unclosed_quote(" 'foo\\'"));
+        assert_eq!(Some('"'), unclosed_quote("\""));
+        assert_eq!(None, unclosed_quote("\"foo\""));
+        assert_eq!(None, unclosed_quote("\"foo\" # comment"));
+        assert_eq!(None, unclosed_quote("'multi\nline'"));
+        assert_eq!(None, unclosed_quote("foo\""));
+    }
+
+    #[test]
+    fn matches_glob_test() {
+        assert!(matches_glob("FOO", "FOO"));
+        assert!(!matches_glob("FOO", "FOO_BAR"));
+        assert!(matches_glob("FEATURE_*", "FEATURE_"));
+        assert!(matches_glob("FEATURE_*", "FEATURE_LOGIN"));
+        assert!(!matches_glob("FEATURE_*", "OLD_FEATURE_LOGIN"));
+        assert!(matches_glob("*_URL", "DATABASE_URL"));
+        assert!(matches_glob("*_*_KEY", "AWS_SECRET_ACCESS_KEY"));
+        assert!(matches_glob("A?C", "ABC"));
+        assert!(!matches_glob("A?C", "AC"));
+        assert!(matches_glob("*", ""));
+        assert!(!matches_glob("", "A"));
+    }
+
     pub fn line_entry(number: usize, total_lines: usize, raw_string: &str) -> LineEntry {
         LineEntry::new(number, raw_string, total_lines == number)
     }
@@ -0,0 +1,187 @@
+//! Decoding of the files which are not UTF-8
+
+use crate::file::FileError;
+
+const NUL: u8 = 0;
+const BOM: u16 = 0xFEFF;
+
+/// Characters of Windows-1252 for the bytes `0x80..=0x9F`, the other bytes are the same as in
+/// Latin-1. The undefined bytes are decoded as the C1 control characters, like in Latin-1.
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// 8-bit encodings, which can't be detected. The files which are not valid UTF-8 are decoded
+/// with the encoding if it is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// ISO-8859-1
+    Latin1,
+    Windows1252,
+}
+
+/// Byte order of a UTF-16 file, the file is written back in UTF-16 with the same byte order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+impl ByteOrder {
+    /// Encodes the content in UTF-16 with the byte order mark
+    pub fn encode(self, content: &str) -> Vec<u8> {
+        let to_bytes: fn(u16) -> [u8; 2] = match self {
+            Self::LittleEndian => u16::to_le_bytes,
+            Self::BigEndian => u16::to_be_bytes,
+        };
+
+        std::iter::once(BOM)
+            .chain(content.encode_utf16())
+            .flat_map(to_bytes)
+            .collect()
+    }
+}
+
+impl Encoding {
+    fn decode(self, bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|&byte| match self {
+                Self::Windows1252 if (0x80..=0x9F).contains(&byte) => {
+                    WINDOWS_1252[usize::from(byte - 0x80)]
+                }
+                _ => char::from(byte),
+            })
+            .collect()
+    }
+}
+
+/// Decodes the content of a file: UTF-16 with a byte order mark, UTF-8 or the `fallback`
+/// encoding. A file with a NUL byte (which isn't UTF-16) is considered binary.
+///
+/// The byte order of a UTF-16 file is returned with the content.
+pub(crate) fn decode(
+    bytes: Vec<u8>,
+    fallback: Option<Encoding>,
+) -> Result<(String, Option<ByteOrder>), FileError> {
+    if let Some((byte_order, content)) = decode_utf16(&bytes) {
+        return content.map(|content| (content, Some(byte_order)));
+    }
+
+    if bytes.contains(&NUL) {
+        return Err(FileError::Binary);
+    }
+
+    let content = String::from_utf8(bytes).or_else(|err| match fallback {
+        Some(encoding) => Ok(encoding.decode(err.as_bytes())),
+        None => Err(FileError::InvalidUtf8 {
+            position: err.utf8_error().valid_up_to(),
+        }),
+    })?;
+
+    Ok((content, None))
+}
+
+/// Decodes UTF-16 if the bytes start with its byte order mark. The byte order mark is not kept,
+/// it is a part of the encoding (unlike the one of UTF-8).
+fn decode_utf16(bytes: &[u8]) -> Option<(ByteOrder, Result<String, FileError>)> {
+    let (byte_order, from_bytes): (_, fn([u8; 2]) -> u16) = match bytes.get(..2)? {
+        [0xFF, 0xFE] => (ByteOrder::LittleEndian, u16::from_le_bytes),
+        [0xFE, 0xFF] => (ByteOrder::BigEndian, u16::from_be_bytes),
+        _ => return None,
+    };
+
+    if !bytes.len().is_multiple_of(2) {
+        return Some((byte_order, Err(FileError::InvalidUtf16)));
+    }
+
+    let units = bytes[2..]
+        .chunks_exact(2)
+        .map(|unit| from_bytes([unit[0], unit[1]]));
+    let content = char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| FileError::InvalidUtf16);
+
+    Some((byte_order, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_test() {
+        let content = decode("A=héllo\n".as_bytes().to_vec(), None).expect("decoded");
+        assert_eq!(("A=héllo\n".to_string(), None), content);
+    }
+
+    #[test]
+    fn invalid_utf8_test() {
+        let bytes = b"A=h\xE9llo\n".to_vec();
+
+        assert!(matches!(
+            decode(bytes.clone(), None),
+            Err(FileError::InvalidUtf8 { position: 3 })
+        ));
+        assert_eq!(
+            "A=héllo\n",
+            decode(bytes, Some(Encoding::Latin1)).expect("decoded").0
+        );
+    }
+
+    #[test]
+    fn windows_1252_test() {
+        let bytes = b"A=\x93\x80\x94\xE9\n".to_vec();
+
+        assert_eq!(
+            "A=\u{93}\u{80}\u{94}é\n",
+            decode(bytes.clone(), Some(Encoding::Latin1))
+                .expect("decoded")
+                .0
+        );
+        assert_eq!(
+            "A=“€”é\n",
+            decode(bytes, Some(Encoding::Windows1252))
+                .expect("decoded")
+                .0
+        );
+    }
+
+    #[test]
+    fn utf16_test() {
+        let le: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain("A=é\n".encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+        let be: Vec<u8> = [0xFE, 0xFF]
+            .into_iter()
+            .chain("A=é\n".encode_utf16().flat_map(u16::to_be_bytes))
+            .collect();
+
+        assert_eq!(
+            ("A=é\n".to_string(), Some(ByteOrder::LittleEndian)),
+            decode(le.clone(), None).expect("decoded")
+        );
+        assert_eq!(
+            ("A=é\n".to_string(), Some(ByteOrder::BigEndian)),
+            decode(be.clone(), None).expect("decoded")
+        );
+        assert_eq!(le, ByteOrder::LittleEndian.encode("A=é\n"));
+        assert_eq!(be, ByteOrder::BigEndian.encode("A=é\n"));
+        assert!(matches!(
+            decode(vec![0xFF, 0xFE, 0x41], None),
+            Err(FileError::InvalidUtf16)
+        ));
+    }
+
+    #[test]
+    fn binary_test() {
+        let bytes = b"A=1\n\x00\x01\x02".to_vec();
+
+        assert!(matches!(
+            decode(bytes, Some(Encoding::Latin1)),
+            Err(FileError::Binary)
+        ));
+    }
+}
@@ -1,31 +1,50 @@
 use std::{
     collections::{BTreeMap, btree_map::IntoIter},
-    fmt, fs,
+    error::Error,
+    fmt, fs, io,
     path::{Path, PathBuf},
 };
 
-use dotenv_core::{LineEntry, is_escaped};
+use dotenv_core::{LineEnding, LineEntry, is_escaped};
 
-use crate::quote::get_quote;
+use crate::{
+    encoding::{self, ByteOrder, Encoding},
+    quote::get_quote,
+};
 
 const PATTERN: &str = ".env";
 const EXCLUDED_FILES: &[&str] = &[".envrc"];
 const BACKUP_EXTENSION: &str = ".bak";
+const LOCAL_SUFFIX: &str = ".local";
 pub const LF: &str = "\n";
+const CR: char = '\r';
+const BOM: char = '\u{feff}';
 
-pub struct Files(BTreeMap<FileEntry, Vec<LineEntry>>);
+pub struct Files {
+    files: BTreeMap<FileEntry, Vec<LineEntry>>,
+    /// Files which are found but not read, with the reasons
+    errors: Vec<(PathBuf, FileError)>,
+}
 
 impl Files {
-    pub(crate) fn new(files: BTreeMap<FileEntry, Vec<LineEntry>>) -> Self {
-        Self(files)
+    pub(crate) fn new(
+        files: BTreeMap<FileEntry, Vec<LineEntry>>,
+        errors: Vec<(PathBuf, FileError)>,
+    ) -> Self {
+        Self { files, errors }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.files.is_empty()
     }
 
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.files.len()
+    }
+
+    /// Returns the files which are not read (relative to the current directory) with the reasons
+    pub fn errors(&self) -> &[(PathBuf, FileError)] {
+        &self.errors
     }
 }
 
@@ -34,7 +53,49 @@ impl IntoIterator for Files {
     type IntoIter = IntoIter<FileEntry, Vec<LineEntry>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.files.into_iter()
+    }
+}
+
+/// Reason why a file is not read
+#[derive(Debug)]
+pub enum FileError {
+    Io(io::Error),
+    /// The file isn't valid UTF-8 and no other encoding is set, `position` is the offset of
+    /// the first invalid byte
+    InvalidUtf8 {
+        position: usize,
+    },
+    /// The file starts with the byte order mark of UTF-16, but isn't valid UTF-16
+    InvalidUtf16,
+    /// The file contains a NUL byte
+    Binary,
+}
+
+impl FileError {
+    /// Returns `true` if the file is skipped on purpose (a binary file), not because of an error
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, Self::Binary)
+    }
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::InvalidUtf8 { position } => write!(f, "invalid UTF-8 at byte {position}"),
+            Self::InvalidUtf16 => write!(f, "invalid UTF-16"),
+            Self::Binary => write!(f, "binary file"),
+        }
+    }
+}
+
+impl Error for FileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
     }
 }
 
@@ -43,6 +104,8 @@ pub struct FileEntry {
     pub path: PathBuf,
     pub file_name: String,
     pub total_lines: usize,
+    /// Byte order of a UTF-16 file, other files are read and written in UTF-8
+    pub utf16: Option<ByteOrder>,
 }
 
 impl fmt::Display for FileEntry {
@@ -52,28 +115,81 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 impl FileEntry {
-    /// Converts `PathBuf` to tuple of `(FileEntry, Vec<LineEntry>)`
-    pub(crate) fn from(path: PathBuf) -> Option<(Self, Vec<LineEntry>)> {
-        let file_name = get_file_name(&path)?.to_string();
-        let content = fs::read_to_string(&path).ok()?;
-
-        let mut lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+    /// Converts `PathBuf` to tuple of `(FileEntry, Vec<LineEntry>)`, the content which isn't
+    /// UTF-8 is decoded with `encoding`. Returns `None` for a path without a file name.
+    pub(crate) fn from(
+        path: PathBuf,
+        encoding: Option<Encoding>,
+    ) -> Result<Option<(Self, Vec<LineEntry>)>, FileError> {
+        let Some(file_name) = get_file_name(&path).map(str::to_string) else {
+            return Ok(None);
+        };
+        let (content, utf16) = encoding::decode(fs::read(&path).map_err(FileError::Io)?, encoding)?;
+
+        // The byte order mark and line endings are kept in the line entries, not in the lines
+        let (content, bom) = match content.strip_prefix(BOM) {
+            Some(content) => (content, true),
+            None => (content.as_str(), false),
+        };
+
+        let mut lines: Vec<(String, LineEnding)> = content
+            .split_inclusive(LF)
+            .map(|line| match line.strip_suffix(LF) {
+                Some(line) => match line.strip_suffix(CR) {
+                    Some(line) => (line.to_string(), LineEnding::CrLf),
+                    None => (line.to_string(), LineEnding::Lf),
+                },
+                None => (line.to_string(), LineEnding::default()),
+            })
+            .collect();
+
+        // The last line without LF gets the line ending of the file, it is used if LF is added
+        if !content.ends_with(LF)
+            && let Some(ending) = lines.first().map(|(_, ending)| *ending)
+            && let Some(last) = lines.last_mut()
+        {
+            last.1 = ending;
+        }
 
-        // You must add a line, because [`Lines`] does not return the last empty row (excludes LF)
-        if content.ends_with(LF) {
-            lines.push(LF.to_string());
+        // You must add a line, because the last empty row is not returned (excludes LF)
+        if let Some(ending) = lines.last().map(|(_, ending)| *ending)
+            && content.ends_with(LF)
+        {
+            lines.push((LF.to_string(), ending));
         }
 
-        let lines = get_line_entries(lines);
+        let mut lines = get_line_entries(lines);
+        if let Some(first) = lines.first_mut() {
+            first.bom = bom;
+        }
 
-        Some((
+        Ok(Some((
             FileEntry {
                 path,
                 file_name,
                 total_lines: lines.len(),
+                utf16,
             },
             lines,
-        ))
+        )))
+    }
+
+    /// Returns the environment name from the file name, e.g. `production` for `.env.production`,
+    /// `.env.production.local` or `production.env`. Files with other suffixes (e.g.
+    /// `.env.production.common`) have no environment.
+    pub fn environment(&self) -> Option<&str> {
+        let name = self
+            .file_name
+            .strip_prefix(PATTERN)
+            .and_then(|name| name.strip_prefix('.'))
+            .or_else(|| self.file_name.strip_suffix(PATTERN))?;
+        let name = name.strip_suffix(LOCAL_SUFFIX).unwrap_or(name);
+
+        if name.is_empty() || name.contains('.') || name == &LOCAL_SUFFIX[1..] {
+            return None;
+        }
+
+        Some(name)
     }
 }
 
@@ -90,13 +206,16 @@ fn get_file_name(path: &Path) -> Option<&str> {
     path.file_name().and_then(|file_name| file_name.to_str())
 }
 
-fn get_line_entries(lines: Vec<String>) -> Vec<LineEntry> {
+fn get_line_entries(lines: Vec<(String, LineEnding)>) -> Vec<LineEntry> {
     let length = lines.len();
 
     let mut lines: Vec<LineEntry> = lines
         .into_iter()
         .enumerate()
-        .map(|(index, line)| LineEntry::new(index + 1, line, length == (index + 1)))
+        .map(|(index, (line, ending))| LineEntry {
+            ending,
+            ..LineEntry::new(index + 1, line, length == (index + 1))
+        })
         .collect();
 
     reduce_multiline_entries(&mut lines);
@@ -110,13 +229,26 @@ fn reduce_multiline_entries(lines: &mut Vec<LineEntry>) {
     // Replace multiline value to one line-entry for checking
     let mut offset = 1; // index offset to account deleted lines (for access by index)
     for (start, end) in multiline_ranges {
-        let result = lines
+        let entries: Vec<LineEntry> = lines
             .drain(start - offset..end - offset + 1) // TODO: consider `drain_filter` (after stabilization in rust std)
-            .map(|entry| entry.raw_string)
-            .reduce(|result, line| result + "\n" + &line); // TODO: `intersperse` (after stabilization in rust std)
+            .collect();
+        // The entry ends with the line ending of its last line, the line endings inside it are
+        // kept in the value (they are a part of it), so CRLF stays CRLF when the file is written
+        let ending = entries.last().map(|entry| entry.ending).unwrap_or_default();
+        let result = entries
+            .into_iter()
+            .map(|entry| (entry.raw_string, entry.ending))
+            .reduce(|(result, previous), (line, ending)| {
+                (result + previous.as_str() + &line, ending)
+            })
+            .map(|(result, _)| result);
 
         if let Some(value) = result {
-            lines.insert(start - offset, LineEntry::new(start, value, length == end));
+            let entry = LineEntry {
+                ending,
+                ..LineEntry::new(start, value, length == end)
+            };
+            lines.insert(start - offset, entry);
         }
 
         offset += end - start;
@@ -125,30 +257,43 @@ fn reduce_multiline_entries(lines: &mut Vec<LineEntry>) {
 
 fn find_multiline_ranges(lines: &[LineEntry]) -> Vec<(usize, usize)> {
     let mut multiline_ranges: Vec<(usize, usize)> = Vec::new();
-    let mut start_number: Option<usize> = None;
-    let mut quote_char: Option<char> = None;
+    let mut index = 0;
 
     // here we find ranges of multi-line values
-    lines.iter().for_each(|entry| {
-        if let Some(start) = start_number {
-            if let Some(quote_char) = quote_char
-                && let Some(idx) = entry.raw_string.find(quote_char)
-                && !is_escaped(&entry.raw_string[..idx])
-            {
-                multiline_ranges.push((start, entry.number));
-                start_number = None;
-            }
-        } else if let Some(trimmed_value) = entry.get_value().map(|val| val.trim())
-            && let Some(quote) = get_quote(trimmed_value)
-        {
-            quote_char = Some(quote.as_char());
-            start_number = Some(entry.number);
+    while index < lines.len() {
+        let start = &lines[index];
+        index += 1;
+
+        let Some(quote) = start.get_value().map(str::trim).and_then(get_quote) else {
+            continue;
+        };
+
+        // A value without the closing quote takes no other lines, so they are parsed as usual
+        // (the value is reported by the `SyntaxError` check)
+        if let Some(offset) = find_closing_line(&lines[index..], quote.as_char()) {
+            multiline_ranges.push((start.number, lines[index + offset].number));
+            index += offset + 1;
         }
-    });
+    }
 
     multiline_ranges
 }
 
+/// Returns the offset of the line with the closing quote, `None` if the file ends before it.
+/// A line closes the value only if it leaves the quote closed, i.e. it has an odd number of
+/// unescaped quotes (a line like `B="bar"` opens and closes its own value).
+fn find_closing_line(lines: &[LineEntry], quote_char: char) -> Option<usize> {
+    lines.iter().position(|entry| {
+        let quotes = entry
+            .raw_string
+            .match_indices(quote_char)
+            .filter(|(idx, _)| !is_escaped(&entry.raw_string[..*idx]))
+            .count();
+
+        quotes % 2 == 1
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,7 +303,7 @@ mod from {
 
         #[test]
         fn path_without_file_test() {
-            let f = FileEntry::from(PathBuf::from("/"));
+            let f = FileEntry::from(PathBuf::from("/"), None).expect("no error");
             assert_eq!(None, f);
         }
 
@@ -169,13 +314,14 @@ fn path_with_file_test() {
             let path = dir.path().join(&file_name);
             fs::File::create(&path).expect("create testfile");
 
-            let f = FileEntry::from(path.clone());
+            let f = FileEntry::from(path.clone(), None).expect("no error");
             assert_eq!(
                 Some((
                     FileEntry {
                         path,
                         file_name,
-                        total_lines: 0
+                        total_lines: 0,
+                        utf16: None,
                     },
                     vec![]
                 )),
@@ -183,6 +329,131 @@ fn path_with_file_test() {
             );
             dir.close().expect("temp dir deleted");
         }
+
+        #[test]
+        fn line_endings_and_bom_test() {
+            let dir = tempfile::tempdir().expect("create temp dir");
+            let path = dir.path().join(".env");
+            fs::write(&path, "\u{feff}A=1\r\nB=\"2\r\n3\"\nC=4\r\nD=\"5\n6\"\r\n")
+                .expect("write testfile");
+
+            let (_, lines) = FileEntry::from(path, None)
+                .expect("no error")
+                .expect("file entry");
+            let lines: Vec<(&str, LineEnding, bool)> = lines
+                .iter()
+                .map(|line| (line.raw_string.as_str(), line.ending, line.bom))
+                .collect();
+
+            assert_eq!(
+                vec![
+                    ("A=1", LineEnding::CrLf, true),
+                    ("B=\"2\r\n3\"", LineEnding::Lf, false),
+                    ("C=4", LineEnding::CrLf, false),
+                    ("D=\"5\n6\"", LineEnding::CrLf, false),
+                    (LF, LineEnding::CrLf, false),
+                ],
+                lines
+            );
+            dir.close().expect("temp dir deleted");
+        }
+    }
+
+    #[test]
+    fn not_utf8_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join(".env");
+        fs::write(&path, b"A=caf\xE9\n").expect("write testfile");
+
+        assert!(matches!(
+            FileEntry::from(path.clone(), None),
+            Err(FileError::InvalidUtf8 { position: 5 })
+        ));
+
+        let (_, lines) = FileEntry::from(path.clone(), Some(Encoding::Latin1))
+            .expect("no error")
+            .expect("file entry");
+        assert_eq!("A=café", lines[0].raw_string);
+
+        fs::write(&path, b"\x00\x01").expect("write testfile");
+        assert!(matches!(
+            FileEntry::from(path, None),
+            Err(FileError::Binary)
+        ));
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn unterminated_quote_test() {
+        let lines = ["A=1", "B=\"2", "3\"", "C='4", "D=5"]
+            .into_iter()
+            .map(|line| (line.to_string(), LineEnding::Lf))
+            .collect();
+
+        let lines: Vec<String> = get_line_entries(lines)
+            .into_iter()
+            .map(|line| line.raw_string)
+            .collect();
+
+        assert_eq!(vec!["A=1", "B=\"2\n3\"", "C='4", "D=5"], lines);
+    }
+
+    #[test]
+    fn unterminated_quote_before_quoted_value_test() {
+        let lines = ["A=\"foo", "B=\"bar\"", "c=baz"]
+            .into_iter()
+            .map(|line| (line.to_string(), LineEnding::Lf))
+            .collect();
+
+        let lines: Vec<String> = get_line_entries(lines)
+            .into_iter()
+            .map(|line| line.raw_string)
+            .collect();
+
+        assert_eq!(vec!["A=\"foo", "B=\"bar\"", "c=baz"], lines);
+    }
+
+    #[test]
+    fn multiline_value_with_assignment_test() {
+        let lines = ["SCRIPT=\"", "export A='x'", "echo hi", "\"", "B=1"]
+            .into_iter()
+            .map(|line| (line.to_string(), LineEnding::Lf))
+            .collect();
+
+        let lines: Vec<String> = get_line_entries(lines)
+            .into_iter()
+            .map(|line| line.raw_string)
+            .collect();
+
+        assert_eq!(vec!["SCRIPT=\"\nexport A='x'\necho hi\n\"", "B=1"], lines);
+    }
+
+    #[test]
+    fn environment_test() {
+        let assertions = vec![
+            (".env", None),
+            (".env.local", None),
+            (".env.production", Some("production")),
+            (".env.production.local", Some("production")),
+            (".env.foo.common", None),
+            (".env.foo.common.local", None),
+            ("staging.env", Some("staging")),
+        ];
+
+        for (file_name, expected) in assertions {
+            let fe = FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines: 0,
+                utf16: None,
+            };
+
+            assert_eq!(
+                expected,
+                fe.environment(),
+                "Expected {expected:?} for the file name {file_name}"
+            );
+        }
     }
 
     #[test]
@@ -1,20 +1,26 @@
-use std::path::PathBuf;
-
-use dotenv_core::LineEntry;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use crate::file::Files;
 
+mod encoding;
 mod file;
 mod fs;
 mod quote;
 
-pub use crate::file::FileEntry;
+pub use crate::{
+    encoding::{ByteOrder, Encoding},
+    file::{FileEntry, FileError},
+};
 
 pub struct Finder<'a> {
     dir: &'a PathBuf,
     paths: Vec<PathBuf>,
     excluded: Vec<PathBuf>,
     recursive: bool,
+    encoding: Option<Encoding>,
 }
 
 pub struct FinderBuilder<'a> {
@@ -22,6 +28,7 @@ pub struct FinderBuilder<'a> {
     paths: Vec<PathBuf>,
     excluded: Vec<PathBuf>,
     recursive: bool,
+    encoding: Option<Encoding>,
 }
 
 impl<'a> FinderBuilder<'a> {
@@ -31,14 +38,12 @@ pub fn new(dir: &'a PathBuf) -> Self {
             paths: vec![],
             excluded: vec![],
             recursive: false,
+            encoding: None,
         }
     }
 
     pub fn with_paths(mut self, paths: &'a [&'a PathBuf]) -> Self {
-        self.paths = paths
-            .iter()
-            .filter_map(|f| fs::canonicalize(f).ok())
-            .collect();
+        self.paths = paths.iter().filter_map(|f| canonical_path(f)).collect();
 
         if self.paths.is_empty() {
             self.paths.push(self.dir.clone());
@@ -60,29 +65,62 @@ pub fn recursive(mut self, recursive: bool) -> Self {
         self
     }
 
+    /// Sets the encoding of the files which are not valid UTF-8
+    pub fn encoding(mut self, encoding: Option<Encoding>) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     pub fn build(self) -> Finder<'a> {
         Finder {
             dir: self.dir,
             paths: self.paths,
             excluded: self.excluded,
             recursive: self.recursive,
+            encoding: self.encoding,
         }
     }
 }
 
 impl<'a> Finder<'a> {
     pub fn find(&self) -> Files {
-        let files = find_dotenv_paths(self.paths.clone(), self.excluded.as_slice(), self.recursive)
-            .iter()
-            .filter_map(|path: &PathBuf| -> Option<(FileEntry, Vec<LineEntry>)> {
-                fs::get_relative_path(path, self.dir).and_then(FileEntry::from)
-            })
-            .collect();
+        let mut files = BTreeMap::new();
+        let mut errors = Vec::new();
+
+        let paths = find_dotenv_paths(self.paths.clone(), self.excluded.as_slice(), self.recursive);
+        for path in paths {
+            let Some(path) = fs::get_relative_path(&path, self.dir) else {
+                continue;
+            };
+
+            match FileEntry::from(path.clone(), self.encoding) {
+                Ok(Some((fe, lines))) => {
+                    files.insert(fe, lines);
+                }
+                Ok(None) => {}
+                Err(err) => errors.push((path, err)),
+            }
+        }
 
-        Files::new(files)
+        Files::new(files, errors)
     }
 }
 
+/// Returns the canonical path of a file or a directory. A symbolic link to a file is kept (only
+/// its directory is canonicalized), so it is the link which is read and written, like the links
+/// found in directories.
+fn canonical_path(path: &Path) -> Option<PathBuf> {
+    if path.is_symlink() && path.is_file() {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        return Some(fs::canonicalize(dir).ok()?.join(path.file_name()?));
+    }
+
+    fs::canonicalize(path).ok()
+}
+
 fn find_dotenv_paths(
     dir_entries: Vec<PathBuf>,
     excludes: &[PathBuf],
@@ -1,4 +1,4 @@
-use dotenv_core::is_escaped;
+use dotenv_core::unclosed_quote;
 
 pub(crate) enum Quote {
     Single,
@@ -13,15 +13,14 @@ pub(crate) fn as_char(&self) -> char {
         }
     }
 
+    /// Checks if the value starts with the quote and has no closing one (it continues on the
+    /// next lines)
     fn is_quoted(&self, val: &str) -> bool {
-        val.starts_with(self.as_char())
-            && (val.len() == 1
-                || !val.ends_with(self.as_char())
-                || is_escaped(&val[..val.len() - 1]))
+        unclosed_quote(val) == Some(self.as_char())
     }
 }
 
-/// Returns the `Quote` for a `&str` starting with a quote-char
+/// Returns the `Quote` for a `&str` starting with a quote-char without the closing one
 pub(crate) fn get_quote(val: &str) -> Option<Quote> {
     [Quote::Single, Quote::Double]
         .into_iter()
@@ -0,0 +1,102 @@
+use std::{
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// A problem found while loading a schema file
+#[derive(Debug)]
+pub struct SchemaError {
+    /// Schema file which contains the problem
+    pub path: PathBuf,
+    /// Line and column (both 1-based) of the problem, if it is known
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl SchemaError {
+    pub(crate) fn new(path: &Path, position: Option<(usize, usize)>, message: String) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            position,
+            message,
+        }
+    }
+
+    pub(crate) fn io(path: &Path, err: io::Error) -> Self {
+        Self::new(path, None, err.to_string())
+    }
+
+    pub(crate) fn json(path: &Path, err: serde_json::Error) -> Self {
+        let position = (err.line() != 0).then(|| (err.line(), err.column()));
+
+        // The position is stored separately, so remove it from the message
+        let message = err.to_string();
+        let suffix = format!(" at line {} column {}", err.line(), err.column());
+        let message = message
+            .strip_suffix(&suffix)
+            .unwrap_or(&message)
+            .to_string();
+
+        Self::new(path, position, message)
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "{} at line {line} column {column}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+impl From<SchemaError> for io::Error {
+    fn from(err: SchemaError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Returns the position of the last needle in the text.
+/// Each needle is searched after the previous one, so a path of JSON keys can be used.
+pub(crate) fn locate(text: &str, needles: &[&str]) -> Option<(usize, usize)> {
+    let mut start = 0;
+    let mut end = 0;
+
+    for needle in needles {
+        start = end + text[end..].find(needle)?;
+        end = start + needle.len();
+    }
+
+    let line = text[..start].matches('\n').count() + 1;
+    let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+    let column = text[line_start..start].chars().count() + 1;
+
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_path() {
+        let text = "{\n  \"a\": {\n    \"b\": 1\n  },\n  \"b\": 2\n}";
+
+        assert_eq!(Some((3, 5)), locate(text, &["\"a\"", "\"b\""]));
+        assert_eq!(Some((5, 3)), locate(text, &["}", "\"b\""]));
+        assert_eq!(None, locate(text, &["\"c\""]));
+    }
+
+    #[test]
+    fn json_message_without_position() {
+        let err = serde_json::from_str::<serde_json::Value>("{\n  bad\n}").expect_err("bad json");
+        let err = SchemaError::json(Path::new("schema.json"), err);
+
+        assert_eq!(err.message, "key must be a string");
+        assert_eq!(err.position, Some((2, 3)));
+        assert_eq!(err.to_string(), "key must be a string at line 2 column 3");
+    }
+}
@@ -0,0 +1,629 @@
+//! Conversion between [`DotEnvSchema`] and [JSON Schema](https://json-schema.org) draft 2020-12.
+//!
+//! Every key becomes a property of an object:
+//! * `type` maps to `type` (with `format` for `Url` and `Email`);
+//! * `regex` maps to `pattern`;
+//! * required keys are listed in `required`;
+//! * `allow_other_keys` maps to `additionalProperties`;
+//! * `description`, `default` and `example` map to the keywords of the same name (`examples`);
+//! * `deprecated` maps to `deprecated`, `replaced_by` and `group` map to the `x-replaced-by` and
+//!   `x-group` annotations;
+//! * pattern entries (`key_regex` or a name with wildcards) become `patternProperties`.
+//!
+//! Overlays are not exported, apply one with [`DotEnvSchema::for_environment`] first. Keywords
+//! without a counterpart in the schema (e.g. `enum`, `minimum` or `$ref`) are not imported, they
+//! are reported as errors, so an imported schema doesn't validate less than the document.
+
+use std::{collections::HashMap, fmt};
+
+use regex::Regex;
+use serde_json::{Map, Value, json};
+
+use crate::{DotEnvSchema, SchemaEntry, SchemaValueType};
+
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+/// Version of the schemas created by [`from_json_schema`]
+const IMPORTED_VERSION: &str = "1.0.0";
+
+/// Keywords of the document which are imported or don't change validation
+const DOCUMENT_KEYWORDS: [&str; 10] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "type",
+    "properties",
+    "patternProperties",
+    "required",
+    "additionalProperties",
+];
+
+/// Keywords of a property which are imported or don't change validation
+const PROPERTY_KEYWORDS: [&str; 11] = [
+    "$comment",
+    "title",
+    "type",
+    "format",
+    "pattern",
+    "description",
+    "default",
+    "examples",
+    "deprecated",
+    "x-replaced-by",
+    "x-group",
+];
+
+/// An error for JSON Schema constructs which can't be expressed in a [`DotEnvSchema`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct JsonSchemaError {
+    /// JSON pointer to the unsupported value
+    pub pointer: String,
+    pub message: String,
+}
+
+impl JsonSchemaError {
+    fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for JsonSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.pointer)
+    }
+}
+
+impl std::error::Error for JsonSchemaError {}
+
+/// Converts a schema to an equivalent JSON Schema document
+pub fn to_json_schema(schema: &DotEnvSchema) -> Value {
+    let mut properties = Map::new();
+    let mut pattern_properties = Map::new();
+    let mut required = Vec::new();
+
+    for (key, entry) in &schema.entries {
+        if entry.is_pattern(key) {
+            let pattern = match &entry.key_regex {
+                Some(regex) => regex.as_str().to_string(),
+                None => glob_to_regex(key),
+            };
+            pattern_properties.insert(pattern, property(entry));
+            continue;
+        }
+
+        if entry.required {
+            required.push(key.clone());
+        }
+
+        properties.insert(key.clone(), property(entry));
+    }
+
+    required.sort();
+
+    let mut document = json!({
+        "$schema": DIALECT,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": schema.allow_other_keys,
+    });
+
+    if !pattern_properties.is_empty() {
+        document["patternProperties"] = Value::Object(pattern_properties);
+    }
+
+    document
+}
+
+/// Converts a glob (`*` and `?` wildcards) to an anchored regex
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    regex.push('$');
+    regex
+}
+
+fn property(entry: &SchemaEntry) -> Value {
+    let mut property = Map::new();
+
+    let (value_type, format) = match entry.value_type {
+        SchemaValueType::String => ("string", None),
+        SchemaValueType::Integer => ("integer", None),
+        SchemaValueType::Float => ("number", None),
+        SchemaValueType::Boolean => ("boolean", None),
+        SchemaValueType::Url => ("string", Some("uri")),
+        SchemaValueType::Email => ("string", Some("email")),
+    };
+    property.insert("type".into(), value_type.into());
+
+    if let Some(format) = format {
+        property.insert("format".into(), format.into());
+    }
+
+    if let Some(regex) = &entry.regex {
+        property.insert("pattern".into(), regex.as_str().into());
+    }
+
+    if let Some(description) = &entry.description {
+        property.insert("description".into(), description.as_str().into());
+    }
+
+    if let Some(default) = &entry.default {
+        property.insert("default".into(), typed_value(entry.value_type, default));
+    }
+
+    if let Some(example) = &entry.example {
+        property.insert(
+            "examples".into(),
+            json!([typed_value(entry.value_type, example)]),
+        );
+    }
+
+    if entry.deprecated {
+        property.insert("deprecated".into(), true.into());
+    }
+
+    if let Some(replaced_by) = &entry.replaced_by {
+        property.insert("x-replaced-by".into(), replaced_by.as_str().into());
+    }
+
+    if let Some(group) = &entry.group {
+        property.insert("x-group".into(), group.as_str().into());
+    }
+
+    Value::Object(property)
+}
+
+/// Converts a value from a `.env` file to the JSON type of the property, if possible
+fn typed_value(value_type: SchemaValueType, value: &str) -> Value {
+    let typed = match value_type {
+        SchemaValueType::Integer => value.parse::<i64>().ok().map(Value::from),
+        SchemaValueType::Float => value.parse::<f64>().ok().map(Value::from),
+        SchemaValueType::Boolean => value.to_lowercase().parse::<bool>().ok().map(Value::from),
+        _ => None,
+    };
+
+    typed.unwrap_or_else(|| value.into())
+}
+
+/// Converts a JSON Schema document to a schema.
+///
+/// Only objects with simple properties (the reverse of [`to_json_schema`]) are supported.
+pub fn from_json_schema(document: &Value) -> Result<DotEnvSchema, JsonSchemaError> {
+    let document = document
+        .as_object()
+        .ok_or_else(|| JsonSchemaError::new("", "expected an object"))?;
+    check_keywords(document, &DOCUMENT_KEYWORDS, "")?;
+
+    if let Some(value_type) = document.get("type")
+        && value_type != "object"
+    {
+        return Err(JsonSchemaError::new(
+            "/type",
+            format!("unsupported type {value_type}"),
+        ));
+    }
+
+    let mut entries = HashMap::new();
+    if let Some(properties) = document.get("properties") {
+        let properties = properties
+            .as_object()
+            .ok_or_else(|| JsonSchemaError::new("/properties", "expected an object"))?;
+
+        for (key, property) in properties {
+            let pointer = format!("/properties/{}", escape_pointer(key));
+            entries.insert(key.clone(), entry(property, &pointer)?);
+        }
+    }
+
+    if let Some(pattern_properties) = document.get("patternProperties") {
+        let pattern_properties = pattern_properties
+            .as_object()
+            .ok_or_else(|| JsonSchemaError::new("/patternProperties", "expected an object"))?;
+
+        for (pattern, property) in pattern_properties {
+            let pointer = format!("/patternProperties/{}", escape_pointer(pattern));
+            let key_regex = Regex::new(pattern)
+                .map_err(|err| JsonSchemaError::new(&pointer, err.to_string()))?;
+
+            let mut entry = entry(property, &pointer)?;
+            entry.key_regex = Some(key_regex);
+            entries.insert(pattern.clone(), entry);
+        }
+    }
+
+    if let Some(required) = document.get("required") {
+        let required = required
+            .as_array()
+            .ok_or_else(|| JsonSchemaError::new("/required", "expected an array"))?;
+
+        for (index, key) in required.iter().enumerate() {
+            let pointer = format!("/required/{index}");
+            let key = key
+                .as_str()
+                .ok_or_else(|| JsonSchemaError::new(&pointer, "expected a string"))?;
+
+            // A required key without a property is a string without restrictions
+            entries.entry(key.to_string()).or_default().required = true;
+        }
+    }
+
+    // Additional properties are allowed in JSON Schema unless they are disabled explicitly
+    let allow_other_keys = match document.get("additionalProperties") {
+        None => true,
+        Some(Value::Bool(allow)) => *allow,
+        Some(_) => {
+            return Err(JsonSchemaError::new(
+                "/additionalProperties",
+                "only boolean values are supported",
+            ));
+        }
+    };
+
+    Ok(DotEnvSchema {
+        version: IMPORTED_VERSION.to_string(),
+        allow_other_keys,
+        entries,
+        ..Default::default()
+    })
+}
+
+fn entry(property: &Value, pointer: &str) -> Result<SchemaEntry, JsonSchemaError> {
+    let property = property
+        .as_object()
+        .ok_or_else(|| JsonSchemaError::new(pointer, "expected an object"))?;
+    check_keywords(property, &PROPERTY_KEYWORDS, pointer)?;
+
+    let value_type = match property.get("type") {
+        None => "string",
+        Some(Value::String(value_type)) => value_type.as_str(),
+        Some(value_type) => {
+            return Err(JsonSchemaError::new(
+                format!("{pointer}/type"),
+                format!("unsupported type {value_type}"),
+            ));
+        }
+    };
+    let format = property.get("format").and_then(Value::as_str);
+
+    let value_type = match (value_type, format) {
+        ("string", Some("uri" | "url")) => SchemaValueType::Url,
+        ("string", Some("email")) => SchemaValueType::Email,
+        ("string", _) => SchemaValueType::String,
+        ("integer", _) => SchemaValueType::Integer,
+        ("number", _) => SchemaValueType::Float,
+        ("boolean", _) => SchemaValueType::Boolean,
+        (value_type, _) => {
+            return Err(JsonSchemaError::new(
+                format!("{pointer}/type"),
+                format!("unsupported type \"{value_type}\""),
+            ));
+        }
+    };
+
+    // A regex checks the value as it is written in the file, whatever the type is
+    let regex = match property.get("pattern") {
+        None => None,
+        Some(pattern) => {
+            let pattern = pattern.as_str().ok_or_else(|| {
+                JsonSchemaError::new(format!("{pointer}/pattern"), "expected a string")
+            })?;
+            let regex = Regex::new(pattern).map_err(|err| {
+                JsonSchemaError::new(format!("{pointer}/pattern"), err.to_string())
+            })?;
+            Some(regex)
+        }
+    };
+
+    let example = property
+        .get("examples")
+        .and_then(Value::as_array)
+        .and_then(|examples| examples.first());
+
+    Ok(SchemaEntry {
+        value_type,
+        regex,
+        description: property
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        default: property.get("default").and_then(scalar_value),
+        example: example.and_then(scalar_value),
+        deprecated: property
+            .get("deprecated")
+            .and_then(Value::as_bool)
+            .unwrap_or_default(),
+        replaced_by: string_keyword(property, "x-replaced-by", pointer)?,
+        group: string_keyword(property, "x-group", pointer)?,
+        ..Default::default()
+    })
+}
+
+/// Returns an error for the first keyword of the object which isn't supported
+fn check_keywords(
+    object: &Map<String, Value>,
+    supported: &[&str],
+    pointer: &str,
+) -> Result<(), JsonSchemaError> {
+    match object.keys().find(|key| !supported.contains(&key.as_str())) {
+        Some(keyword) => Err(JsonSchemaError::new(
+            format!("{pointer}/{}", escape_pointer(keyword)),
+            format!("unsupported keyword \"{keyword}\""),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn string_keyword(
+    object: &Map<String, Value>,
+    keyword: &str,
+    pointer: &str,
+) -> Result<Option<String>, JsonSchemaError> {
+    object
+        .get(keyword)
+        .map(|value| {
+            value.as_str().map(str::to_string).ok_or_else(|| {
+                JsonSchemaError::new(format!("{pointer}/{keyword}"), "expected a string")
+            })
+        })
+        .transpose()
+}
+
+fn scalar_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(_) | Value::Bool(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Escapes a key to use it in a JSON pointer (RFC 6901)
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_schema() -> DotEnvSchema {
+        let json = r#"{
+            "version": "1.0.0",
+            "entries": {
+                "NAME": {
+                    "type": "String",
+                    "regex": "^[A-Z]+$",
+                    "required": true
+                },
+                "PORT": {
+                    "type": "Integer",
+                    "regex": "^[0-9]{4}$",
+                    "default": 8080,
+                    "description": "Port to listen on",
+                    "group": "Server"
+                },
+                "PRICE": {
+                    "type": "Float"
+                },
+                "URL": {
+                    "type": "Url",
+                    "example": "https://example.com"
+                },
+                "EMAIL": {
+                    "type": "Email",
+                    "required": true
+                },
+                "FLAG": {
+                    "type": "Boolean",
+                    "default": "TRUE"
+                },
+                "OLD_NAME": {
+                    "deprecated": true,
+                    "replaced_by": "NAME"
+                },
+                "FEATURE_*": {
+                    "type": "Boolean"
+                },
+                "LIMITS": {
+                    "key_regex": "^LIMIT_[0-9]+$",
+                    "type": "Integer"
+                }
+            }
+        }"#;
+        serde_json::from_str(json).expect("deserializing schema")
+    }
+
+    #[test]
+    fn export() {
+        let expected = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "NAME": { "type": "string", "pattern": "^[A-Z]+$" },
+                "PORT": {
+                    "type": "integer",
+                    "pattern": "^[0-9]{4}$",
+                    "default": 8080,
+                    "description": "Port to listen on",
+                    "x-group": "Server"
+                },
+                "PRICE": { "type": "number" },
+                "URL": { "type": "string", "format": "uri", "examples": ["https://example.com"] },
+                "EMAIL": { "type": "string", "format": "email" },
+                "FLAG": { "type": "boolean", "default": true },
+                "OLD_NAME": { "type": "string", "deprecated": true, "x-replaced-by": "NAME" }
+            },
+            "patternProperties": {
+                "^FEATURE_.*$": { "type": "boolean" },
+                "^LIMIT_[0-9]+$": { "type": "integer" }
+            },
+            "required": ["EMAIL", "NAME"],
+            "additionalProperties": false
+        });
+
+        assert_eq!(expected, to_json_schema(&load_schema()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let schema = load_schema();
+        let imported = from_json_schema(&to_json_schema(&schema)).expect("import schema");
+
+        assert!(!imported.allow_other_keys);
+        assert_eq!(imported.entries.len(), schema.entries.len());
+        for (key, entry) in schema.entries.iter().filter(|(key, e)| !e.is_pattern(key)) {
+            let imported = &imported.entries[key];
+
+            assert_eq!(imported.value_type, entry.value_type, "type of {key}");
+            assert_eq!(imported.required, entry.required, "required of {key}");
+            assert_eq!(imported.description, entry.description);
+            assert_eq!(imported.example, entry.example);
+            assert_eq!(imported.deprecated, entry.deprecated);
+            assert_eq!(imported.replaced_by, entry.replaced_by);
+            assert_eq!(imported.group, entry.group);
+            assert_eq!(
+                imported.regex.as_ref().map(Regex::as_str),
+                entry.regex.as_ref().map(Regex::as_str)
+            );
+        }
+        assert_eq!(imported.entries["PORT"].default.as_deref(), Some("8080"));
+        assert_eq!(imported.entries["FLAG"].default.as_deref(), Some("true"));
+        assert!(imported.entries["OLD_NAME"].deprecated);
+
+        let feature = imported.entry_for("FEATURE_LOGIN").expect("pattern entry");
+        assert_eq!(feature.value_type, SchemaValueType::Boolean);
+        assert!(imported.entry_for("OLD_FEATURE_LOGIN").is_none());
+        let limit = imported.entry_for("LIMIT_10").expect("pattern entry");
+        assert_eq!(limit.value_type, SchemaValueType::Integer);
+    }
+
+    #[test]
+    fn import_defaults() {
+        let document = json!({
+            "type": "object",
+            "properties": { "NAME": {} },
+            "required": ["NAME", "OTHER"]
+        });
+
+        let schema = from_json_schema(&document).expect("import schema");
+
+        assert!(schema.allow_other_keys);
+        assert_eq!(schema.version, IMPORTED_VERSION);
+        assert!(schema.entries["NAME"].required);
+        assert!(schema.entries["OTHER"].required);
+        assert_eq!(schema.entries["OTHER"].value_type, SchemaValueType::String);
+    }
+
+    #[test]
+    fn import_unsupported_type() {
+        let document = json!({
+            "properties": { "LIST": { "type": "array" } }
+        });
+
+        assert_eq!(
+            Err(JsonSchemaError::new(
+                "/properties/LIST/type",
+                "unsupported type \"array\""
+            )),
+            from_json_schema(&document).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn import_invalid_pattern() {
+        let document = json!({
+            "properties": { "a/b": { "type": "string", "pattern": "~[.." } }
+        });
+
+        let err = from_json_schema(&document).expect_err("invalid pattern");
+
+        assert_eq!(err.pointer, "/properties/a~1b/pattern");
+    }
+
+    #[test]
+    fn import_pattern_for_integer() {
+        let document = json!({
+            "properties": { "PORT": { "type": "integer", "pattern": "^1" } }
+        });
+
+        let schema = from_json_schema(&document).expect("import schema");
+
+        assert_eq!(
+            schema.entries["PORT"].regex.as_ref().map(Regex::as_str),
+            Some("^1")
+        );
+    }
+
+    #[test]
+    fn json_schema_round_trip() {
+        let document = to_json_schema(&load_schema());
+        let imported = from_json_schema(&document).expect("import schema");
+
+        assert_eq!(document, to_json_schema(&imported));
+    }
+
+    #[test]
+    fn import_unsupported_keywords() {
+        let properties = [
+            json!({ "type": "string", "enum": ["a", "b"] }),
+            json!({ "const": "a" }),
+            json!({ "type": "integer", "minimum": 1 }),
+            json!({ "type": "integer", "maximum": 10 }),
+            json!({ "minLength": 1 }),
+            json!({ "maxLength": 10 }),
+            json!({ "$ref": "#/$defs/name" }),
+            json!({ "allOf": [{ "type": "string" }] }),
+        ];
+
+        for property in properties {
+            let keyword = property
+                .as_object()
+                .and_then(|property| property.keys().find(|key| *key != "type"))
+                .expect("keyword")
+                .clone();
+            let document = json!({ "properties": { "NAME": property } });
+
+            assert_eq!(
+                Err(JsonSchemaError::new(
+                    format!("/properties/NAME/{}", escape_pointer(&keyword)),
+                    format!("unsupported keyword \"{keyword}\"")
+                )),
+                from_json_schema(&document).map(|_| ())
+            );
+        }
+
+        let document = json!({ "$defs": {}, "properties": {} });
+        assert_eq!(
+            Err(JsonSchemaError::new(
+                "/$defs",
+                "unsupported keyword \"$defs\""
+            )),
+            from_json_schema(&document).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn import_additional_properties_schema() {
+        let document = json!({ "additionalProperties": { "type": "string" } });
+
+        assert_eq!(
+            Err(JsonSchemaError::new(
+                "/additionalProperties",
+                "only boolean values are supported"
+            )),
+            from_json_schema(&document).map(|_| ())
+        );
+    }
+}
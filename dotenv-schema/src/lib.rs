@@ -1,32 +1,268 @@
-use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
 
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, de};
 
 #[cfg(feature = "clap")]
 pub mod clap;
+mod error;
+pub mod json_schema;
+pub mod render;
 
-#[derive(Deserialize, Default, Debug, Clone)]
+pub use error::SchemaError;
+use error::locate;
+
+/// Major version of the schema format supported by this crate
+const SUPPORTED_MAJOR_VERSION: &str = "1";
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct DotEnvSchema {
     pub version: String,
     #[serde(default)]
     pub allow_other_keys: bool,
     #[serde(with = "::serde_with::rust::maps_duplicate_key_is_error")]
     pub entries: HashMap<String, SchemaEntry>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overlays: HashMap<String, SchemaOverlay>,
+}
+
+/// Additional rules applied on top of a schema for a single environment (e.g. `production`)
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaOverlay {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_other_keys: Option<bool>,
+    #[serde(default, with = "::serde_with::rust::maps_duplicate_key_is_error")]
+    pub entries: HashMap<String, SchemaEntry>,
+}
+
+impl SchemaOverlay {
+    fn merge(&mut self, other: SchemaOverlay) {
+        self.allow_other_keys = other.allow_other_keys.or(self.allow_other_keys);
+        self.entries.extend(other.entries);
+    }
+}
+
+/// A schema file as it is written on disk, before `extends` are resolved
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SchemaFile {
+    version: String,
+    #[serde(default)]
+    extends: Vec<PathBuf>,
+    allow_other_keys: Option<bool>,
+    #[serde(default, with = "::serde_with::rust::maps_duplicate_key_is_error")]
+    entries: HashMap<String, SchemaEntry>,
+    #[serde(default)]
+    overlays: HashMap<String, SchemaOverlay>,
+}
+
+impl SchemaFile {
+    /// Loads a schema file and merges all the schemas it extends into it.
+    /// `path` is the file as it is shown in errors, `file` is the one to read.
+    /// Problems which don't prevent loading the schema are added to `errors`.
+    fn resolve(
+        path: &Path,
+        file: &Path,
+        chain: &mut Vec<PathBuf>,
+        errors: &mut Vec<SchemaError>,
+    ) -> Result<Self, SchemaError> {
+        let canonical_path = fs::canonicalize(file).map_err(|err| SchemaError::io(path, err))?;
+        if chain.contains(&canonical_path) {
+            return Err(SchemaError::new(
+                path,
+                None,
+                format!("circular extends of {}", canonical_path.display()),
+            ));
+        }
+
+        let text = fs::read_to_string(&canonical_path).map_err(|err| SchemaError::io(path, err))?;
+        let mut schema: SchemaFile =
+            serde_json::from_str(&text).map_err(|err| SchemaError::json(path, err))?;
+        errors.extend(schema.lint(path, &text));
+
+        // Paths in `extends` are relative to the schema file which declares them
+        let base_dir = canonical_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let shown_dir = path.parent().unwrap_or(Path::new(""));
+        chain.push(canonical_path);
+
+        let mut base: Option<SchemaFile> = None;
+        for parent in std::mem::take(&mut schema.extends) {
+            let parent = Self::resolve(
+                &shown_dir.join(&parent),
+                &base_dir.join(&parent),
+                chain,
+                errors,
+            )?;
+            base = Some(match base {
+                Some(base) => base.merge(parent),
+                None => parent,
+            });
+        }
+
+        chain.pop();
+
+        Ok(match base {
+            Some(base) => base.merge(schema),
+            None => schema,
+        })
+    }
+
+    /// Returns problems which are not caught by deserialization:
+    /// an unsupported `version` and `key` fields which don't match the entry names
+    fn lint(&self, path: &Path, text: &str) -> Vec<SchemaError> {
+        let mut errors = Vec::new();
+
+        if !is_supported_version(&self.version) {
+            errors.push(SchemaError::new(
+                path,
+                locate(text, &["\"version\""]),
+                format!(
+                    "unsupported schema version \"{}\", expected {SUPPORTED_MAJOR_VERSION}.x.x",
+                    self.version
+                ),
+            ));
+        }
+
+        let entries = self.entries.iter().map(|entry| (None, entry));
+        let overlay_entries = self.overlays.iter().flat_map(|(name, overlay)| {
+            overlay
+                .entries
+                .iter()
+                .map(move |entry| (Some(name.as_str()), entry))
+        });
+
+        for (overlay, (name, entry)) in entries.chain(overlay_entries) {
+            if entry.key.is_empty() || &entry.key == name {
+                continue;
+            }
+
+            let overlay = overlay.map(json_string);
+            let name = json_string(name);
+            let needles: Vec<&str> = match &overlay {
+                Some(overlay) => vec!["\"overlays\"", overlay, "\"entries\"", &name, "\"key\""],
+                None => vec!["\"entries\"", &name, "\"key\""],
+            };
+
+            errors.push(SchemaError::new(
+                path,
+                locate(text, &needles),
+                format!(
+                    "the key \"{}\" does not match the entry name {name}",
+                    entry.key
+                ),
+            ));
+        }
+
+        for (name, entry) in &self.entries {
+            if entry.required && entry.is_pattern(name) {
+                let name = json_string(name);
+                errors.push(SchemaError::new(
+                    path,
+                    locate(text, &["\"entries\"", &name, "\"required\""]),
+                    format!("the pattern entry {name} can't be required"),
+                ));
+            }
+        }
+
+        // Report problems in the order they appear in the file
+        errors.sort_by_key(|err| err.position);
+        errors
+    }
+
+    /// Applies `other` on top of `self`:
+    /// * `version` is taken from `other`;
+    /// * `allow_other_keys` is taken from `other` if it is set there;
+    /// * `entries` with the same key are replaced as a whole, others are added;
+    /// * `overlays` with the same name are merged by the same rules.
+    fn merge(mut self, other: SchemaFile) -> Self {
+        self.version = other.version;
+        self.allow_other_keys = other.allow_other_keys.or(self.allow_other_keys);
+        self.entries.extend(other.entries);
+
+        for (name, overlay) in other.overlays {
+            self.overlays.entry(name).or_default().merge(overlay);
+        }
+
+        self
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
-#[serde(default)]
+impl From<SchemaFile> for DotEnvSchema {
+    fn from(file: SchemaFile) -> Self {
+        Self {
+            version: file.version,
+            allow_other_keys: file.allow_other_keys.unwrap_or_default(),
+            entries: file.entries,
+            overlays: file.overlays,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
 pub struct SchemaEntry {
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub key: String,
+    #[serde(skip_serializing_if = "is_false")]
     pub required: bool,
     #[serde(rename = "type")]
     pub value_type: SchemaValueType,
-    #[serde(with = "serde_regex")]
+    #[serde(with = "serde_regex", skip_serializing_if = "Option::is_none")]
     pub regex: Option<Regex>,
+    /// Human-readable description used in generated `.env.example` files and docs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        deserialize_with = "deserialize_scalar",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default: Option<String>,
+    #[serde(
+        deserialize_with = "deserialize_scalar",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub example: Option<String>,
+    /// Name of the group the key belongs to in generated `.env.example` files and docs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Makes the entry apply to every key matching the regex instead of the entry name
+    #[serde(with = "serde_regex", skip_serializing_if = "Option::is_none")]
+    pub key_regex: Option<Regex>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub deprecated: bool,
+    /// Key to use instead of the deprecated one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Checks that the version looks like `1`, `1.2` or `1.2.3` with the supported major version
+fn is_supported_version(version: &str) -> bool {
+    let parts: Vec<&str> = version.split('.').collect();
+
+    parts.len() <= 3
+        && parts[0] == SUPPORTED_MAJOR_VERSION
+        && parts.iter().all(|part| part.parse::<u64>().is_ok())
+}
+
+/// Returns the string as a JSON string literal (quoted and escaped)
+fn json_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SchemaValueType {
     #[default]
     String,
@@ -37,12 +273,86 @@ pub enum SchemaValueType {
     Email,
 }
 
+impl fmt::Display for SchemaValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Accepts strings, numbers and booleans, because values in `.env` files are always strings
+fn deserialize_scalar<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(value)) => Ok(Some(value)),
+        Some(value @ (serde_json::Value::Number(_) | serde_json::Value::Bool(_))) => {
+            Ok(Some(value.to_string()))
+        }
+        Some(_) => Err(de::Error::custom(
+            "expected a string, a number or a boolean",
+        )),
+    }
+}
+
 impl DotEnvSchema {
-    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, std::io::Error> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let read_schema: DotEnvSchema = serde_json::from_reader(reader)?;
-        Ok(read_schema)
+    /// Loads a schema from the file and resolves the schemas listed in its `extends`.
+    /// The error wraps the first [`SchemaError`] found.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
+        Self::check(path).map_err(|mut errors| errors.swap_remove(0).into())
+    }
+
+    /// Loads a schema like [`DotEnvSchema::load`], but returns all the problems found.
+    /// Errors which stop loading (I/O and syntax errors) are always the last ones.
+    pub fn check<P: AsRef<Path>>(path: P) -> Result<Self, Vec<SchemaError>> {
+        let mut errors = Vec::new();
+
+        let path = path.as_ref();
+        match SchemaFile::resolve(path, path, &mut Vec::new(), &mut errors) {
+            Ok(schema) if errors.is_empty() => Ok(schema.into()),
+            Ok(_) => Err(errors),
+            Err(err) => {
+                errors.push(err);
+                Err(errors)
+            }
+        }
+    }
+
+    /// Returns the entry for the key: the entry with the same name or, if there is none,
+    /// the first pattern entry (ordered by name) which matches the key
+    pub fn entry_for(&self, key: &str) -> Option<&SchemaEntry> {
+        if let Some(entry) = self.entries.get(key)
+            && !entry.is_pattern(key)
+        {
+            return Some(entry);
+        }
+
+        let mut patterns: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(name, entry)| entry.is_pattern(name))
+            .collect();
+        patterns.sort_by_key(|(name, _)| name.as_str());
+
+        patterns
+            .into_iter()
+            .find(|(name, entry)| entry.matches(name, key))
+            .map(|(_, entry)| entry)
+    }
+
+    /// Returns the schema with the overlay for the given environment applied on top of it.
+    /// Overlay entries replace the entries with the same key.
+    pub fn for_environment(&self, environment: &str) -> Cow<'_, Self> {
+        let Some(overlay) = self.overlays.get(environment) else {
+            return Cow::Borrowed(self);
+        };
+
+        let mut schema = self.clone();
+        schema.allow_other_keys = overlay.allow_other_keys.unwrap_or(self.allow_other_keys);
+        schema.entries.extend(overlay.entries.clone());
+
+        Cow::Owned(schema)
     }
 }
 
@@ -52,6 +362,20 @@ pub enum ValidateResult {
 }
 
 impl SchemaEntry {
+    /// Whether the entry applies to keys matching a pattern: the `key_regex` or the name
+    /// with glob wildcards (e.g. `FEATURE_FLAG_*`)
+    pub fn is_pattern(&self, name: &str) -> bool {
+        self.key_regex.is_some() || name.contains(['*', '?'])
+    }
+
+    /// Whether the entry with the given name applies to the key
+    pub fn matches(&self, name: &str, key: &str) -> bool {
+        match &self.key_regex {
+            Some(regex) => regex.is_match(key),
+            None => dotenv_core::matches_glob(name, key),
+        }
+    }
+
     pub fn is_valid(&self, value: &str) -> ValidateResult {
         match self.value_type {
             SchemaValueType::String => {
@@ -106,7 +430,7 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use super::DotEnvSchema;
+    use super::{DotEnvSchema, SchemaValueType, is_supported_version};
 
     #[test]
     fn create_file_schema() {
@@ -243,4 +567,456 @@ fn test_dup_schema() {
             "invalid entry: found duplicate key at line 9 column 17"
         );
     }
+
+    mod extends {
+        use std::{fs, path::Path};
+
+        use tempfile::tempdir;
+
+        use super::*;
+
+        fn write(dir: &Path, name: &str, json: &str) {
+            fs::write(dir.join(name), json).expect("write file");
+        }
+
+        #[test]
+        fn merge_entries() {
+            let temp_dir = tempdir().expect("create temp dir");
+            write(
+                temp_dir.path(),
+                "base.json",
+                r#"{
+                    "version": "1.0.0",
+                    "entries": {
+                        "NAME": { "type": "String", "required": true },
+                        "PORT": { "type": "Integer" }
+                    }
+                }"#,
+            );
+            write(
+                temp_dir.path(),
+                "schema.json",
+                r#"{
+                    "version": "1.1.0",
+                    "extends": ["base.json"],
+                    "entries": {
+                        "PORT": { "type": "Float" },
+                        "URL": { "type": "Url" }
+                    }
+                }"#,
+            );
+
+            let schema =
+                DotEnvSchema::load(temp_dir.path().join("schema.json")).expect("load schema");
+
+            assert_eq!(schema.version, "1.1.0");
+            assert_eq!(schema.entries.len(), 3);
+            assert!(schema.entries["NAME"].required);
+            assert!(matches!(
+                schema.entries["PORT"].value_type,
+                SchemaValueType::Float
+            ));
+            assert!(matches!(
+                schema.entries["URL"].value_type,
+                SchemaValueType::Url
+            ));
+        }
+
+        #[test]
+        fn later_parent_wins() {
+            let temp_dir = tempdir().expect("create temp dir");
+            write(
+                temp_dir.path(),
+                "one.json",
+                r#"{ "version": "1.0.0", "entries": { "PORT": { "type": "Integer" } } }"#,
+            );
+            write(
+                temp_dir.path(),
+                "two.json",
+                r#"{ "version": "1.0.0", "entries": { "PORT": { "type": "Float" } } }"#,
+            );
+            write(
+                temp_dir.path(),
+                "schema.json",
+                r#"{ "version": "1.0.0", "extends": ["one.json", "two.json"] }"#,
+            );
+
+            let schema =
+                DotEnvSchema::load(temp_dir.path().join("schema.json")).expect("load schema");
+
+            assert!(matches!(
+                schema.entries["PORT"].value_type,
+                SchemaValueType::Float
+            ));
+        }
+
+        #[test]
+        fn inherit_allow_other_keys() {
+            let temp_dir = tempdir().expect("create temp dir");
+            write(
+                temp_dir.path(),
+                "base.json",
+                r#"{ "version": "1.0.0", "allow_other_keys": true, "entries": {} }"#,
+            );
+            write(
+                temp_dir.path(),
+                "inherit.json",
+                r#"{ "version": "1.0.0", "extends": ["base.json"] }"#,
+            );
+            write(
+                temp_dir.path(),
+                "override.json",
+                r#"{ "version": "1.0.0", "extends": ["base.json"], "allow_other_keys": false }"#,
+            );
+
+            let inherit =
+                DotEnvSchema::load(temp_dir.path().join("inherit.json")).expect("load schema");
+            let overridden =
+                DotEnvSchema::load(temp_dir.path().join("override.json")).expect("load schema");
+
+            assert!(inherit.allow_other_keys);
+            assert!(!overridden.allow_other_keys);
+        }
+
+        #[test]
+        fn relative_to_schema_file() {
+            let temp_dir = tempdir().expect("create temp dir");
+            fs::create_dir(temp_dir.path().join("shared")).expect("create dir");
+            write(
+                &temp_dir.path().join("shared"),
+                "base.json",
+                r#"{ "version": "1.0.0", "entries": { "NAME": { "type": "String" } } }"#,
+            );
+            write(
+                temp_dir.path(),
+                "schema.json",
+                r#"{ "version": "1.0.0", "extends": ["shared/base.json"] }"#,
+            );
+
+            let schema =
+                DotEnvSchema::load(temp_dir.path().join("schema.json")).expect("load schema");
+
+            assert!(schema.entries.contains_key("NAME"));
+        }
+
+        #[test]
+        fn circular() {
+            let temp_dir = tempdir().expect("create temp dir");
+            write(
+                temp_dir.path(),
+                "one.json",
+                r#"{ "version": "1.0.0", "extends": ["two.json"] }"#,
+            );
+            write(
+                temp_dir.path(),
+                "two.json",
+                r#"{ "version": "1.0.0", "extends": ["one.json"] }"#,
+            );
+
+            let err =
+                DotEnvSchema::load(temp_dir.path().join("one.json")).expect_err("circular extends");
+
+            assert!(err.to_string().starts_with("circular extends of"));
+        }
+
+        #[test]
+        fn missing_parent() {
+            let temp_dir = tempdir().expect("create temp dir");
+            write(
+                temp_dir.path(),
+                "schema.json",
+                r#"{ "version": "1.0.0", "extends": ["missing.json"] }"#,
+            );
+
+            assert!(DotEnvSchema::load(temp_dir.path().join("schema.json")).is_err());
+        }
+    }
+
+    mod validation {
+        use tempfile::tempdir;
+
+        use super::*;
+        use crate::SchemaError;
+
+        fn check(json: &str) -> Result<DotEnvSchema, Vec<SchemaError>> {
+            let temp_dir = tempdir().expect("create temp dir");
+            let file_path = temp_dir.path().join("schema.json");
+            fs::write(&file_path, json).expect("write file");
+            DotEnvSchema::check(&file_path)
+        }
+
+        fn messages(json: &str) -> Vec<String> {
+            check(json)
+                .expect_err("invalid schema")
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        }
+
+        #[test]
+        fn unknown_entry_field() {
+            let json = r#"{
+    "version": "1.0.0",
+    "entries": {
+        "NAME": { "requried": true }
+    }
+}"#;
+
+            assert_eq!(
+                messages(json),
+                [
+                    "unknown field `requried`, expected one of `key`, `required`, `type`, `regex`, `description`, `default`, `example`, `group`, `key_regex`, `deprecated`, `replaced_by` at line 4 column 28"
+                ]
+            );
+        }
+
+        #[test]
+        fn unknown_top_level_field() {
+            let json = r#"{ "version": "1.0.0", "entris": {} }"#;
+
+            assert!(messages(json)[0].starts_with("unknown field `entris`"));
+        }
+
+        #[test]
+        fn unknown_type() {
+            let json = r#"{
+    "version": "1.0.0",
+    "entries": {
+        "PORT": { "type": "Interger" }
+    }
+}"#;
+
+            assert_eq!(
+                messages(json),
+                [
+                    "unknown variant `Interger`, expected one of `String`, `Integer`, `Float`, `Boolean`, `Url`, `Email` at line 4 column 36"
+                ]
+            );
+        }
+
+        #[test]
+        fn invalid_regex() {
+            let json = r#"{
+    "version": "1.0.0",
+    "entries": {
+        "NAME": { "regex": "~[.." }
+    }
+}"#;
+
+            let errors = check(json).expect_err("invalid schema");
+
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].position, Some((4, 35)));
+            assert!(errors[0].message.contains("regex parse error"));
+        }
+
+        #[test]
+        fn mismatched_key_and_version() {
+            let json = r#"{
+    "version": "2.0.0",
+    "entries": {
+        "NAME": { "key": "NAME" },
+        "PORT": { "key": "PROT" }
+    },
+    "overlays": {
+        "production": {
+            "entries": { "URL": { "key": "URI" } }
+        }
+    }
+}"#;
+
+            assert_eq!(
+                messages(json),
+                [
+                    "unsupported schema version \"2.0.0\", expected 1.x.x at line 2 column 5",
+                    "the key \"PROT\" does not match the entry name \"PORT\" at line 5 column 19",
+                    "the key \"URI\" does not match the entry name \"URL\" at line 9 column 35",
+                ]
+            );
+        }
+
+        #[test]
+        fn load_returns_first_error() {
+            let temp_dir = tempdir().expect("create temp dir");
+            let file_path = temp_dir.path().join("schema.json");
+            fs::write(&file_path, r#"{ "version": "one", "entries": {} }"#).expect("write file");
+
+            let err = DotEnvSchema::load(&file_path).expect_err("invalid schema");
+
+            assert_eq!(
+                err.to_string(),
+                "unsupported schema version \"one\", expected 1.x.x at line 1 column 3"
+            );
+        }
+
+        #[test]
+        fn supported_versions() {
+            for version in ["1", "1.2", "1.0.0"] {
+                assert!(is_supported_version(version), "{version}");
+            }
+
+            for version in ["", "2.0.0", "1.0.0.0", "1.x", "v1"] {
+                assert!(!is_supported_version(version), "{version}");
+            }
+        }
+    }
+
+    mod patterns {
+        use super::*;
+
+        fn load_schema() -> DotEnvSchema {
+            let json = r#"{
+                "version": "1.0.0",
+                "entries": {
+                    "FEATURE_*": { "type": "Boolean" },
+                    "FEATURE_LIMIT": { "type": "Integer" },
+                    "*_URL": { "type": "Url" },
+                    "TIMEOUTS": { "type": "Float", "key_regex": "^TIMEOUT_[A-Z]+$" }
+                }
+            }"#;
+            serde_json::from_str(json).expect("deserializing schema")
+        }
+
+        fn value_type(schema: &DotEnvSchema, key: &str) -> Option<SchemaValueType> {
+            schema.entry_for(key).map(|entry| entry.value_type)
+        }
+
+        #[test]
+        fn glob() {
+            let schema = load_schema();
+
+            assert_eq!(
+                value_type(&schema, "FEATURE_LOGIN"),
+                Some(SchemaValueType::Boolean)
+            );
+            assert_eq!(
+                value_type(&schema, "DATABASE_URL"),
+                Some(SchemaValueType::Url)
+            );
+            assert_eq!(value_type(&schema, "LOGIN_FEATURE"), None);
+        }
+
+        #[test]
+        fn exact_entry_wins() {
+            let schema = load_schema();
+
+            assert_eq!(
+                value_type(&schema, "FEATURE_LIMIT"),
+                Some(SchemaValueType::Integer)
+            );
+        }
+
+        #[test]
+        fn first_pattern_by_name_wins() {
+            let schema = load_schema();
+
+            // Both `*_URL` and `FEATURE_*` match, `*_URL` goes first
+            assert_eq!(
+                value_type(&schema, "FEATURE_URL"),
+                Some(SchemaValueType::Url)
+            );
+        }
+
+        #[test]
+        fn key_regex() {
+            let schema = load_schema();
+
+            assert_eq!(
+                value_type(&schema, "TIMEOUT_READ"),
+                Some(SchemaValueType::Float)
+            );
+            // The entry name isn't a key when the entry has a regex
+            assert_eq!(value_type(&schema, "TIMEOUTS"), None);
+        }
+
+        #[test]
+        fn required_pattern() {
+            let temp_dir = tempdir().expect("create temp dir");
+            let file_path = temp_dir.path().join("schema.json");
+            fs::write(
+                &file_path,
+                r#"{ "version": "1.0.0", "entries": { "FEATURE_*": { "required": true } } }"#,
+            )
+            .expect("write file");
+
+            let err = DotEnvSchema::load(&file_path).expect_err("invalid schema");
+
+            assert_eq!(
+                err.to_string(),
+                "the pattern entry \"FEATURE_*\" can't be required at line 1 column 51"
+            );
+        }
+    }
+
+    mod overlays {
+        use super::*;
+
+        fn load_schema() -> DotEnvSchema {
+            let json = r#"{
+                "version": "1.0.0",
+                "entries": {
+                    "SENTRY_DSN": { "type": "Url" },
+                    "PORT": { "type": "Integer" }
+                },
+                "overlays": {
+                    "production": {
+                        "allow_other_keys": true,
+                        "entries": {
+                            "SENTRY_DSN": { "type": "Url", "required": true }
+                        }
+                    }
+                }
+            }"#;
+            serde_json::from_str(json).expect("deserializing schema")
+        }
+
+        #[test]
+        fn apply_overlay() {
+            let schema = load_schema();
+            let production = schema.for_environment("production");
+
+            assert!(production.allow_other_keys);
+            assert!(production.entries["SENTRY_DSN"].required);
+            assert!(production.entries.contains_key("PORT"));
+        }
+
+        #[test]
+        fn without_overlay() {
+            let schema = load_schema();
+            let development = schema.for_environment("development");
+
+            assert!(!development.allow_other_keys);
+            assert!(!development.entries["SENTRY_DSN"].required);
+        }
+
+        #[test]
+        fn merge_overlays_from_parent() {
+            let temp_dir = tempdir().expect("create temp dir");
+            fs::write(
+                temp_dir.path().join("base.json"),
+                r#"{
+                    "version": "1.0.0",
+                    "entries": {},
+                    "overlays": { "production": { "entries": { "A": { "required": true } } } }
+                }"#,
+            )
+            .expect("write file");
+            fs::write(
+                temp_dir.path().join("schema.json"),
+                r#"{
+                    "version": "1.0.0",
+                    "extends": ["base.json"],
+                    "overlays": { "production": { "entries": { "B": { "required": true } } } }
+                }"#,
+            )
+            .expect("write file");
+
+            let schema =
+                DotEnvSchema::load(temp_dir.path().join("schema.json")).expect("load schema");
+            let production = schema.for_environment("production");
+
+            assert!(production.entries["A"].required);
+            assert!(production.entries["B"].required);
+        }
+    }
 }
@@ -0,0 +1,370 @@
+//! Generates `.env.example` files and reference documentation from a [`DotEnvSchema`]
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::{DotEnvSchema, SchemaEntry};
+
+const TITLE: &str = "Environment variables";
+/// Heading for entries without a group when the schema has groups
+const DEFAULT_GROUP: &str = "General";
+const COLUMNS: [&str; 6] = [
+    "Key",
+    "Type",
+    "Required",
+    "Default",
+    "Example",
+    "Description",
+];
+
+/// Comment of a required key without a default value or an example
+const REQUIRED_NOTE: &str = "Required, set a value";
+
+type Group<'a> = (Option<&'a str>, Vec<(&'a str, &'a SchemaEntry)>);
+
+/// Renders a `.env.example` file: entries are grouped, sorted by key and described in comments.
+/// The value is the default value, the example or empty. A required key without a value is
+/// marked with a comment, so it isn't taken for an optional empty value.
+/// Deprecated and pattern entries are skipped, because they don't describe keys to set.
+pub fn to_dotenv(schema: &DotEnvSchema) -> String {
+    let mut output = String::new();
+
+    let mut groups = groups(schema);
+    for (_, entries) in &mut groups {
+        entries.retain(|(key, entry)| !entry.deprecated && !entry.is_pattern(key));
+    }
+    groups.retain(|(_, entries)| !entries.is_empty());
+
+    for (index, (group, entries)) in groups.into_iter().enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+
+        if let Some(group) = group {
+            let _ = writeln!(output, "# {group}");
+        }
+
+        for (key, entry) in entries {
+            if let Some(description) = &entry.description {
+                for line in description.lines() {
+                    let _ = writeln!(output, "# {line}");
+                }
+            }
+
+            let required = if entry.required { " (required)" } else { "" };
+            let _ = writeln!(output, "# Type: {}{required}", entry.value_type);
+
+            let value = entry.default.as_deref().or(entry.example.as_deref());
+            if entry.required && value.is_none() {
+                let _ = writeln!(output, "# {REQUIRED_NOTE}");
+            }
+            let _ = writeln!(output, "{key}={}", quote(value.unwrap_or_default()));
+        }
+    }
+
+    output
+}
+
+/// Renders reference documentation as Markdown tables, one table per group
+pub fn to_markdown(schema: &DotEnvSchema) -> String {
+    let mut output = format!("# {TITLE}\n");
+    let groups = groups(schema);
+    let has_groups = groups.iter().any(|(group, _)| group.is_some());
+
+    for (group, entries) in groups {
+        if has_groups {
+            let _ = write!(output, "\n## {}\n", group.unwrap_or(DEFAULT_GROUP));
+        }
+
+        let _ = write!(output, "\n| {} |\n", COLUMNS.join(" | "));
+        let _ = writeln!(output, "|{}", " --- |".repeat(COLUMNS.len()));
+
+        for (key, entry) in entries {
+            let cells = [
+                format!("`{key}`"),
+                entry.value_type.to_string(),
+                yes_no(entry.required).to_string(),
+                markdown_code(entry.default.as_deref()),
+                markdown_code(entry.example.as_deref()),
+                markdown_text(&description(entry)),
+            ];
+            let _ = writeln!(output, "| {} |", cells.join(" | "));
+        }
+    }
+
+    output
+}
+
+/// Renders reference documentation as an HTML fragment, one table per group
+pub fn to_html(schema: &DotEnvSchema) -> String {
+    let mut output = format!("<h1>{TITLE}</h1>\n");
+    let groups = groups(schema);
+    let has_groups = groups.iter().any(|(group, _)| group.is_some());
+
+    for (group, entries) in groups {
+        if has_groups {
+            let _ = writeln!(
+                output,
+                "<h2>{}</h2>",
+                html_escape(group.unwrap_or(DEFAULT_GROUP))
+            );
+        }
+
+        output.push_str("<table>\n  <thead>\n    <tr>");
+        for column in COLUMNS {
+            let _ = write!(output, "<th>{column}</th>");
+        }
+        output.push_str("</tr>\n  </thead>\n  <tbody>\n");
+
+        for (key, entry) in entries {
+            let cells = [
+                html_code(Some(key)),
+                entry.value_type.to_string(),
+                yes_no(entry.required).to_string(),
+                html_code(entry.default.as_deref()),
+                html_code(entry.example.as_deref()),
+                html_escape(&description(entry)),
+            ];
+
+            output.push_str("    <tr>");
+            for cell in cells {
+                let _ = write!(output, "<td>{cell}</td>");
+            }
+            output.push_str("</tr>\n");
+        }
+
+        output.push_str("  </tbody>\n</table>\n");
+    }
+
+    output
+}
+
+/// Returns entries grouped by `group` and sorted by key. Entries without a group go first.
+fn groups(schema: &DotEnvSchema) -> Vec<Group<'_>> {
+    let mut groups: BTreeMap<Option<&str>, BTreeMap<&str, &SchemaEntry>> = BTreeMap::new();
+
+    for (key, entry) in &schema.entries {
+        groups
+            .entry(entry.group.as_deref())
+            .or_default()
+            .insert(key.as_str(), entry);
+    }
+
+    groups
+        .into_iter()
+        .map(|(group, entries)| (group, entries.into_iter().collect()))
+        .collect()
+}
+
+/// Returns the description with a deprecation note
+fn description(entry: &SchemaEntry) -> String {
+    let description = entry.description.as_deref().unwrap_or_default();
+    if !entry.deprecated {
+        return description.to_string();
+    }
+
+    let note = match &entry.replaced_by {
+        Some(key) => format!("Deprecated, use {key} instead."),
+        None => "Deprecated.".to_string(),
+    };
+
+    if description.is_empty() {
+        note
+    } else {
+        format!("{note} {description}")
+    }
+}
+
+/// Quotes the value if it has characters which end or change an unquoted value (e.g. `#` or `$`).
+/// The characters which have a meaning in double quotes are escaped.
+fn quote(value: &str) -> String {
+    if !value.contains(|c: char| c.is_whitespace() || "#\"'$`\\".contains(c)) {
+        return value.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '$' | '`' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "Yes" } else { "No" }
+}
+
+fn markdown_text(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn markdown_code(value: Option<&str>) -> String {
+    value
+        .map(|value| format!("`{}`", markdown_text(value)))
+        .unwrap_or_default()
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html_code(value: Option<&str>) -> String {
+    value
+        .map(|value| format!("<code>{}</code>", html_escape(value)))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_schema() -> DotEnvSchema {
+        let json = r#"{
+            "version": "1.0.0",
+            "entries": {
+                "PORT": {
+                    "type": "Integer",
+                    "default": 8080
+                },
+                "DATABASE_URL": {
+                    "type": "Url",
+                    "required": true,
+                    "group": "Database",
+                    "description": "Connection string of the primary database",
+                    "example": "postgres://localhost/app"
+                },
+                "DATABASE_POOL": {
+                    "type": "Integer",
+                    "group": "Database"
+                },
+                "DATABASE_USER": {
+                    "type": "Email",
+                    "required": true,
+                    "group": "Database"
+                },
+                "GREETING": {
+                    "description": "Shown on <b>the</b> main | page",
+                    "example": "Hello world"
+                },
+                "DB_URL": {
+                    "type": "Url",
+                    "group": "Database",
+                    "deprecated": true,
+                    "replaced_by": "DATABASE_URL"
+                },
+                "FEATURE_*": {
+                    "type": "Boolean"
+                }
+            }
+        }"#;
+        serde_json::from_str(json).expect("deserializing schema")
+    }
+
+    #[test]
+    fn render_dotenv() {
+        let expected = "\
+# Shown on <b>the</b> main | page
+# Type: String
+GREETING=\"Hello world\"
+# Type: Integer
+PORT=8080
+
+# Database
+# Type: Integer
+DATABASE_POOL=
+# Connection string of the primary database
+# Type: Url (required)
+DATABASE_URL=postgres://localhost/app
+# Type: Email (required)
+# Required, set a value
+DATABASE_USER=
+";
+
+        assert_eq!(expected, to_dotenv(&load_schema()));
+    }
+
+    #[test]
+    fn quote_test() {
+        assert_eq!("8080", quote("8080"));
+        assert_eq!("\"Hello world\"", quote("Hello world"));
+        assert_eq!("\"a#b\"", quote("a#b"));
+        assert_eq!("\"it's\"", quote("it's"));
+        assert_eq!("\"say \\\"hi\\\"\"", quote("say \"hi\""));
+        assert_eq!("\"\\${HOME}/\\`pwd\\`\"", quote("${HOME}/`pwd`"));
+        assert_eq!("\"C:\\\\app\"", quote("C:\\app"));
+    }
+
+    #[test]
+    fn render_markdown() {
+        let expected = "\
+# Environment variables
+
+## General
+
+| Key | Type | Required | Default | Example | Description |
+| --- | --- | --- | --- | --- | --- |
+| `GREETING` | String | No |  | `Hello world` | Shown on <b>the</b> main \\| page |
+| `PORT` | Integer | No | `8080` |  |  |
+
+## Database
+
+| Key | Type | Required | Default | Example | Description |
+| --- | --- | --- | --- | --- | --- |
+| `DATABASE_POOL` | Integer | No |  |  |  |
+| `DATABASE_URL` | Url | Yes |  | `postgres://localhost/app` | Connection string of the primary database |
+| `DATABASE_USER` | Email | Yes |  |  |  |
+| `DB_URL` | Url | No |  |  | Deprecated, use DATABASE_URL instead. |
+";
+
+        let mut schema = load_schema();
+        schema.entries.remove("FEATURE_*");
+
+        assert_eq!(expected, to_markdown(&schema));
+    }
+
+    #[test]
+    fn render_markdown_without_groups() {
+        let mut schema = load_schema();
+        schema
+            .entries
+            .retain(|key, entry| entry.group.is_none() && !entry.is_pattern(key));
+
+        let expected = "\
+# Environment variables
+
+| Key | Type | Required | Default | Example | Description |
+| --- | --- | --- | --- | --- | --- |
+| `GREETING` | String | No |  | `Hello world` | Shown on <b>the</b> main \\| page |
+| `PORT` | Integer | No | `8080` |  |  |
+";
+
+        assert_eq!(expected, to_markdown(&schema));
+    }
+
+    #[test]
+    fn render_html() {
+        let mut schema = load_schema();
+        schema.entries.retain(|key, _| key == "GREETING");
+
+        let expected = "\
+<h1>Environment variables</h1>
+<table>
+  <thead>
+    <tr><th>Key</th><th>Type</th><th>Required</th><th>Default</th><th>Example</th><th>Description</th></tr>
+  </thead>
+  <tbody>
+    <tr><td><code>GREETING</code></td><td>String</td><td>No</td><td></td><td><code>Hello world</code></td><td>Shown on &lt;b&gt;the&lt;/b&gt; main | page</td></tr>
+  </tbody>
+</table>
+";
+
+        assert_eq!(expected, to_html(&schema));
+    }
+}